@@ -473,6 +473,48 @@ mod tests {
         assert_eq!(text.plain(), "Hi!");
     }
 
+    fn style_of(text: &Text, needle: &str) -> Style {
+        text.render("")
+            .into_iter()
+            .find(|seg| seg.text.as_ref() == needle)
+            .and_then(|seg| seg.style)
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn decode_line_blink_and_blink_off() {
+        let mut decoder = AnsiDecoder::new();
+        let text = decoder.decode_line("\u{1b}[5mX\u{1b}[25mY");
+        assert_eq!(text.plain(), "XY");
+        assert!(style_of(&text, "X").attributes.contains(Attributes::BLINK));
+        assert!(!style_of(&text, "Y").attributes.contains(Attributes::BLINK));
+    }
+
+    #[test]
+    fn decode_line_extended_attributes_and_resets() {
+        let mut decoder = AnsiDecoder::new();
+        let text = decoder
+            .decode_line("\u{1b}[8;53;21;1mA\u{1b}[28;55;24;22mB\u{1b}[6;51;52mC\u{1b}[26;54mD");
+        let a = style_of(&text, "A").attributes;
+        for attr in [
+            Attributes::CONCEAL,
+            Attributes::OVERLINE,
+            Attributes::UNDERLINE2,
+            Attributes::BOLD,
+        ] {
+            assert!(a.contains(attr), "A missing {attr:?}");
+        }
+
+        let b = style_of(&text, "B").attributes;
+        assert!(b.is_empty(), "B should have every attribute reset: {b:?}");
+
+        let c = style_of(&text, "C").attributes;
+        assert!(c.contains(Attributes::BLINK2 | Attributes::FRAME | Attributes::ENCIRCLE));
+
+        let d = style_of(&text, "D").attributes;
+        assert!(d.is_empty(), "D should have every attribute reset: {d:?}");
+    }
+
     #[test]
     fn decode_line_osc8_link_set_and_clear() {
         let mut decoder = AnsiDecoder::new();
@@ -9,7 +9,8 @@ use std::sync::{LazyLock, Mutex};
 use crate::sync::lock_recover;
 
 use lru::LruCache;
-use unicode_width::UnicodeWidthChar;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 /// Minimum string length to cache (shorter strings have minimal overhead).
 const CACHE_MIN_LEN: usize = 8;
@@ -28,18 +29,39 @@ pub fn get_character_cell_size(c: char) -> usize {
     c.width().unwrap_or(0)
 }
 
-/// Compute cell width by summing character widths.
+/// Get the cell width of a single grapheme cluster.
+///
+/// Combining marks and zero-width joiners add no width, so `"e\u{301}"` is
+/// 1 cell, while an emoji ZWJ sequence or a regional-indicator flag pair
+/// is drawn as one 2-cell glyph. Control characters have 0 width.
+#[must_use]
+pub fn grapheme_cell_size(grapheme: &str) -> usize {
+    let mut chars = grapheme.chars();
+    match (chars.next(), chars.next()) {
+        (None, _) => 0,
+        (Some(c), None) => get_character_cell_size(c),
+        _ if grapheme.chars().any(char::is_control) => {
+            grapheme.chars().map(get_character_cell_size).sum()
+        }
+        _ => grapheme.width(),
+    }
+}
+
+/// Compute cell width by summing grapheme cluster widths.
 ///
 /// This ensures consistent handling of control characters (width 0)
 /// using the same logic as `get_character_cell_size`.
 #[inline]
 fn compute_cell_width(text: &str) -> usize {
-    text.chars().map(get_character_cell_size).sum()
+    if text.is_ascii() {
+        return text.chars().map(get_character_cell_size).sum();
+    }
+    text.graphemes(true).map(grapheme_cell_size).sum()
 }
 
 /// Get the total cell width of a string (cached for longer strings).
 ///
-/// This is the sum of the widths of all characters, accounting for
+/// This is the sum of the widths of all grapheme clusters, accounting for
 /// wide characters that take 2 cells. Control characters have 0 width.
 ///
 /// Per `RICH_SPEC.md` Section 12.4, results are cached using an LRU cache
@@ -108,18 +130,19 @@ pub fn set_cell_size(text: &str, total: usize) -> String {
 
 /// Truncate a string to a maximum cell width.
 ///
-/// Returns the truncated string and its actual width.
+/// Cuts on grapheme cluster boundaries, so an emoji sequence is kept whole
+/// or dropped. Returns the truncated string and its actual width.
 fn truncate_to_width(text: &str, max_width: usize) -> (String, usize) {
     let mut width = 0;
     let mut result = String::new();
 
-    for c in text.chars() {
-        let char_width = get_character_cell_size(c);
-        if width + char_width > max_width {
+    for grapheme in text.graphemes(true) {
+        let grapheme_width = grapheme_cell_size(grapheme);
+        if width + grapheme_width > max_width {
             break;
         }
-        width += char_width;
-        result.push(c);
+        width += grapheme_width;
+        result.push_str(grapheme);
     }
 
     (result, width)
@@ -128,19 +151,20 @@ fn truncate_to_width(text: &str, max_width: usize) -> (String, usize) {
 /// Split a string at a cell position.
 ///
 /// Returns (left, right) where left has the specified width (or less if
-/// a wide character exceeds it).
+/// a wide character exceeds it). The split never falls inside a grapheme
+/// cluster.
 #[must_use]
 pub fn chop_cells(text: &str, max_size: usize) -> (&str, &str) {
     let mut width = 0;
     let mut byte_pos = 0;
 
-    for (i, c) in text.char_indices() {
-        let char_width = get_character_cell_size(c);
-        if width + char_width > max_size {
+    for (i, grapheme) in text.grapheme_indices(true) {
+        let grapheme_width = grapheme_cell_size(grapheme);
+        if width + grapheme_width > max_size {
             break;
         }
-        width += char_width;
-        byte_pos = i + c.len_utf8();
+        width += grapheme_width;
+        byte_pos = i + grapheme.len();
     }
 
     (&text[..byte_pos], &text[byte_pos..])
@@ -148,15 +172,18 @@ pub fn chop_cells(text: &str, max_size: usize) -> (&str, &str) {
 
 /// Get the cell position for each character in a string.
 ///
-/// Returns a vector of (`byte_index`, `cell_position`) pairs.
+/// Returns a vector of (`byte_index`, `cell_position`) pairs. Characters
+/// that continue a grapheme cluster share the cluster's cell position.
 #[must_use]
 pub fn cell_positions(text: &str) -> Vec<(usize, usize)> {
     let mut positions = Vec::new();
     let mut cell_pos = 0;
 
-    for (byte_idx, c) in text.char_indices() {
-        positions.push((byte_idx, cell_pos));
-        cell_pos += get_character_cell_size(c);
+    for (cluster_start, grapheme) in text.grapheme_indices(true) {
+        for (offset, _) in grapheme.char_indices() {
+            positions.push((cluster_start + offset, cell_pos));
+        }
+        cell_pos += grapheme_cell_size(grapheme);
     }
 
     positions
@@ -169,11 +196,11 @@ pub fn cell_positions(text: &str) -> Vec<(usize, usize)> {
 pub fn cell_to_byte_index(text: &str, cell_pos: usize) -> Option<usize> {
     let mut current_cell = 0;
 
-    for (byte_idx, c) in text.char_indices() {
+    for (byte_idx, grapheme) in text.grapheme_indices(true) {
         if current_cell >= cell_pos {
             return Some(byte_idx);
         }
-        current_cell += get_character_cell_size(c);
+        current_cell += grapheme_cell_size(grapheme);
     }
 
     if current_cell >= cell_pos {
@@ -577,6 +604,16 @@ mod tests {
         assert_eq!(cell_len(with_zwj), 3);
     }
 
+    #[test]
+    fn test_grapheme_cell_size_for_combining_and_flags() {
+        assert_eq!(cell_len("e\u{301}"), 1);
+        assert_eq!(cell_len("cafe\u{301}"), 4);
+        assert_eq!(grapheme_cell_size("\u{1F1EF}\u{1F1F5}"), 2);
+        assert_eq!(cell_len("\u{1F1EF}\u{1F1F5}"), 2);
+        assert_eq!(cell_len("\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}"), 2);
+        assert_eq!(chop_cells("e\u{301}x", 1), ("e\u{301}", "x"));
+    }
+
     // Test: Zero-width joiner sequences (emoji ZWJ sequences)
     #[test]
     fn test_unicode_zwj_sequences() {
@@ -596,6 +633,15 @@ mod tests {
         assert!(width >= 2, "Family emoji should have some width: {width}");
     }
 
+    #[test]
+    fn test_set_cell_size_keeps_zwj_sequence_whole() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let text = format!("a{family}b");
+        assert_eq!(set_cell_size(&text, 2), "a ");
+        assert_eq!(chop_cells(&text, 2), ("a", &text[1..]));
+        assert_eq!(set_cell_size(&text, 3), format!("a{family}"));
+    }
+
     // Test: Emoji with skin tone modifiers
     #[test]
     fn test_unicode_emoji_skin_tones() {
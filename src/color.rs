@@ -149,6 +149,72 @@ impl ColorTriplet {
 
         (hue / 6.0, lightness, saturation)
     }
+
+    /// Relative luminance as defined by WCAG 2.x (0.0 = black, 1.0 = white).
+    #[must_use]
+    pub fn luminance(&self) -> f64 {
+        let linearize = |channel: f64| -> f64 {
+            if channel <= 0.039_28 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let (r, g, b) = self.normalized();
+        0.0722f64.mul_add(
+            linearize(b),
+            0.2126f64.mul_add(linearize(r), 0.7152 * linearize(g)),
+        )
+    }
+
+    /// Convert to CIELAB (`L*`, `a*`, `b*`) under the D65 white point.
+    ///
+    /// Euclidean distance in this space tracks perceived color difference
+    /// far better than distance in RGB.
+    #[must_use]
+    pub fn to_lab(&self) -> (f64, f64, f64) {
+        let linearize = |channel: f64| -> f64 {
+            if channel <= 0.040_45 {
+                channel / 12.92
+            } else {
+                ((channel + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let (red, green, blue) = self.normalized();
+        let rgb = [linearize(red), linearize(green), linearize(blue)];
+        // Linear sRGB to XYZ, normalized by the D65 white point, then CIELAB's
+        // cube-root compression.
+        let compress = |row: [f64; 3], white: f64| -> f64 {
+            let t = row[2].mul_add(rgb[2], row[0].mul_add(rgb[0], row[1] * rgb[1])) / white;
+            if t > 216.0 / 24_389.0 {
+                t.cbrt()
+            } else {
+                (24_389.0f64 / 27.0).mul_add(t, 16.0) / 116.0
+            }
+        };
+        let fx = compress([0.412_456_4, 0.357_576_1, 0.180_437_5], 0.950_47);
+        let fy = compress([0.212_672_9, 0.715_152_2, 0.072_175_0], 1.0);
+        let fz = compress([0.019_333_9, 0.119_192_0, 0.950_304_1], 1.088_83);
+        (
+            116.0f64.mul_add(fy, -16.0),
+            500.0 * (fx - fy),
+            200.0 * (fy - fz),
+        )
+    }
+
+    /// Perceptual difference from `other` (CIE76 ΔE in CIELAB).
+    #[must_use]
+    pub fn delta_e(&self, other: &Self) -> f64 {
+        lab_distance(self.to_lab(), other.to_lab())
+    }
+
+    /// WCAG contrast ratio between two colors, in the range 1.0-21.0.
+    #[must_use]
+    pub fn contrast_ratio(&self, other: &Self) -> f64 {
+        let a = self.luminance();
+        let b = other.luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
 }
 
 impl From<(u8, u8, u8)> for ColorTriplet {
@@ -197,6 +263,52 @@ impl ColorSystem {
     }
 }
 
+/// How [`Color::downgrade_with`] picks a palette entry for a color the
+/// terminal cannot show.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DowngradeMethod {
+    /// Fast RGB-space mapping (the behavior of [`Color::downgrade`]).
+    #[default]
+    Nearest,
+    /// Closest palette entry by CIELAB distance; slower but visually closer.
+    Perceptual,
+}
+
+/// Color vision deficiency simulated by [`Color::simulate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ColorVisionDeficiency {
+    /// No functioning long-wavelength (red) cones.
+    Protanopia,
+    /// No functioning medium-wavelength (green) cones.
+    Deuteranopia,
+    /// No functioning short-wavelength (blue) cones.
+    Tritanopia,
+}
+
+impl ColorVisionDeficiency {
+    /// Simulation matrix in linear sRGB (Machado, Oliveira & Fernandes 2009,
+    /// severity 1.0), derived from the LMS cone-response model.
+    const fn matrix(self) -> [[f64; 3]; 3] {
+        match self {
+            Self::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            Self::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            Self::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        }
+    }
+}
+
 /// Type of color stored in Color structure.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 #[repr(u8)]
@@ -615,6 +727,127 @@ impl Color {
         }
     }
 
+    /// Downgrade to a color system using the given palette-matching method.
+    ///
+    /// [`DowngradeMethod::Nearest`] is the same as [`Color::downgrade`];
+    /// [`DowngradeMethod::Perceptual`] picks the palette entry with the
+    /// smallest CIELAB distance.
+    ///
+    /// ```
+    /// use rich_rust::color::{Color, ColorSystem, DowngradeMethod};
+    ///
+    /// let teal = Color::from_rgb(0, 128, 128);
+    /// let downgraded = teal.downgrade_with(ColorSystem::EightBit, DowngradeMethod::Perceptual);
+    /// assert_eq!(downgraded.number, Some(30));
+    /// ```
+    #[must_use]
+    pub fn downgrade_with(&self, system: ColorSystem, method: DowngradeMethod) -> Self {
+        if method == DowngradeMethod::Nearest || self.is_default() {
+            return self.downgrade(system);
+        }
+
+        match (self.color_type, system) {
+            (ColorType::TrueColor, ColorSystem::EightBit) => {
+                // Skip 0-15: their actual colors depend on the terminal theme.
+                let number = nearest_by_lab(self.get_truecolor(), &EIGHT_BIT_LAB[16..]);
+                Self::from_ansi(16 + number)
+            }
+            (
+                ColorType::TrueColor | ColorType::EightBit,
+                ColorSystem::Standard | ColorSystem::Windows,
+            ) => Self::from_ansi(nearest_by_lab(self.get_truecolor(), &*STANDARD_LAB)),
+            _ => self.downgrade(system),
+        }
+    }
+
+    /// Simulate how this color appears with a color vision deficiency.
+    ///
+    /// The color is resolved to RGB and returned as a truecolor; the default
+    /// color is returned unchanged. Grays map to themselves.
+    ///
+    /// ```
+    /// use rich_rust::color::{Color, ColorVisionDeficiency};
+    ///
+    /// let red = Color::from_rgb(255, 0, 0).simulate(ColorVisionDeficiency::Deuteranopia);
+    /// assert_eq!(red, Color::from_rgb(163, 144, 0));
+    /// ```
+    #[must_use]
+    pub fn simulate(&self, deficiency: ColorVisionDeficiency) -> Self {
+        if self.is_default() {
+            return self.clone();
+        }
+
+        let linearize = |channel: u8| -> f64 {
+            let c = f64::from(channel) / 255.0;
+            if c <= 0.040_45 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        let encode = |linear: f64| -> u8 {
+            let c = linear.clamp(0.0, 1.0);
+            let c = if c <= 0.003_130_8 {
+                c * 12.92
+            } else {
+                1.055f64.mul_add(c.powf(1.0 / 2.4), -0.055)
+            };
+            #[expect(clippy::cast_sign_loss, reason = "value is clamped to 0..255")]
+            #[expect(
+                clippy::cast_possible_truncation,
+                reason = "value is clamped to 0..255"
+            )]
+            ((c * 255.0).round().clamp(0.0, 255.0) as u8)
+        };
+
+        let triplet = self.get_truecolor();
+        let rgb = [
+            linearize(triplet.red),
+            linearize(triplet.green),
+            linearize(triplet.blue),
+        ];
+        let row = |m: [f64; 3]| m[2].mul_add(rgb[2], m[0].mul_add(rgb[0], m[1] * rgb[1]));
+        let [r, g, b] = deficiency.matrix();
+        Self::from_rgb(encode(row(r)), encode(row(g)), encode(row(b)))
+    }
+
+    /// WCAG contrast ratio between this color and `other` (1.0-21.0).
+    ///
+    /// Both colors are resolved to RGB via [`Color::get_truecolor`], so the
+    /// default color is treated as black.
+    #[must_use]
+    pub fn contrast_ratio(&self, other: &Self) -> f64 {
+        self.get_truecolor().contrast_ratio(&other.get_truecolor())
+    }
+
+    /// Pick the candidate with the highest contrast against this color.
+    ///
+    /// Treats `self` as a background and returns the most readable foreground
+    /// from `candidates`. When `candidates` is empty, chooses between black
+    /// and white.
+    ///
+    /// ```
+    /// use rich_rust::color::Color;
+    ///
+    /// let background = Color::from_rgb(255, 255, 0);
+    /// let foreground = background.best_contrast(&[]);
+    /// assert_eq!(foreground, Color::from_rgb(0, 0, 0));
+    /// ```
+    #[must_use]
+    pub fn best_contrast(&self, candidates: &[Self]) -> Self {
+        let defaults = [Self::from_rgb(0, 0, 0), Self::from_rgb(255, 255, 255)];
+        let candidates = if candidates.is_empty() {
+            &defaults[..]
+        } else {
+            candidates
+        };
+        candidates
+            .iter()
+            .max_by(|a, b| self.contrast_ratio(a).total_cmp(&self.contrast_ratio(b)))
+            .cloned()
+            .unwrap_or_else(|| defaults[0].clone())
+    }
+
     /// Parse a color string (cached).
     ///
     /// Supported formats:
@@ -1102,6 +1335,38 @@ pub fn rgb_to_standard(triplet: ColorTriplet) -> u8 {
     best_index
 }
 
+/// A color in CIELAB (`L*`, `a*`, `b*`), as returned by [`ColorTriplet::to_lab`].
+type Lab = (f64, f64, f64);
+
+/// [`STANDARD_PALETTE`] in CIELAB, for perceptual downgrades.
+static STANDARD_LAB: LazyLock<[Lab; 16]> =
+    LazyLock::new(|| STANDARD_PALETTE.map(|triplet| triplet.to_lab()));
+
+/// [`EIGHT_BIT_PALETTE`] in CIELAB, for perceptual downgrades.
+static EIGHT_BIT_LAB: LazyLock<[Lab; 256]> =
+    LazyLock::new(|| EIGHT_BIT_PALETTE.map(|triplet| triplet.to_lab()));
+
+/// CIE76 ΔE between two CIELAB colors.
+fn lab_distance(x: Lab, y: Lab) -> f64 {
+    (x.0 - y.0).hypot(x.1 - y.1).hypot(x.2 - y.2)
+}
+
+/// Index of the `palette` entry perceptually closest to `triplet`.
+fn nearest_by_lab(triplet: ColorTriplet, palette: &[Lab]) -> u8 {
+    let target = triplet.to_lab();
+    let index = palette
+        .iter()
+        .enumerate()
+        .min_by(|(_, x), (_, y)| lab_distance(target, **x).total_cmp(&lab_distance(target, **y)))
+        .map_or(0, |(index, _)| index);
+    #[expect(
+        clippy::cast_possible_truncation,
+        reason = "palettes have at most 256 entries"
+    )]
+    let index = index as u8;
+    index
+}
+
 /// Calculate weighted color distance (CIE76-like).
 fn color_distance(c1: ColorTriplet, c2: ColorTriplet) -> u32 {
     let r1 = u32::from(c1.red);
@@ -1431,6 +1696,27 @@ mod tests {
         assert_eq!(c.get_ansi_codes(true), vec!["38", "2", "255", "128", "64"]);
     }
 
+    #[test]
+    fn test_downgrade_perceptual_picks_closer_gray() {
+        // A cool mid-gray: the RGB heuristic rounds it up to a lighter gray.
+        let gray = Color::from_rgb(128, 128, 140);
+        let triplet = gray.get_truecolor();
+
+        let nearest = gray.downgrade_with(ColorSystem::EightBit, DowngradeMethod::Nearest);
+        let perceptual = gray.downgrade_with(ColorSystem::EightBit, DowngradeMethod::Perceptual);
+        assert_eq!(nearest, gray.downgrade(ColorSystem::EightBit));
+        assert_eq!(nearest.number, Some(245));
+        assert_eq!(perceptual.number, Some(244));
+        assert!(
+            triplet.delta_e(&perceptual.get_truecolor())
+                < triplet.delta_e(&nearest.get_truecolor())
+        );
+
+        let standard = gray.downgrade_with(ColorSystem::Standard, DowngradeMethod::Perceptual);
+        assert_eq!(standard.color_type, ColorType::Standard);
+        assert!(standard.number.is_some_and(|n| n < 16));
+    }
+
     #[test]
     fn test_color_downgrade() {
         let truecolor = Color::from_rgb(255, 0, 0);
@@ -1911,4 +2197,73 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_contrast_ratio_extremes() {
+        let white = Color::from_rgb(255, 255, 255);
+        let black = Color::from_rgb(0, 0, 0);
+        assert!((white.contrast_ratio(&black) - 21.0).abs() < 0.01);
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 0.01);
+        assert!((white.contrast_ratio(&white) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_best_contrast_picks_readable_foreground() {
+        let white = Color::from_rgb(255, 255, 255);
+        let black = Color::from_rgb(0, 0, 0);
+        let candidates = [black.clone(), white.clone()];
+
+        let dark_blue = Color::from_rgb(0, 0, 139);
+        assert_eq!(dark_blue.best_contrast(&candidates), white);
+
+        let yellow = Color::from_rgb(255, 255, 0);
+        assert_eq!(yellow.best_contrast(&candidates), black);
+    }
+
+    #[test]
+    fn test_simulate_color_vision_deficiency() {
+        let red = Color::from_rgb(255, 0, 0);
+        let deutan = red.simulate(ColorVisionDeficiency::Deuteranopia);
+        let t = deutan.get_truecolor();
+        // Red loses its distinctness from green: it turns a dark olive/yellow.
+        assert_eq!((t.red, t.green, t.blue), (163, 144, 0));
+
+        let protan = red
+            .simulate(ColorVisionDeficiency::Protanopia)
+            .get_truecolor();
+        assert_eq!((protan.red, protan.green, protan.blue), (109, 95, 0));
+
+        // Standard colors resolve through the palette first.
+        let named = Color::parse("blue").unwrap();
+        assert_eq!(
+            named.simulate(ColorVisionDeficiency::Tritanopia).color_type,
+            ColorType::TrueColor
+        );
+
+        assert!(
+            Color::default_color()
+                .simulate(ColorVisionDeficiency::Protanopia)
+                .is_default()
+        );
+    }
+
+    #[test]
+    fn test_simulate_preserves_grays() {
+        for deficiency in [
+            ColorVisionDeficiency::Protanopia,
+            ColorVisionDeficiency::Deuteranopia,
+            ColorVisionDeficiency::Tritanopia,
+        ] {
+            for level in (0..=255u8).step_by(17) {
+                let gray = Color::from_rgb(level, level, level);
+                assert_eq!(gray.simulate(deficiency), gray, "{deficiency:?} {level}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_best_contrast_empty_candidates() {
+        let navy = Color::parse("navy_blue").unwrap();
+        assert_eq!(navy.best_contrast(&[]), Color::from_rgb(255, 255, 255));
+    }
 }
@@ -78,30 +78,60 @@
 //!
 //! You can override these with the builder pattern or by setting explicit values.
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt::Write as FmtWrite;
 use std::io::{self, Write};
 use std::sync::{
-    Arc, Mutex, Weak,
+    Arc, Mutex, MutexGuard, Weak,
     atomic::{AtomicBool, Ordering},
 };
-use time::OffsetDateTime;
+use std::time::{Duration, Instant, SystemTime};
+use time::{OffsetDateTime, UtcOffset};
 
-use crate::color::{ColorSystem, DEFAULT_TERMINAL_THEME, SVG_EXPORT_THEME, TerminalTheme};
+use crate::ansi::AnsiDecoder;
+use crate::color::{
+    ColorSystem, ColorVisionDeficiency, DEFAULT_TERMINAL_THEME, DowngradeMethod, SVG_EXPORT_THEME,
+    TerminalTheme,
+};
 use crate::emoji;
 use crate::highlighter::{Highlighter, ReprHighlighter};
-use crate::live::LiveInner;
+use crate::interactive::{
+    DEFAULT_MAX_INPUT_LENGTH, EchoGuard, Key, PromptError, next_key, read_secret,
+};
+use crate::live::{LiveInner, LiveSuspension};
 use crate::markup;
 use crate::measure::{Measurement, RichMeasure};
 use crate::protocol::{RichCast, RichCastOutput};
 use crate::renderables::Renderable;
-use crate::segment::{ControlCode, ControlType, Segment};
+use crate::renderables::align::{Align, AlignMethod};
+use crate::renderables::control::Control;
+use crate::renderables::group::Group;
+use crate::renderables::rule::Rule;
+use crate::renderables::table::{Table, ToRow};
+use crate::renderables::tree::{Tree, TreeNode};
+use crate::segment::{ControlCode, ControlType, Segment, segments_to_plain};
 use crate::style::{Attributes, Style, StyleParseError};
 use crate::sync::lock_recover;
-use crate::terminal;
-use crate::text::{JustifyMethod, OverflowMethod, Text};
+use crate::terminal::{self, Background, TerminalEnvironment};
+use crate::text::{JustifyMethod, OverflowMethod, Text, WordOptions};
 use crate::theme::{Theme, ThemeStack, ThemeStackError};
 
+/// Status words styled by [`ConsoleBuilder::keyword_highlight`], with their theme style.
+const STATUS_KEYWORDS: [(&str, &[&str]); 3] = [
+    (
+        "keyword.error",
+        &["ERROR", "ERR", "FAIL", "FAILED", "FATAL"],
+    ),
+    ("keyword.warning", &["WARN", "WARNING"]),
+    ("keyword.success", &["OK", "PASS", "PASSED", "SUCCESS"]),
+];
+
+/// Deepest level [`Console::print_tree_of`] descends before eliding children.
+const PRINT_TREE_MAX_DEPTH: usize = 64;
+
+/// How long a visual bell keeps the display reversed.
+const VISUAL_BELL_DURATION: Duration = Duration::from_millis(100);
+
 /// Console dimensions in cells.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct ConsoleDimensions {
@@ -232,6 +262,8 @@ pub struct PrintOptions {
     pub crop: bool,
     /// Soft wrap at width.
     pub soft_wrap: bool,
+    /// Importance of this output; dropped when above the console's verbosity.
+    pub level: Verbosity,
 }
 
 impl PrintOptions {
@@ -335,6 +367,13 @@ impl PrintOptions {
         self.soft_wrap = soft_wrap;
         self
     }
+
+    /// Tag the output with a verbosity level (see [`ConsoleBuilder::verbosity`]).
+    #[must_use]
+    pub fn with_level(mut self, level: Verbosity) -> Self {
+        self.level = level;
+        self
+    }
 }
 
 impl std::fmt::Debug for PrintOptions {
@@ -356,6 +395,7 @@ impl std::fmt::Debug for PrintOptions {
             .field("width", &self.width)
             .field("crop", &self.crop)
             .field("soft_wrap", &self.soft_wrap)
+            .field("level", &self.level)
             .finish()
     }
 }
@@ -418,22 +458,56 @@ pub struct Console {
     highlight: bool,
     /// Highlighter used when `highlight` is enabled (Python Rich `rich.highlighter` parity).
     highlighter: Arc<dyn Highlighter>,
+    /// Additional highlighters applied after `highlighter`, in insertion order.
+    extra_highlighters: Mutex<Vec<Arc<dyn Highlighter>>>,
     /// Theme stack for named styles (Python Rich parity).
     theme_stack: Mutex<ThemeStack>,
-    /// Override width.
-    width: Option<usize>,
-    /// Override height.
-    height: Option<usize>,
+    /// Override width (see [`Console::set_size`]).
+    width: Mutex<Option<usize>>,
+    /// Override height (see [`Console::set_size`]).
+    height: Mutex<Option<usize>>,
     /// Use ASCII-safe box characters.
     safe_box: bool,
+    /// Output character encoding (e.g. `"utf-8"`).
+    encoding: String,
     /// Output stream (defaults to stdout).
-    file: Mutex<Box<dyn Write + Send>>,
+    file: Arc<Mutex<Box<dyn Write + Send>>>,
+    /// A screen-wide visual bell is showing and still has to be undone.
+    flash_pending: Arc<AtomicBool>,
     /// Recording buffer.
     buffer: Mutex<Vec<Segment<'static>>>,
     /// Cached terminal detection.
     is_terminal: bool,
     /// Detected/configured color system.
     detected_color_system: Option<ColorSystem>,
+    /// Detected/configured terminal background.
+    background: Option<Background>,
+    /// Force OSC 9 notification/progress support (None = auto-detect).
+    notifications: Option<bool>,
+    /// Line terminator written for newlines.
+    line_ending: LineEnding,
+    /// Marker drawn in the last cell of lines cropped in no-wrap mode.
+    overflow_marker: Option<char>,
+    /// Dim marker leading each soft-wrapped continuation line.
+    wrap_continuation: Option<char>,
+    /// Flash the display instead of sounding the bell.
+    visual_bell: bool,
+    /// Style status words like `ERROR` and `OK` in printed text.
+    keyword_highlight: bool,
+    /// Color vision deficiency simulated on every emitted color.
+    color_vision_filter: Option<ColorVisionDeficiency>,
+    /// How truecolor is mapped onto 256- and 16-color palettes.
+    downgrade_method: DowngradeMethod,
+    /// Most detailed [`PrintOptions::level`] that is still printed.
+    verbosity: Verbosity,
+    /// Translates styled segments into bytes for the output stream.
+    encoder: Box<dyn OutputEncoder>,
+    /// Cached OSC 9 support detection.
+    notifications_detected: bool,
+    /// Time source for log timestamps.
+    clock: Arc<dyn Clock>,
+    /// Emit OSC 8 hyperlinks for styled links.
+    hyperlinks: bool,
     /// Render hooks (Live uses this).
     render_hooks: Mutex<Vec<Arc<dyn RenderHook>>>,
     /// Active Live stack for nested Live handling.
@@ -450,13 +524,30 @@ impl std::fmt::Debug for Console {
             .field("markup", &self.markup)
             .field("emoji", &self.emoji)
             .field("highlight", &self.highlight)
-            .field("width", &self.width)
-            .field("height", &self.height)
+            .field(
+                "extra_highlighters",
+                &lock_recover(&self.extra_highlighters).len(),
+            )
+            .field("width", &*lock_recover(&self.width))
+            .field("height", &*lock_recover(&self.height))
             .field("safe_box", &self.safe_box)
+            .field("encoding", &self.encoding)
             .field("file", &"<dyn Write>")
             .field("buffer_len", &lock_recover(&self.buffer).len())
             .field("is_terminal", &self.is_terminal)
             .field("detected_color_system", &self.detected_color_system)
+            .field("background", &self.background)
+            .field("notifications", &self.notifications)
+            .field("notifications_detected", &self.notifications_detected)
+            .field("line_ending", &self.line_ending)
+            .field("overflow_marker", &self.overflow_marker)
+            .field("wrap_continuation", &self.wrap_continuation)
+            .field("visual_bell", &self.visual_bell)
+            .field("keyword_highlight", &self.keyword_highlight)
+            .field("color_vision_filter", &self.color_vision_filter)
+            .field("downgrade_method", &self.downgrade_method)
+            .field("verbosity", &self.verbosity)
+            .field("hyperlinks", &self.hyperlinks)
             .finish_non_exhaustive()
     }
 }
@@ -467,34 +558,75 @@ impl Default for Console {
     }
 }
 
+impl Drop for Console {
+    fn drop(&mut self) {
+        drop(self.lock_file());
+    }
+}
+
 impl Console {
     /// Create a new console with default settings.
     #[must_use]
     pub fn new() -> Self {
-        let is_terminal = terminal::is_terminal();
-        let detected_color_system = if is_terminal {
-            terminal::detect_color_system()
+        Self::from_environment(&TerminalEnvironment::detect(), true)
+    }
+
+    /// Create a console for `env`.
+    ///
+    /// A `detected` snapshot describes this process's stdout, so the size
+    /// keeps following the terminal. An injected one only simulates a
+    /// terminal: its size is fixed and it never counts as a real TTY for
+    /// process-wide stdio redirection.
+    fn from_environment(env: &TerminalEnvironment, detected: bool) -> Self {
+        // Keep the locale's charset for the real terminal; an injected
+        // environment only says whether Unicode is available.
+        let encoding = if detected {
+            terminal::detect_encoding()
+        } else {
+            String::from(if env.unicode { "utf-8" } else { "ascii" })
+        };
+        let (width, height) = if detected {
+            (None, None)
         } else {
-            None
+            (Some(env.width), Some(env.height))
         };
+        let background = terminal::detect_background();
+        let theme = background.map_or_else(Theme::default, Theme::for_background);
 
         Self {
             color_system: None,
-            force_terminal: None,
+            force_terminal: (!detected).then_some(env.is_tty),
             tab_size: 8,
             record: AtomicBool::new(false),
             markup: true,
             emoji: true,
             highlight: true,
             highlighter: Arc::new(ReprHighlighter::default()),
-            theme_stack: Mutex::new(ThemeStack::new(Theme::default())),
-            width: None,
-            height: None,
+            extra_highlighters: Mutex::new(Vec::new()),
+            theme_stack: Mutex::new(ThemeStack::new(theme)),
+            width: Mutex::new(width),
+            height: Mutex::new(height),
             safe_box: false,
-            file: Mutex::new(Box::new(io::stdout())),
+            encoding,
+            file: Arc::new(Mutex::new(Box::new(io::stdout()))),
+            flash_pending: Arc::new(AtomicBool::new(false)),
             buffer: Mutex::new(Vec::new()),
-            is_terminal,
-            detected_color_system,
+            is_terminal: detected && env.is_tty,
+            detected_color_system: env.color_system,
+            background,
+            notifications: None,
+            line_ending: LineEnding::Lf,
+            overflow_marker: None,
+            wrap_continuation: None,
+            visual_bell: false,
+            keyword_highlight: false,
+            color_vision_filter: None,
+            downgrade_method: DowngradeMethod::default(),
+            verbosity: Verbosity::default(),
+            encoder: Box::new(AnsiEncoder),
+            notifications_detected: terminal::supports_osc9(),
+            clock: Arc::new(SystemClock),
+            hyperlinks: env.hyperlinks,
             render_hooks: Mutex::new(Vec::new()),
             live_stack: Mutex::new(Vec::new()),
         }
@@ -515,13 +647,23 @@ impl Console {
     /// Get the console width.
     #[must_use]
     pub fn width(&self) -> usize {
-        self.width.unwrap_or_else(terminal::get_terminal_width)
+        lock_recover(&self.width).unwrap_or_else(terminal::get_terminal_width)
     }
 
     /// Get the console height.
     #[must_use]
     pub fn height(&self) -> usize {
-        self.height.unwrap_or_else(terminal::get_terminal_height)
+        lock_recover(&self.height).unwrap_or_else(terminal::get_terminal_height)
+    }
+
+    /// Override the console dimensions after construction.
+    ///
+    /// Useful when the size is tracked externally, e.g. from a resize event;
+    /// follow with [`Live::notify_resize`](crate::live::Live::notify_resize)
+    /// to redraw an active live display at the new size.
+    pub fn set_size(&self, width: usize, height: usize) {
+        *lock_recover(&self.width) = Some(width);
+        *lock_recover(&self.height) = Some(height);
     }
 
     /// Get the console dimensions.
@@ -554,6 +696,15 @@ impl Console {
         self.color_system.or(self.detected_color_system)
     }
 
+    /// Get the terminal background (dark or light), if known.
+    ///
+    /// Uses the builder override when set, otherwise the `COLORFGBG`
+    /// environment variable. The base theme is chosen to match.
+    #[must_use]
+    pub const fn detect_background(&self) -> Option<Background> {
+        self.background
+    }
+
     /// Check if Rich-style emoji code replacement is enabled.
     #[must_use]
     pub const fn emoji(&self) -> bool {
@@ -566,6 +717,29 @@ impl Console {
         self.safe_box
     }
 
+    /// Get the output character encoding (e.g. `"utf-8"`).
+    #[must_use]
+    pub fn encoding(&self) -> &str {
+        &self.encoding
+    }
+
+    /// Check whether the output encoding can represent Unicode glyphs.
+    ///
+    /// When it can't, panels, tables, trees, progress bars, and spinners fall
+    /// back to ASCII so box drawing doesn't turn into mojibake.
+    #[must_use]
+    pub fn supports_unicode(&self) -> bool {
+        self.encoding.starts_with("utf")
+    }
+
+    /// Check whether styled links are emitted as OSC 8 hyperlinks.
+    ///
+    /// When disabled, the link text is still printed with its other styling.
+    #[must_use]
+    pub const fn supports_hyperlinks(&self) -> bool {
+        self.hyperlinks
+    }
+
     /// Get a style by theme name or parse a style definition.
     ///
     /// Mirrors Python Rich `Console.get_style()`:
@@ -612,12 +786,30 @@ impl Console {
         self.color_system().is_some()
     }
 
+    /// Get the verbosity threshold set with [`ConsoleBuilder::verbosity`].
+    #[must_use]
+    pub const fn verbosity(&self) -> Verbosity {
+        self.verbosity
+    }
+
+    /// Check whether output tagged with `level` would be printed.
+    #[must_use]
+    pub fn is_enabled_for(&self, level: Verbosity) -> bool {
+        level <= self.verbosity
+    }
+
     /// Get the tab size.
     #[must_use]
     pub const fn tab_size(&self) -> usize {
         self.tab_size
     }
 
+    /// Get the time source set with [`ConsoleBuilder::clock`].
+    #[must_use]
+    pub fn clock(&self) -> Arc<dyn Clock> {
+        Arc::clone(&self.clock)
+    }
+
     /// Create console options for rendering.
     #[must_use]
     pub fn options(&self) -> ConsoleOptions {
@@ -627,7 +819,7 @@ impl Console {
             min_width: 1,
             max_width: self.width(),
             is_terminal: self.is_terminal(),
-            encoding: String::from("utf-8"),
+            encoding: self.encoding.clone(),
             max_height: self.height(),
             justify: None,
             overflow: None,
@@ -638,10 +830,43 @@ impl Console {
         }
     }
 
+    /// Append a highlighter to the pipeline applied to all highlighted text.
+    ///
+    /// Added highlighters run after the base highlighter (see
+    /// [`ConsoleBuilder::highlighter`]) in insertion order, whenever
+    /// highlighting is enabled for a print call.
+    pub fn add_highlighter(&self, highlighter: Box<dyn Highlighter>) {
+        lock_recover(&self.extra_highlighters).push(Arc::from(highlighter));
+    }
+
     pub(crate) fn apply_highlighter_to_text(&self, options: &ConsoleOptions, text: &mut Text) {
         let highlight_enabled = options.highlight.unwrap_or(self.highlight);
         if highlight_enabled {
-            self.highlighter.highlight(self, text);
+            self.run_highlighters(self.highlighter.as_ref(), text);
+        }
+        self.apply_keyword_styles(text);
+    }
+
+    /// Style status keywords when [`ConsoleBuilder::keyword_highlight`] is on.
+    fn apply_keyword_styles(&self, text: &mut Text) {
+        if !self.keyword_highlight {
+            return;
+        }
+        let options = WordOptions {
+            case_insensitive: false,
+            whole_word: true,
+        };
+        for (style_name, words) in STATUS_KEYWORDS {
+            text.highlight_words(words, &self.get_style(style_name), options);
+        }
+    }
+
+    /// Run `base` followed by every highlighter added via [`Self::add_highlighter`].
+    fn run_highlighters(&self, base: &dyn Highlighter, text: &mut Text) {
+        base.highlight(self, text);
+        let extra = lock_recover(&self.extra_highlighters).clone();
+        for highlighter in extra {
+            highlighter.highlight(self, text);
         }
     }
 
@@ -656,6 +881,47 @@ impl Console {
         Measurement::get(self, &options, Some(renderable))
     }
 
+    /// Wrap an iterator so a progress bar advances as its items are consumed.
+    ///
+    /// This is Python Rich's `track`: items are yielded unchanged while a bar
+    /// sized from `total` (or the iterator's exact length) is drawn via a
+    /// transient [`Live`](crate::live::Live) display on interactive consoles.
+    ///
+    /// ```rust,ignore
+    /// let console = Console::new().shared();
+    /// for path in console.track(paths, None) {
+    ///     process(path);
+    /// }
+    /// ```
+    pub fn track<I: IntoIterator>(
+        self: &Arc<Self>,
+        iter: I,
+        total: Option<u64>,
+    ) -> crate::renderables::Track<I::IntoIter> {
+        crate::renderables::Track::new(self, iter.into_iter(), total)
+    }
+
+    /// Render a renderable at `width` and split the output into visual lines.
+    ///
+    /// Each inner vector holds one line's segments with the line breaks
+    /// removed, so callers can paginate, clip, or lay out the result. A
+    /// trailing newline does not produce an extra empty line.
+    #[must_use]
+    pub fn render_lines(
+        &self,
+        renderable: &impl Renderable,
+        width: usize,
+    ) -> Vec<Vec<Segment<'static>>> {
+        let options = self.options().update_width(width);
+        let segments = renderable
+            .render(self, &options)
+            .into_iter()
+            .map(Segment::into_owned);
+        let mut lines = crate::segment::split_lines(segments);
+        crate::segment::pop_trailing_empty_line(&mut lines);
+        lines
+    }
+
     /// Check if the terminal is "dumb".
     #[must_use]
     pub fn is_dumb_terminal(&self) -> bool {
@@ -701,29 +967,122 @@ impl Console {
         result
     }
 
+    /// Erase the active live display until the returned guard is dropped.
+    pub(crate) fn suspend_live(&self) -> LiveSuspension {
+        LiveSuspension::new(self.live_stack_snapshot().into_iter().next())
+    }
+
     pub(crate) fn write_control_codes(&self, control_codes: Vec<ControlCode>) -> io::Result<()> {
         if control_codes.is_empty() {
             return Ok(());
         }
         let segment = Segment::control(control_codes);
-        let mut file = lock_recover(&self.file);
+        let mut file = self.lock_file();
         self.write_segments_raw(&mut *file, &[segment])
     }
 
+    /// Redraw the active Live display by writing nothing through the render hooks.
+    ///
+    /// Not subject to verbosity: a Live display draws even in quiet mode.
+    pub(crate) fn refresh_live(&self) -> io::Result<()> {
+        let mut file = self.lock_file();
+        self.write_segments_hooked(&mut *file, &[])
+    }
+
     pub(crate) fn swap_file(&self, writer: Box<dyn Write + Send>) -> Box<dyn Write + Send> {
-        std::mem::replace(&mut *lock_recover(&self.file), writer)
+        std::mem::replace(&mut *self.lock_file(), writer)
+    }
+
+    /// Collect all output in memory until the returned guard is flushed or dropped.
+    ///
+    /// Each print normally reaches the output (and the OS) straight away;
+    /// inside a buffered block the output is written in one go instead, which
+    /// cuts syscalls for animation frames or large tables.
+    ///
+    /// ```rust,ignore
+    /// {
+    ///     let _buffered = console.buffered();
+    ///     for row in rows {
+    ///         console.print(row);
+    ///     }
+    /// } // everything is written here
+    /// ```
+    #[must_use]
+    pub fn buffered(&self) -> BufferedGuard<'_> {
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let inner = self.swap_file(Box::new(BufferWriter(Arc::clone(&buffer))));
+        BufferedGuard {
+            console: self,
+            buffer,
+            inner: Some(inner),
+        }
     }
 
     /// Show or hide the cursor.
+    ///
+    /// Does nothing when the console isn't a terminal.
     pub fn show_cursor(&self, show: bool) -> io::Result<()> {
         let control = if show {
             ControlCode::new(ControlType::ShowCursor)
         } else {
             ControlCode::new(ControlType::HideCursor)
         };
+        self.control(control)
+    }
+
+    /// Write a single terminal control code (cursor movement, scrolling, title, ...).
+    ///
+    /// Does nothing when the console isn't a terminal, so control sequences
+    /// never leak into pipes or files.
+    ///
+    /// ```rust,ignore
+    /// use rich_rust::segment::{ControlCode, ControlType};
+    ///
+    /// console.control(ControlCode::with_params_vec(ControlType::CursorMoveTo, vec![0, 0]))?;
+    /// console.control(ControlCode::with_params_vec(ControlType::ScrollUp, vec![3]))?;
+    /// ```
+    pub fn control(&self, control: ControlCode) -> io::Result<()> {
+        if !self.is_terminal() {
+            return Ok(());
+        }
         self.write_control_codes(vec![control])
     }
 
+    /// Save the cursor position so [`Console::restore_cursor`] can return to it.
+    ///
+    /// Does nothing when the console isn't a terminal.
+    pub fn save_cursor(&self) -> io::Result<()> {
+        self.control(ControlCode::new(ControlType::SaveCursor))
+    }
+
+    /// Return the cursor to the position saved by [`Console::save_cursor`].
+    ///
+    /// Does nothing when the console isn't a terminal.
+    pub fn restore_cursor(&self) -> io::Result<()> {
+        self.control(ControlCode::new(ControlType::RestoreCursor))
+    }
+
+    /// Move the cursor relative to its current position.
+    ///
+    /// Positive `dx` moves right and positive `dy` moves down. Does nothing
+    /// when the console isn't a terminal.
+    pub fn move_cursor(&self, dx: i32, dy: i32) -> io::Result<()> {
+        if !self.is_terminal() {
+            return Ok(());
+        }
+        self.write_control_codes(Control::r#move(dx, dy).into_codes())
+    }
+
+    /// Move the cursor to a 0-based `(col, row)` position on the screen.
+    ///
+    /// Does nothing when the console isn't a terminal.
+    pub fn move_to(&self, col: i32, row: i32) -> io::Result<()> {
+        self.control(ControlCode::with_params_vec(
+            ControlType::CursorMoveTo,
+            vec![col, row],
+        ))
+    }
+
     /// Enable or disable the alternate screen buffer.
     pub fn set_alt_screen(&self, enable: bool) -> io::Result<()> {
         let control = if enable {
@@ -734,6 +1093,30 @@ impl Console {
         self.write_control_codes(vec![control])
     }
 
+    /// Switch to the alternate screen buffer for the lifetime of the returned guard.
+    ///
+    /// Emits `ESC[?1049h` now and `ESC[?1049l` plus a cursor restore when the
+    /// guard is dropped. Nothing is emitted when the console isn't a terminal.
+    ///
+    /// ```rust,ignore
+    /// let console = Console::new();
+    /// {
+    ///     let _screen = console.alternate_screen();
+    ///     console.print("full-screen UI");
+    /// } // original screen restored here
+    /// ```
+    #[must_use]
+    pub fn alternate_screen(&self) -> ScreenGuard<'_> {
+        let active = self.is_terminal();
+        if active {
+            let _ = self.set_alt_screen(true);
+        }
+        ScreenGuard {
+            console: self,
+            active,
+        }
+    }
+
     /// Enable recording mode.
     ///
     /// All subsequent console output will be captured to an internal buffer
@@ -768,12 +1151,15 @@ impl Console {
 
     /// Print a prepared Text object.
     pub fn print_text(&self, text: &Text) {
-        let mut file = lock_recover(&self.file);
+        let mut file = self.lock_file();
         let _ = self.print_text_to(&mut *file, text);
     }
 
     /// Print a prepared Text object to a specific writer.
     pub fn print_text_to<W: Write>(&self, writer: &mut W, text: &Text) -> io::Result<()> {
+        if !self.is_enabled_for(Verbosity::Normal) {
+            return Ok(());
+        }
         let segments: Vec<Segment<'static>> = text
             .render(&text.end)
             .into_iter()
@@ -785,7 +1171,7 @@ impl Console {
 
     /// Print prepared segments.
     pub fn print_segments(&self, segments: &[Segment<'_>]) {
-        let mut file = lock_recover(&self.file);
+        let mut file = self.lock_file();
         let _ = self.print_segments_to(&mut *file, segments);
     }
 
@@ -794,6 +1180,18 @@ impl Console {
         &self,
         writer: &mut W,
         segments: &[Segment<'_>],
+    ) -> io::Result<()> {
+        if !self.is_enabled_for(Verbosity::Normal) {
+            return Ok(());
+        }
+        self.write_segments_hooked(writer, segments)
+    }
+
+    /// Write segments through the render hooks, regardless of verbosity.
+    fn write_segments_hooked<W: Write>(
+        &self,
+        writer: &mut W,
+        segments: &[Segment<'_>],
     ) -> io::Result<()> {
         let owned: Vec<Segment<'static>> =
             segments.iter().cloned().map(Segment::into_owned).collect();
@@ -808,6 +1206,185 @@ impl Console {
         self.print_segments(&segments);
     }
 
+    /// Print a renderable with per-call options.
+    ///
+    /// `style` is composed underneath every segment, so segment styles win
+    /// on conflict, and `width` overrides the render width. A `Left`,
+    /// `Center`, or `Right` `justify` aligns each rendered line within that
+    /// width (see [`Align::renderable`]). The remaining options only affect
+    /// printed strings.
+    pub fn print_renderable_with_options(
+        &self,
+        renderable: &impl Renderable,
+        options: &PrintOptions,
+    ) {
+        if !self.is_enabled_for(options.level) {
+            return;
+        }
+        let segments = self.render_renderable_segments(renderable, options);
+        let mut file = self.lock_file();
+        let _ = self.write_segments_hooked(&mut *file, &segments);
+    }
+
+    /// Print typed items as a table, one row per item.
+    ///
+    /// Headers come from [`ToRow::columns`] and cells from [`ToRow::cells`].
+    pub fn print_table_of<T: ToRow>(&self, items: &[T]) {
+        self.print_renderable(&Table::of(items));
+    }
+
+    /// Print recursive data as a tree, walking it with the given closures.
+    ///
+    /// `children` lists a node's children and `label` renders its line.
+    /// A node that is already one of its own ancestors is marked `(cycle)`
+    /// and not expanded again, and children below 64 levels are elided
+    /// with a dim `…`.
+    pub fn print_tree_of<T>(
+        &self,
+        root: &T,
+        children: impl Fn(&T) -> Vec<&T>,
+        label: impl Fn(&T) -> Text,
+    ) {
+        let mut ancestors = HashSet::new();
+        let node = build_tree_node(root, &children, &label, &mut ancestors, 0);
+        self.print_renderable(&Tree::new(node));
+    }
+
+    /// Lay out renderables side by side when they fit, stacked otherwise.
+    ///
+    /// Each renderable is rendered at the console width and measured by its
+    /// widest line. If the widths plus a two-cell gutter fit on one line the
+    /// blocks are joined into a single row, top-aligned; otherwise they are
+    /// stacked vertically. The result is pre-rendered, so it does not borrow
+    /// the inputs.
+    #[must_use]
+    pub fn fit_renderables(&self, renderables: &[&dyn Renderable]) -> Group<'static> {
+        const GUTTER: usize = 2;
+
+        let width = self.width();
+        let blocks: Vec<Vec<Vec<Segment<'static>>>> = renderables
+            .iter()
+            .map(|renderable| self.render_lines(renderable, width))
+            .collect();
+        let widths: Vec<usize> = blocks
+            .iter()
+            .map(|lines| {
+                lines
+                    .iter()
+                    .map(|line| line.iter().map(Segment::cell_length).sum())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect();
+        let row_width = widths.iter().sum::<usize>() + GUTTER * widths.len().saturating_sub(1);
+
+        if blocks.len() < 2 || row_width > width {
+            return blocks.into_iter().fold(Group::new(), |group, lines| {
+                group.push(RenderedLines(lines))
+            });
+        }
+
+        let height = blocks.iter().map(Vec::len).max().unwrap_or(0);
+        let mut rows = Vec::with_capacity(height);
+        for row in 0..height {
+            let mut line = Vec::new();
+            for (index, (lines, &block_width)) in blocks.iter().zip(&widths).enumerate() {
+                if index > 0 {
+                    line.push(Segment::new(" ".repeat(GUTTER), None));
+                }
+                let cells = lines.get(row).cloned().unwrap_or_default();
+                line.extend(crate::segment::adjust_line_length(
+                    cells,
+                    block_width,
+                    None,
+                    true,
+                ));
+            }
+            rows.push(line);
+        }
+        Group::new().push(RenderedLines(rows))
+    }
+
+    /// Print `key : value` rows with the keys right-aligned to a common width.
+    ///
+    /// Keys are dimmed. Values wrap to the console width, with continuation
+    /// lines indented under the value column:
+    ///
+    /// ```text
+    ///        Name : rich_rust
+    /// Description : A Rust port of Python's Rich
+    ///               library.
+    /// ```
+    pub fn print_key_values(&self, items: &[(&str, Text)]) {
+        const SEPARATOR: &str = " : ";
+
+        let key_width = items
+            .iter()
+            .map(|(key, _)| crate::cells::cell_len(key))
+            .max()
+            .unwrap_or(0);
+        let indent = key_width + SEPARATOR.len();
+        let value_width = self.width().saturating_sub(indent).max(1);
+        let key_style = Style::new().dim();
+
+        let mut output = Text::new("");
+        for (index, (key, value)) in items.iter().enumerate() {
+            if index > 0 {
+                output.append("\n");
+            }
+            output.append(&" ".repeat(key_width - crate::cells::cell_len(key)));
+            output.append_styled(key, key_style.clone());
+            output.append(SEPARATOR);
+            for (line_index, line) in value.wrap(value_width).iter().enumerate() {
+                if line_index > 0 {
+                    output.append("\n");
+                    output.append(&" ".repeat(indent));
+                }
+                output.append_text(line);
+            }
+        }
+        self.print_text(&output);
+    }
+
+    /// Render a renderable honoring the `width` and `style` print options.
+    fn render_renderable_segments<R: Renderable + ?Sized>(
+        &self,
+        renderable: &R,
+        options: &PrintOptions,
+    ) -> Vec<Segment<'static>> {
+        let mut render_options = self.options();
+        if let Some(width) = options.width {
+            render_options = render_options.update_width(width);
+        }
+        let align = match options.justify {
+            Some(JustifyMethod::Left) => Some(AlignMethod::Left),
+            Some(JustifyMethod::Center) => Some(AlignMethod::Center),
+            Some(JustifyMethod::Right) => Some(AlignMethod::Right),
+            Some(JustifyMethod::Default | JustifyMethod::Full) | None => None,
+        };
+        let rendered = match align {
+            Some(method) => Align::renderable(renderable, self, method, render_options.max_width),
+            None => renderable.render(self, &render_options),
+        };
+        let mut segments: Vec<Segment<'static>> =
+            rendered.into_iter().map(Segment::into_owned).collect();
+        Self::apply_base_style(&mut segments, options.style.as_ref());
+        segments
+    }
+
+    /// Compose `style` underneath the style of every non-control segment.
+    fn apply_base_style(segments: &mut [Segment<'static>], style: Option<&Style>) {
+        let Some(style) = style else {
+            return;
+        };
+        for segment in segments.iter_mut().filter(|segment| !segment.is_control()) {
+            segment.style = Some(match segment.style {
+                Some(ref s) => style.combine(s),
+                None => style.clone(),
+            });
+        }
+    }
+
     fn render_rich_cast_segments(
         &self,
         value: &dyn RichCast,
@@ -816,20 +1393,10 @@ impl Console {
         match crate::protocol::rich_cast(value) {
             RichCastOutput::Str(text) => self.render_str_segments(&text, options),
             RichCastOutput::Renderable(renderable) => {
-                let options = self.options();
-                renderable
-                    .render(self, &options)
-                    .into_iter()
-                    .map(Segment::into_owned)
-                    .collect()
+                self.render_renderable_segments(&*renderable, options)
             }
             RichCastOutput::Castable(renderable) => {
-                let options = self.options();
-                renderable
-                    .render(self, &options)
-                    .into_iter()
-                    .map(Segment::into_owned)
-                    .collect()
+                self.render_renderable_segments(&*renderable, options)
             }
         }
     }
@@ -841,7 +1408,7 @@ impl Console {
 
     /// Print a castable value with custom options (string options apply when the cast yields a string).
     pub fn print_cast_with_options(&self, value: &dyn RichCast, options: &PrintOptions) {
-        let mut file = lock_recover(&self.file);
+        let mut file = self.lock_file();
         let _ = self.print_cast_to(&mut *file, value, options);
     }
 
@@ -852,6 +1419,9 @@ impl Console {
         value: &dyn RichCast,
         options: &PrintOptions,
     ) -> io::Result<()> {
+        if !self.is_enabled_for(options.level) {
+            return Ok(());
+        }
         let segments = self.render_rich_cast_segments(value, options);
         let segments = self.apply_render_hooks(segments);
         self.write_segments_raw(writer, &segments)
@@ -860,13 +1430,67 @@ impl Console {
     /// Print an exception / traceback renderable.
     ///
     /// This is a convenience wrapper mirroring Python Rich's `Console.print_exception`.
+    /// Like [`Console::print_error`], it is shown even in quiet mode.
     pub fn print_exception(&self, traceback: &crate::renderables::Traceback) {
-        self.print_renderable(traceback);
+        let options = self.options();
+        self.write_error_segments(&Renderable::render(traceback, self, &options));
+    }
+
+    /// Print an error and its [`source`](std::error::Error::source) chain.
+    ///
+    /// The error itself is shown in a red bordered "Error" panel, and each cause
+    /// follows in its own "Caused by" panel with the type name styled. When
+    /// `RUST_BACKTRACE` is set (and not `0`), a captured backtrace is printed
+    /// after the chain.
+    ///
+    /// ```rust,ignore
+    /// if let Err(err) = run() {
+    ///     console.print_error(&err);
+    /// }
+    /// ```
+    pub fn print_error(&self, err: &dyn std::error::Error) {
+        let width = self.width();
+        let border_style = Style::parse("red").unwrap_or_default();
+        let type_style = Style::parse("bold bright_red").unwrap_or_default();
+
+        let mut current = Some(err);
+        let mut title = "Error";
+        while let Some(error) = current {
+            let message = error.to_string();
+            let mut lines = message.lines();
+            let mut content_lines = vec![vec![
+                Segment::new(error_type_name(error), Some(type_style.clone())),
+                Segment::new(": ", None),
+                Segment::new(lines.next().unwrap_or_default().to_string(), None),
+            ]];
+            content_lines.extend(lines.map(|line| vec![Segment::new(line.to_string(), None)]));
+
+            let panel = crate::renderables::Panel::new(content_lines)
+                .title(title)
+                .border_style(border_style.clone())
+                .width(width);
+            self.write_error_segments(&panel.render(width));
+
+            current = error.source();
+            title = "Caused by";
+        }
+
+        if backtrace_requested(std::env::var("RUST_BACKTRACE").ok().as_deref()) {
+            let backtrace = std::backtrace::Backtrace::force_capture().to_string();
+            let text = Text::styled(backtrace, Style::new().dim());
+            self.write_error_segments(&text.render(&text.end));
+        }
+    }
+
+    /// Errors stay visible in quiet mode, so skip the verbosity filter.
+    fn write_error_segments(&self, segments: &[Segment<'_>]) {
+        let mut file = self.lock_file();
+        let _ = self.write_segments_hooked(&mut *file, segments);
     }
 
     /// Print with custom options.
     pub fn print_with_options(&self, content: &str, options: &PrintOptions) {
-        let mut file = lock_recover(&self.file);
+        let mut file = self.lock_file();
         // Keep `Console::print_*` infallible (matches Rich's ergonomics). If callers need
         // I/O error handling they can use `Console::print_to(...)` directly.
         let _ = self.print_to(&mut *file, content, options);
@@ -882,7 +1506,7 @@ impl Console {
     #[must_use]
     pub fn export_text_with_options(&self, content: &str, options: &PrintOptions) -> String {
         let segments = self.render_str_segments(content, options);
-        Self::segments_to_plain(&segments)
+        segments_to_plain(&segments)
     }
 
     /// Export a castable value to plain text (no ANSI).
@@ -899,7 +1523,7 @@ impl Console {
         options: &PrintOptions,
     ) -> String {
         let segments = self.render_rich_cast_segments(value, options);
-        Self::segments_to_plain(&segments)
+        segments_to_plain(&segments)
     }
 
     /// Export a renderable to plain text (no ANSI).
@@ -907,7 +1531,27 @@ impl Console {
     pub fn export_renderable_text(&self, renderable: &impl Renderable) -> String {
         let options = self.options();
         let segments = renderable.render(self, &options);
-        Self::segments_to_plain(&segments)
+        segments_to_plain(&segments)
+    }
+
+    /// Render a renderable to plain text, e.g. for assertions in tests.
+    ///
+    /// Like [`Console::export_renderable_text`], but escape sequences embedded
+    /// in segment text (such as pre-styled strings) are stripped too, so the
+    /// result holds only the visible characters. Padding follows cell widths,
+    /// so wide characters line up as they would on screen.
+    #[must_use]
+    pub fn render_to_plain(&self, renderable: &impl Renderable) -> String {
+        let plain = self.export_renderable_text(renderable);
+        if !plain.contains('\x1b') {
+            return plain;
+        }
+        let mut decoder = AnsiDecoder::new();
+        plain
+            .split('\n')
+            .map(|line| decoder.decode_line(line).plain().to_string())
+            .collect::<Vec<_>>()
+            .join("\n")
     }
 
     /// Export recorded output to HTML.
@@ -961,6 +1605,9 @@ impl Console {
         content: &str,
         options: &PrintOptions,
     ) -> io::Result<()> {
+        if !self.is_enabled_for(options.level) {
+            return Ok(());
+        }
         let segments = self.render_str_segments(content, options);
         let segments = self.apply_render_hooks(segments);
         self.write_segments_raw(writer, &segments)
@@ -986,8 +1633,9 @@ impl Console {
         let highlight_enabled = options.highlight.unwrap_or(self.highlight);
         if highlight_enabled {
             let highlighter = options.highlighter.as_ref().unwrap_or(&self.highlighter);
-            highlighter.highlight(self, &mut text);
+            self.run_highlighters(highlighter.as_ref(), &mut text);
         }
+        self.apply_keyword_styles(&mut text);
 
         if let Some(justify) = options.justify {
             text.justify = justify;
@@ -1025,7 +1673,7 @@ impl Console {
             let lines = if text.no_wrap {
                 text.split_lines()
             } else {
-                text.wrap(width)
+                self.wrap_with_continuation(&text, width)
             };
             let last_index = lines.len().saturating_sub(1);
             let justify = match text.justify {
@@ -1035,7 +1683,23 @@ impl Console {
 
             for (index, mut line) in lines.into_iter().enumerate() {
                 if text.no_wrap && line.cell_len() > width {
-                    line.truncate(width, line.overflow, false);
+                    match self.overflow_marker {
+                        Some(marker)
+                            if matches!(
+                                line.overflow,
+                                OverflowMethod::Fold | OverflowMethod::Crop
+                            ) =>
+                        {
+                            let marker_width = crate::cells::get_character_cell_size(marker);
+                            line.truncate(
+                                width.saturating_sub(marker_width),
+                                OverflowMethod::Crop,
+                                false,
+                            );
+                            line.append(marker.encode_utf8(&mut [0; 4]));
+                        }
+                        _ => line.truncate(width, line.overflow, false),
+                    }
                 }
 
                 if matches!(
@@ -1059,33 +1723,48 @@ impl Console {
         };
 
         // Apply any overall style
-        if let Some(ref style) = options.style {
-            for segment in &mut segments {
-                if !segment.is_control() {
-                    segment.style = Some(match segment.style {
-                        Some(ref s) => style.combine(s),
-                        None => style.clone(),
-                    });
-                }
-            }
-        }
+        Self::apply_base_style(&mut segments, options.style.as_ref());
 
         segments
     }
 
-    fn segments_to_plain(segments: &[Segment<'_>]) -> String {
-        let capacity: usize = segments
-            .iter()
-            .filter(|segment| !segment.is_control())
-            .map(|segment| segment.text.len())
-            .sum();
-        let mut output = String::with_capacity(capacity);
-        for segment in segments {
-            if !segment.is_control() {
-                output.push_str(segment.text.as_ref());
+    /// Wrap `text` to `width`, marking soft-wrapped lines with
+    /// [`ConsoleBuilder::wrap_continuation`].
+    ///
+    /// The first line of each paragraph uses the full width; the marker
+    /// takes the first cell of every continuation line, so the rest is
+    /// wrapped one marker-width narrower. Hard line breaks start a fresh,
+    /// unmarked line. Only [`OverflowMethod::Fold`] text continues; cropped
+    /// and ellipsized text keeps its single truncated line.
+    fn wrap_with_continuation(&self, text: &Text, width: usize) -> Vec<Text> {
+        let Some(marker) = self.wrap_continuation else {
+            return text.wrap(width);
+        };
+        if text.overflow != OverflowMethod::Fold || width == 0 {
+            return text.wrap(width);
+        }
+        let marker_width = crate::cells::get_character_cell_size(marker);
+        let inner_width = width.saturating_sub(marker_width).max(1);
+
+        let mut lines = Vec::new();
+        for paragraph in text.split_lines() {
+            let paragraph = paragraph.expand_tabs(paragraph.tab_size);
+            let (first, rest_start) = paragraph.fold_first_line(width);
+            let rest = paragraph.slice(rest_start, paragraph.len());
+            lines.push(first);
+            if rest.is_empty() {
+                continue;
+            }
+            for line in rest.wrap(inner_width) {
+                let mut continued = Text::styled(marker.to_string(), Style::new().dim());
+                continued.set_style(line.style().clone());
+                continued.justify = line.justify;
+                continued.overflow = line.overflow;
+                continued.append_text(&line);
+                lines.push(continued);
             }
         }
-        output
+        lines
     }
 
     fn recorded_segments(&self, clear: bool) -> Vec<Segment<'static>> {
@@ -1127,23 +1806,46 @@ impl Console {
                 continue;
             }
 
-            // Get ANSI codes for style
-            let ansi_codes;
-            let (prefix, suffix) = if let Some(ref style) = segment.style {
-                if let Some(cs) = color_system {
-                    ansi_codes = style.render_ansi(cs);
-                    (&ansi_codes.0, &ansi_codes.1)
-                } else {
-                    static EMPTY: (String, String) = (String::new(), String::new());
-                    (&EMPTY.0, &EMPTY.1)
-                }
+            // Write styled text
+            let text = if self.line_ending == LineEnding::CrLf && segment.text.contains('\n') {
+                std::borrow::Cow::Owned(translate_newlines(
+                    &segment.text,
+                    self.line_ending.as_str(),
+                ))
             } else {
-                static EMPTY: (String, String) = (String::new(), String::new());
-                (&EMPTY.0, &EMPTY.1)
+                std::borrow::Cow::Borrowed(segment.text.as_ref())
             };
-
-            // Write styled text
-            write!(writer, "{prefix}{}{suffix}", segment.text)?;
+            let mut filtered =
+                self.color_vision_filter
+                    .zip(segment.style.as_ref())
+                    .map(|(deficiency, style)| {
+                        let mut style = style.clone();
+                        style.color = style.color.map(|c| c.simulate(deficiency));
+                        style.bgcolor = style.bgcolor.map(|c| c.simulate(deficiency));
+                        style
+                    });
+            if self.downgrade_method != DowngradeMethod::Nearest
+                && let Some(system) = color_system
+                && let Some(style) = filtered.as_ref().or(segment.style.as_ref())
+            {
+                let mut style = style.clone();
+                let method = self.downgrade_method;
+                style.color = style.color.map(|c| c.downgrade_with(system, method));
+                style.bgcolor = style.bgcolor.map(|c| c.downgrade_with(system, method));
+                filtered = Some(style);
+            }
+            if !self.hyperlinks
+                && let Some(style) = filtered.as_ref().or(segment.style.as_ref())
+                && style.link.is_some()
+            {
+                let mut style = style.clone();
+                style.link = None;
+                style.link_id = None;
+                filtered = Some(style);
+            }
+            let style = filtered.as_ref().or(segment.style.as_ref());
+            self.encoder
+                .write_text(writer, &text, style, color_system)?;
         }
 
         writer.flush()
@@ -1220,6 +1922,40 @@ impl Console {
                     let title = control_title(segment, control);
                     write!(writer, "\x1b]0;{title}\x07")?;
                 }
+                crate::segment::ControlType::ScrollUp => {
+                    let n = control_param(&control.params, 0, 1);
+                    write!(writer, "\x1b[{n}S")?;
+                }
+                crate::segment::ControlType::ScrollDown => {
+                    let n = control_param(&control.params, 0, 1);
+                    write!(writer, "\x1b[{n}T")?;
+                }
+                crate::segment::ControlType::SaveCursor => {
+                    write!(writer, "\x1b[s")?;
+                }
+                crate::segment::ControlType::RestoreCursor => {
+                    write!(writer, "\x1b[u")?;
+                }
+                crate::segment::ControlType::Notify => {
+                    // Control characters would terminate or escape the sequence early.
+                    let message: String =
+                        segment.text.chars().filter(|c| !c.is_control()).collect();
+                    write!(writer, "\x1b]9;{message}\x07")?;
+                }
+                crate::segment::ControlType::TerminalProgress => {
+                    let state = control_param(&control.params, 0, 0);
+                    let percent = control_param(&control.params, 1, 0).min(100);
+                    write!(writer, "\x1b]9;4;{state};{percent}\x07")?;
+                }
+                crate::segment::ControlType::InlineImage => {
+                    // Only base64 payload characters may reach the terminal.
+                    let data: String = segment
+                        .text
+                        .chars()
+                        .filter(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '/' | '='))
+                        .collect();
+                    write!(writer, "\x1b]1337;File=inline=1:{data}\x07")?;
+                }
             }
         }
 
@@ -1228,16 +1964,94 @@ impl Console {
 
     /// Print a blank line.
     pub fn line(&self) {
-        let mut file = lock_recover(&self.file);
-        let _ = writeln!(file);
+        if !self.is_enabled_for(Verbosity::Normal) {
+            return;
+        }
+        let mut file = self.lock_file();
+        let _ = write!(file, "{}", self.line_ending.as_str());
     }
 
-    /// Print a rule (horizontal line).
-    pub fn rule(&self, title: Option<&str>) {
-        let width = self.width();
+    /// Prompt for a secret (password, token) without echoing what is typed.
+    ///
+    /// The message is printed with markup followed by `": "`. When stdin is
+    /// a terminal, echo is switched off while reading and restored afterwards,
+    /// even if reading fails or panics; otherwise a line is read normally.
+    pub fn prompt_password(&self, message: &str) -> Result<String, PromptError> {
+        let stdin = io::stdin();
+        let no_echo = io::IsTerminal::is_terminal(&stdin)
+            .then_some(EchoGuard::disable as fn() -> io::Result<EchoGuard>);
+        self.prompt_password_with(message, &mut stdin.lock(), no_echo)
+    }
+
+    /// Like [`Console::prompt_password`], reading from `reader` (useful for tests).
+    pub fn prompt_password_from<R: io::BufRead>(
+        &self,
+        message: &str,
+        reader: &mut R,
+    ) -> Result<String, PromptError> {
+        self.prompt_password_with(message, reader, None)
+    }
+
+    /// Read a password from `reader`, holding the guard from `no_echo` (if
+    /// any) for the duration of the read.
+    pub(crate) fn prompt_password_with<R: io::BufRead>(
+        &self,
+        message: &str,
+        reader: &mut R,
+        no_echo: Option<fn() -> io::Result<EchoGuard>>,
+    ) -> Result<String, PromptError> {
+        self.read_password(message, || {
+            let _echo = no_echo.map(|disable| disable()).transpose()?;
+            read_secret(reader, DEFAULT_MAX_INPUT_LENGTH)
+        })
+    }
+
+    /// Wait up to `timeout` for a single key press.
+    ///
+    /// The terminal is switched to raw mode while waiting and restored
+    /// afterwards, even on panic. Arrow, navigation and function keys are
+    /// decoded. Returns `None` when the timeout expires or no terminal is
+    /// available, so an event loop can redraw between polls.
+    #[must_use]
+    pub fn read_key_timeout(&self, timeout: Duration) -> Option<Key> {
+        use crossterm::event::{poll, read};
+
+        let deadline = Instant::now() + timeout;
+        let _raw = EchoGuard::disable().ok()?;
+        next_key(
+            |wait| if poll(wait).ok()? { read().ok() } else { None },
+            deadline,
+        )
+    }
+
+    fn read_password(
+        &self,
+        message: &str,
+        read: impl FnOnce() -> Result<String, PromptError>,
+    ) -> Result<String, PromptError> {
+        let _suspended = self.suspend_live();
+        self.print_with_options(
+            &format!("{message}: "),
+            &PrintOptions::new()
+                .with_no_newline(true)
+                .with_level(Verbosity::Quiet),
+        );
+        let secret = read();
+        // Enter isn't echoed either, so end the prompt line ourselves.
+        let mut file = self.lock_file();
+        let _ = write!(file, "{}", self.line_ending.as_str());
+        secret
+    }
+
+    /// Print a rule (horizontal line).
+    pub fn rule(&self, title: Option<&str>) {
+        if !self.is_enabled_for(Verbosity::Normal) {
+            return;
+        }
+        let width = self.width();
         let line_char = if self.safe_box { '-' } else { '\u{2500}' };
 
-        let mut file = lock_recover(&self.file);
+        let mut file = self.lock_file();
         if let Some(title) = title {
             // Ensure title fits within width, accounting for 2 spaces padding
             let max_title_width = width.saturating_sub(2);
@@ -1257,36 +2071,242 @@ impl Console {
             let right_pad = available - left_pad;
             let left = line_char.to_string().repeat(left_pad);
             let right = line_char.to_string().repeat(right_pad);
-            let _ = writeln!(file, "{left} {display_title} {right}");
+            let _ = write!(
+                file,
+                "{left} {display_title} {right}{}",
+                self.line_ending.as_str()
+            );
         } else {
-            let _ = writeln!(file, "{}", line_char.to_string().repeat(width));
+            let _ = write!(
+                file,
+                "{}{}",
+                line_char.to_string().repeat(width),
+                self.line_ending.as_str()
+            );
         }
     }
 
-    /// Clear the screen.
+    /// Start a titled output section.
+    ///
+    /// Prints a rule containing `title` now, and a closing rule followed by a
+    /// blank line when the returned guard is dropped.
+    ///
+    /// ```rust,ignore
+    /// {
+    ///     let _section = console.begin_section("Build");
+    ///     console.print("compiling...");
+    /// } // closing rule printed here
+    /// ```
+    #[must_use]
+    pub fn begin_section(&self, title: &str) -> SectionGuard<'_> {
+        self.rule(Some(title));
+        SectionGuard { console: self }
+    }
+
+    /// Print a left-aligned rule announcing step `current` of `total`.
+    ///
+    /// The title is followed by a `[current/total]` marker and a ten-cell
+    /// bar filled in proportion to the steps reached, e.g.
+    /// `Deploy [2/5] ████░░░░░░ ──────────`.
+    pub fn step_header(&self, current: usize, total: usize, title: &str) {
+        const BAR_WIDTH: usize = 10;
+
+        let total = total.max(1);
+        let current = current.min(total);
+        let filled = current * BAR_WIDTH / total;
+        let (full, empty) = if self.safe_box || !self.supports_unicode() {
+            ("#", "-")
+        } else {
+            ("\u{2588}", "\u{2591}")
+        };
+
+        let mut label = Text::new("");
+        label.append_styled(title, Style::new().bold());
+        label.append_styled(&format!(" [{current}/{total}] "), Style::new().dim());
+        label.append_styled(
+            &full.repeat(filled),
+            Style::parse("green").unwrap_or_default(),
+        );
+        label.append_styled(&empty.repeat(BAR_WIDTH - filled), Style::new().dim());
+        self.print_renderable(&Rule::with_title(label).align_left());
+    }
+
+    /// Clear the screen and move the cursor home.
+    ///
+    /// Does nothing when the console isn't a terminal.
     pub fn clear(&self) {
-        let mut file = lock_recover(&self.file);
-        let _ = terminal::control::clear_screen(&mut *file);
+        if !self.is_terminal() {
+            return;
+        }
+        let _ = self.write_control_codes(vec![
+            ControlCode::new(ControlType::Clear),
+            ControlCode::new(ControlType::Home),
+        ]);
     }
 
     /// Clear the current line.
     pub fn clear_line(&self) {
-        let mut file = lock_recover(&self.file);
+        let mut file = self.lock_file();
         let _ = terminal::control::clear_line(&mut *file);
     }
 
-    /// Set the terminal title.
+    /// Set the terminal title, ignoring write errors.
+    ///
+    /// Same as [`Console::set_window_title`].
     pub fn set_title(&self, title: &str) {
-        let mut file = lock_recover(&self.file);
-        let _ = terminal::control::set_title(&mut *file, title);
+        let _ = self.set_window_title(title);
+    }
+
+    /// Set the terminal window title with `OSC 0`.
+    ///
+    /// Control characters are stripped from `title` so it cannot end the
+    /// sequence early. Nothing is written when the console isn't a terminal.
+    pub fn set_window_title(&self, title: &str) -> io::Result<()> {
+        if !self.is_terminal() {
+            return Ok(());
+        }
+        self.write_control_text(ControlCode::new(ControlType::SetWindowTitle), title)
+    }
+
+    /// Set the window title for the lifetime of the returned guard.
+    ///
+    /// The current title is pushed onto the terminal's title stack
+    /// (`CSI 22;0 t`) first and popped back (`CSI 23;0 t`) when the guard is
+    /// dropped. Nothing is emitted when the console isn't a terminal.
+    ///
+    /// ```rust,ignore
+    /// {
+    ///     let _title = console.window_title("building...");
+    ///     run_build();
+    /// } // previous title restored here
+    /// ```
+    #[must_use]
+    pub fn window_title(&self, title: &str) -> TitleGuard<'_> {
+        let active = self.is_terminal();
+        if active {
+            let _ = self.write_raw("\x1b[22;0t");
+            let _ = self.set_window_title(title);
+        }
+        TitleGuard {
+            console: self,
+            active,
+        }
+    }
+
+    /// Check whether desktop notifications and taskbar progress will be emitted.
+    ///
+    /// Requires a terminal that understands `OSC 9` (Windows Terminal, `ConEmu`,
+    /// `WezTerm`, ...); see [`ConsoleBuilder::notifications`] to override detection.
+    #[must_use]
+    pub fn supports_notifications(&self) -> bool {
+        self.is_terminal() && self.notifications.unwrap_or(self.notifications_detected)
+    }
+
+    /// Report task progress to the terminal tab/taskbar (`OSC 9;4`).
+    ///
+    /// `percent` is clamped to 100 and ignored by the terminal for
+    /// [`TerminalProgressState::Remove`] and [`TerminalProgressState::Indeterminate`].
+    /// Does nothing unless [`Console::supports_notifications`] is true.
+    ///
+    /// ```rust,ignore
+    /// console.set_terminal_progress(TerminalProgressState::Normal, 40)?;
+    /// // ... later
+    /// console.set_terminal_progress(TerminalProgressState::Remove, 0)?;
+    /// ```
+    pub fn set_terminal_progress(
+        &self,
+        state: TerminalProgressState,
+        percent: u8,
+    ) -> io::Result<()> {
+        if !self.supports_notifications() {
+            return Ok(());
+        }
+        self.write_control_text(
+            ControlCode::with_params_vec(
+                ControlType::TerminalProgress,
+                vec![i32::from(state as u8), i32::from(percent.min(100))],
+            ),
+            "",
+        )
+    }
+
+    /// Show a desktop notification via `OSC 9`.
+    ///
+    /// `OSC 9` carries a single message, so `title` and `body` are joined as
+    /// `"title: body"`. Does nothing unless [`Console::supports_notifications`] is true.
+    pub fn notify(&self, title: &str, body: &str) -> io::Result<()> {
+        if !self.supports_notifications() {
+            return Ok(());
+        }
+        let message = if title.is_empty() {
+            body.to_string()
+        } else {
+            format!("{title}: {body}")
+        };
+        self.write_control_text(ControlCode::new(ControlType::Notify), &message)
+    }
+
+    /// Write a control segment carrying `text` through the render hooks, so
+    /// capture and recording see it like any other output.
+    fn write_control_text(&self, control: ControlCode, text: &str) -> io::Result<()> {
+        let mut segment = Segment::control(vec![control]);
+        segment.text = std::borrow::Cow::Borrowed(text);
+        let mut file = self.lock_file();
+        self.write_segments_hooked(&mut *file, &[segment])
     }
 
     /// Ring the terminal bell.
+    ///
+    /// Writes BEL (`\x07`) when the output is a terminal and nothing otherwise.
+    /// With [`ConsoleBuilder::visual_bell`], the bottom line of the active
+    /// [`Live`](crate::live::Live) display briefly flashes in reverse video
+    /// instead, or the whole screen when no Live display is running.
     pub fn bell(&self) {
-        let mut file = lock_recover(&self.file);
+        if !self.is_terminal() {
+            return;
+        }
+        if self.visual_bell {
+            self.flash();
+            return;
+        }
+        let mut file = self.lock_file();
         let _ = terminal::control::bell(&mut *file);
     }
 
+    /// Start a visual bell; it is undone from a short-lived thread, so the
+    /// caller never waits for it.
+    ///
+    /// A screen-wide flash is also undone early by the next write or when
+    /// the console is dropped, so the terminal is never left reversed.
+    fn flash(&self) {
+        if let Some(live) = self.live_stack_snapshot().first() {
+            LiveInner::flash(live, VISUAL_BELL_DURATION);
+            return;
+        }
+        // DECSCNM: reverse the whole screen, then restore it.
+        let _ = self.write_raw("\x1b[?5h");
+        self.flash_pending.store(true, Ordering::SeqCst);
+        let file = Arc::clone(&self.file);
+        let pending = Arc::clone(&self.flash_pending);
+        std::thread::spawn(move || {
+            std::thread::sleep(VISUAL_BELL_DURATION);
+            restore_flash(&mut **lock_recover(&file), &pending);
+        });
+    }
+
+    /// Lock the output stream, first undoing a pending screen-wide flash.
+    fn lock_file(&self) -> MutexGuard<'_, Box<dyn Write + Send>> {
+        let mut file = lock_recover(&self.file);
+        restore_flash(&mut **file, &self.flash_pending);
+        file
+    }
+
+    fn write_raw(&self, sequence: &str) -> io::Result<()> {
+        let mut file = self.lock_file();
+        file.write_all(sequence.as_bytes())?;
+        file.flush()
+    }
+
     /// Print text without parsing markup.
     pub fn print_plain(&self, content: &str) {
         self.print_with_options(content, &PrintOptions::new().with_markup(false));
@@ -1302,6 +2322,28 @@ impl Console {
         );
     }
 
+    /// Print rows of plain cells in fixed-width columns.
+    ///
+    /// Each cell is padded or truncated (with an ellipsis) to its column width
+    /// in terminal cells, and columns are separated by a one-space gutter.
+    /// Missing cells print blank; cells beyond `col_widths` are ignored. A
+    /// lighter alternative to [`Table`](crate::renderables::Table) for quick reports.
+    pub fn print_aligned(&self, rows: &[Vec<&str>], col_widths: &[usize]) {
+        for row in rows {
+            let mut line = String::new();
+            for (i, &width) in col_widths.iter().enumerate() {
+                if i > 0 {
+                    line.push(' ');
+                }
+                let mut cell = Text::new(row.get(i).copied().unwrap_or_default());
+                // Skip padding the last column to avoid trailing whitespace.
+                cell.truncate(width, OverflowMethod::Ellipsis, i + 1 < col_widths.len());
+                line.push_str(cell.plain());
+            }
+            self.print_text(&Text::new(line));
+        }
+    }
+
     /// Print a log message with a level indicator.
     ///
     /// This is a simple version that just shows the level prefix and message.
@@ -1342,6 +2384,9 @@ impl Console {
     /// // Output: [12:34:56] src/main.rs:42 [DEBUG] Debug info
     /// ```
     pub fn log_with_options(&self, message: &str, level: LogLevel, options: &LogOptions) {
+        if !self.is_enabled_for(Verbosity::Normal) {
+            return;
+        }
         let (level_prefix, level_style) = match level {
             LogLevel::Debug => ("[DEBUG]", Style::parse("cyan").unwrap_or_default()),
             LogLevel::Info => ("[INFO]", Style::parse("green").unwrap_or_default()),
@@ -1350,10 +2395,13 @@ impl Console {
         };
 
         {
-            let mut file = lock_recover(&self.file);
+            let mut file = self.lock_file();
             // Print timestamp if enabled
             if options.show_timestamp {
-                let timestamp = Self::format_timestamp(options.timestamp_format.as_deref());
+                let timestamp = Self::format_timestamp(
+                    self.clock.system_now(),
+                    options.timestamp_format.as_deref(),
+                );
                 let ts_style = Style::parse("dim").unwrap_or_default();
                 let _ = self.print_to(
                     &mut *file,
@@ -1405,11 +2453,9 @@ impl Console {
         }
     }
 
-    /// Format the current time as a timestamp string.
-    fn format_timestamp(format: Option<&str>) -> String {
-        // Prefer local time for parity with typical "console logger" expectations, but
-        // fall back to UTC when local offset can't be determined (e.g., sandboxed envs).
-        let now = OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc());
+    /// Format `at` as a local-time timestamp string.
+    fn format_timestamp(at: SystemTime, format: Option<&str>) -> String {
+        let now = local_datetime(at);
 
         match format {
             None => format!(
@@ -1472,6 +2518,14 @@ impl Console {
     }
 }
 
+/// Undo a screen-wide visual bell if one is still showing.
+fn restore_flash(file: &mut dyn Write, pending: &AtomicBool) {
+    if pending.swap(false, Ordering::SeqCst) {
+        let _ = file.write_all(b"\x1b[?5l");
+        let _ = file.flush();
+    }
+}
+
 fn control_param(params: &[i32], index: usize, default: i32) -> i32 {
     params
         .get(index)
@@ -1480,6 +2534,69 @@ fn control_param(params: &[i32], index: usize, default: i32) -> i32 {
         .unwrap_or(default)
 }
 
+/// Best-effort type name for a `dyn Error`: the leading identifier of its `Debug` output.
+fn error_type_name(error: &dyn std::error::Error) -> String {
+    let debug = format!("{error:?}");
+    let name: String = debug
+        .chars()
+        .take_while(|c| c.is_alphanumeric() || *c == '_')
+        .collect();
+    if name.is_empty() {
+        String::from("Error")
+    } else {
+        name
+    }
+}
+
+fn backtrace_requested(value: Option<&str>) -> bool {
+    value.is_some_and(|value| !value.is_empty() && value != "0")
+}
+
+/// Build the [`TreeNode`] for `node`, tracking the current path in `ancestors`.
+fn build_tree_node<T>(
+    node: &T,
+    children: &impl Fn(&T) -> Vec<&T>,
+    label: &impl Fn(&T) -> Text,
+    ancestors: &mut HashSet<*const T>,
+    depth: usize,
+) -> TreeNode {
+    let mut text = label(node);
+    let key = std::ptr::from_ref(node);
+    if !ancestors.insert(key) {
+        text.append_styled(" (cycle)", Style::new().dim());
+        return TreeNode::new(text);
+    }
+
+    let kids = children(node);
+    let tree_node = if kids.is_empty() {
+        TreeNode::new(text)
+    } else if depth >= PRINT_TREE_MAX_DEPTH {
+        TreeNode::new(text).child(TreeNode::new(Text::styled("…", Style::new().dim())))
+    } else {
+        TreeNode::new(text).children(
+            kids.into_iter()
+                .map(|kid| build_tree_node(kid, children, label, ancestors, depth + 1)),
+        )
+    };
+    ancestors.remove(&key);
+    tree_node
+}
+
+/// Replace bare `\n` with `ending`, leaving existing `\r\n` pairs alone.
+fn translate_newlines(text: &str, ending: &str) -> String {
+    let mut out = String::with_capacity(text.len() + 8);
+    let mut prev = '\0';
+    for c in text.chars() {
+        if c == '\n' && prev != '\r' {
+            out.push_str(ending);
+        } else {
+            out.push(c);
+        }
+        prev = c;
+    }
+    out
+}
+
 fn erase_in_line_mode(params: &[i32]) -> i32 {
     if let Some(value) = params.first().copied()
         && (0..=2).contains(&value)
@@ -1931,6 +3048,232 @@ fn adler32(bytes: &[u8]) -> u32 {
     (b << 16) | a
 }
 
+/// Source of the current time for timestamps, elapsed times, and ETAs.
+///
+/// Production code uses [`SystemClock`]. Tests can inject a fake through
+/// [`ConsoleBuilder::clock`] (console and [`RichLogger`](crate::logging::RichLogger)
+/// timestamps) or [`ProgressBar::clock`](crate::renderables::ProgressBar::clock).
+pub trait Clock: Send + Sync {
+    /// Monotonic time, used for elapsed times and rates.
+    fn now(&self) -> Instant;
+
+    /// Wall-clock time, used for log timestamps.
+    fn system_now(&self) -> SystemTime;
+}
+
+/// Clock reading the real monotonic and wall-clock time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+
+    fn system_now(&self) -> SystemTime {
+        SystemTime::now()
+    }
+}
+
+/// Convert `at` to local time, falling back to UTC when the local offset
+/// can't be determined (e.g. in sandboxed or multi-threaded processes).
+pub(crate) fn local_datetime(at: SystemTime) -> OffsetDateTime {
+    let utc = OffsetDateTime::from(at);
+    UtcOffset::local_offset_at(utc).map_or(utc, |offset| utc.to_offset(offset))
+}
+
+/// Writes styled text to the console's output stream.
+///
+/// The console passes every non-control segment to its encoder (see
+/// [`ConsoleBuilder::encoder`]). Control segments (cursor movement, titles,
+/// ...) are written separately and are not affected.
+pub trait OutputEncoder: Send + Sync {
+    /// Write `text` with `style`, using `color_system` (`None` = no color).
+    fn write_text(
+        &self,
+        writer: &mut dyn Write,
+        text: &str,
+        style: Option<&Style>,
+        color_system: Option<ColorSystem>,
+    ) -> io::Result<()>;
+}
+
+/// Default encoder: wraps styled text in SGR escape sequences.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AnsiEncoder;
+
+impl OutputEncoder for AnsiEncoder {
+    fn write_text(
+        &self,
+        writer: &mut dyn Write,
+        text: &str,
+        style: Option<&Style>,
+        color_system: Option<ColorSystem>,
+    ) -> io::Result<()> {
+        match (style, color_system) {
+            (Some(style), Some(color_system)) => {
+                let codes = style.render_ansi(color_system);
+                write!(writer, "{}{text}{}", codes.0, codes.1)
+            }
+            _ => writer.write_all(text.as_bytes()),
+        }
+    }
+}
+
+/// Encoder for legacy Windows consoles that don't parse ANSI escape sequences.
+///
+/// Text is written without any escape sequences. Styling is reported through an
+/// optional callback receiving the equivalent `SetConsoleTextAttribute` word
+/// (see [`LegacyWindowsEncoder::attributes`]) before each styled run, and the
+/// default attributes afterwards; without a callback styling is stripped.
+///
+/// ```rust,ignore
+/// let encoder = LegacyWindowsEncoder::new().on_attributes(|attributes| {
+///     // e.g. windows::Win32::System::Console::SetConsoleTextAttribute(handle, attributes)
+/// });
+/// let console = Console::builder().encoder(Box::new(encoder)).build();
+/// ```
+#[derive(Default)]
+pub struct LegacyWindowsEncoder {
+    set_attributes: Option<Box<dyn Fn(u16) + Send + Sync>>,
+}
+
+impl std::fmt::Debug for LegacyWindowsEncoder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LegacyWindowsEncoder")
+            .field("set_attributes", &self.set_attributes.is_some())
+            .finish()
+    }
+}
+
+impl LegacyWindowsEncoder {
+    /// Default console attributes: light grey on black.
+    pub const DEFAULT_ATTRIBUTES: u16 = 0x07;
+
+    /// Create an encoder that strips styling.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Call `apply` with the console attribute word before each styled run.
+    #[must_use]
+    pub fn on_attributes(mut self, apply: impl Fn(u16) + Send + Sync + 'static) -> Self {
+        self.set_attributes = Some(Box::new(apply));
+        self
+    }
+
+    /// Translate a style into a `SetConsoleTextAttribute` word.
+    ///
+    /// Colors are reduced to the 16-color palette; bold maps to foreground
+    /// intensity, reverse to `COMMON_LVB_REVERSE_VIDEO`, and underline to
+    /// `COMMON_LVB_UNDERSCORE`.
+    #[must_use]
+    pub fn attributes(style: &Style) -> u16 {
+        // ANSI orders channels red/green/blue; the console API uses blue/green/red bits.
+        fn palette_bits(color: &crate::color::Color) -> Option<u16> {
+            if color.is_default() {
+                return None;
+            }
+            let number = u16::from(color.downgrade(ColorSystem::Standard).number?);
+            let rgb = (number & 1) << 2 | (number & 2) | (number & 4) >> 2;
+            Some(rgb | if number >= 8 { 0x08 } else { 0 })
+        }
+
+        let mut foreground = style.color.as_ref().and_then(palette_bits).unwrap_or(0x07);
+        let background = style.bgcolor.as_ref().and_then(palette_bits).unwrap_or(0);
+        if style.attributes.contains(Attributes::BOLD) {
+            foreground |= 0x08;
+        }
+        let mut attributes = foreground | background << 4;
+        if style.attributes.contains(Attributes::REVERSE) {
+            attributes |= 0x4000;
+        }
+        if style.attributes.contains(Attributes::UNDERLINE) {
+            attributes |= 0x8000;
+        }
+        attributes
+    }
+}
+
+impl OutputEncoder for LegacyWindowsEncoder {
+    fn write_text(
+        &self,
+        writer: &mut dyn Write,
+        text: &str,
+        style: Option<&Style>,
+        color_system: Option<ColorSystem>,
+    ) -> io::Result<()> {
+        let styled = style.filter(|style| !style.is_null() && color_system.is_some());
+        match (&self.set_attributes, styled) {
+            (Some(apply), Some(style)) => {
+                // Attribute changes take effect immediately, so flush text written so far.
+                writer.flush()?;
+                apply(Self::attributes(style));
+                writer.write_all(text.as_bytes())?;
+                writer.flush()?;
+                apply(Self::DEFAULT_ATTRIBUTES);
+                Ok(())
+            }
+            _ => writer.write_all(text.as_bytes()),
+        }
+    }
+}
+
+/// Line terminator written by the console for each newline.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LineEnding {
+    /// Unix-style `\n` (default).
+    #[default]
+    Lf,
+    /// Windows-style `\r\n`.
+    CrLf,
+}
+
+impl LineEnding {
+    /// The terminator as a string.
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Lf => "\n",
+            Self::CrLf => "\r\n",
+        }
+    }
+}
+
+/// How much output a print call represents, from most to least essential.
+///
+/// A console set to a level prints output tagged with that level or any
+/// level before it, so `-q` maps to [`Verbosity::Quiet`] and `-v` to
+/// [`Verbosity::Verbose`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Verbosity {
+    /// Essential output that is shown even in quiet mode.
+    Quiet,
+    /// Regular output.
+    #[default]
+    Normal,
+    /// Diagnostics for troubleshooting.
+    Debug,
+    /// The most detailed level; as a threshold it lets everything through.
+    Verbose,
+}
+
+/// Taskbar/tab progress state for [`Console::set_terminal_progress`] (`OSC 9;4`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TerminalProgressState {
+    /// Hide the progress indicator.
+    Remove = 0,
+    /// Regular progress.
+    Normal = 1,
+    /// Progress in an error state (typically red).
+    Error = 2,
+    /// Busy without a known percentage.
+    Indeterminate = 3,
+    /// Progress in a paused/warning state (typically yellow).
+    Paused = 4,
+}
+
 /// Log level for `console.log()`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LogLevel {
@@ -2065,54 +3408,286 @@ impl Drop for ThemeGuard<'_> {
     }
 }
 
-/// Builder for creating a Console with custom settings.
-#[derive(Default)]
-pub struct ConsoleBuilder {
-    color_system: Option<ColorSystem>,
-    force_terminal: Option<bool>,
-    tab_size: Option<usize>,
-    markup: Option<bool>,
-    emoji: Option<bool>,
-    highlight: Option<bool>,
-    highlighter: Option<Arc<dyn Highlighter>>,
-    width: Option<usize>,
-    height: Option<usize>,
-    safe_box: Option<bool>,
-    theme: Option<Theme>,
-    file: Option<Box<dyn Write + Send>>,
+/// Guard returned by [`Console::alternate_screen`].
+///
+/// Leaves the alternate screen and shows the cursor when dropped.
+pub struct ScreenGuard<'a> {
+    console: &'a Console,
+    active: bool,
 }
 
-impl std::fmt::Debug for ConsoleBuilder {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        f.debug_struct("ConsoleBuilder")
-            .field("color_system", &self.color_system)
-            .field("force_terminal", &self.force_terminal)
-            .field("tab_size", &self.tab_size)
-            .field("markup", &self.markup)
-            .field("emoji", &self.emoji)
-            .field("highlight", &self.highlight)
-            .field(
-                "highlighter",
-                &self.highlighter.as_ref().map(|_| "<Highlighter>"),
-            )
-            .field("width", &self.width)
-            .field("height", &self.height)
-            .field("safe_box", &self.safe_box)
-            .field("theme", &self.theme.as_ref().map(|_| "<Theme>"))
-            .field("file", &self.file.as_ref().map(|_| "<dyn Write>"))
-            .finish()
+impl Drop for ScreenGuard<'_> {
+    fn drop(&mut self) {
+        if self.active {
+            let _ = self.console.write_control_codes(vec![
+                ControlCode::new(ControlType::DisableAltScreen),
+                ControlCode::new(ControlType::ShowCursor),
+            ]);
+        }
     }
 }
 
-impl ConsoleBuilder {
-    /// Set the color system.
-    #[must_use]
-    pub fn color_system(mut self, system: ColorSystem) -> Self {
-        self.color_system = Some(system);
-        self
+/// Guard returned by [`Console::window_title`].
+///
+/// Restores the previous window title when dropped.
+pub struct TitleGuard<'a> {
+    console: &'a Console,
+    active: bool,
+}
+
+impl Drop for TitleGuard<'_> {
+    fn drop(&mut self) {
+        if self.active {
+            let _ = self.console.write_raw("\x1b[23;0t");
+        }
     }
+}
 
-    /// Disable colors.
+/// Guard returned by [`Console::buffered`].
+///
+/// Writes the collected output and restores the original writer when dropped.
+pub struct BufferedGuard<'a> {
+    console: &'a Console,
+    buffer: Arc<Mutex<Vec<u8>>>,
+    /// The writer in use before buffering; `None` only while dropping.
+    inner: Option<Box<dyn Write + Send>>,
+}
+
+impl BufferedGuard<'_> {
+    /// Write everything collected so far to the original writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        // Hold the file lock so no print lands between the drain and the write.
+        let _file = lock_recover(&self.console.file);
+        let pending = std::mem::take(&mut *lock_recover(&self.buffer));
+        let Some(inner) = self.inner.as_mut() else {
+            return Ok(());
+        };
+        if !pending.is_empty() {
+            inner.write_all(&pending)?;
+        }
+        inner.flush()
+    }
+}
+
+impl Drop for BufferedGuard<'_> {
+    fn drop(&mut self) {
+        let Some(inner) = self.inner.take() else {
+            return;
+        };
+        let mut file = lock_recover(&self.console.file);
+        *file = inner;
+        let pending = std::mem::take(&mut *lock_recover(&self.buffer));
+        if !pending.is_empty() {
+            let _ = file.write_all(&pending);
+        }
+        let _ = file.flush();
+    }
+}
+
+/// Appends writes to a shared buffer; flushing is left to [`BufferedGuard`].
+struct BufferWriter(Arc<Mutex<Vec<u8>>>);
+
+impl Write for BufferWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        lock_recover(&self.0).extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Guard returned by [`Console::begin_section`].
+///
+/// Prints the closing rule and a blank line when dropped.
+pub struct SectionGuard<'a> {
+    console: &'a Console,
+}
+
+impl Drop for SectionGuard<'_> {
+    fn drop(&mut self) {
+        self.console.rule(None);
+        self.console.line();
+    }
+}
+
+/// Removes escape sequences and bells from a byte stream.
+///
+/// Keeps its state between writes, so sequences split across calls are
+/// still dropped whole.
+#[derive(Debug, Default)]
+struct AnsiStripper {
+    state: StripState,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum StripState {
+    #[default]
+    Text,
+    Escape,
+    Csi,
+    Osc,
+    OscEscape,
+}
+
+impl AnsiStripper {
+    fn strip(&mut self, bytes: &[u8]) -> Vec<u8> {
+        let mut plain = Vec::with_capacity(bytes.len());
+        for &byte in bytes {
+            self.state = match (self.state, byte) {
+                (StripState::Text, 0x1b) => StripState::Escape,
+                (StripState::Text, 0x07) => StripState::Text,
+                (StripState::Text, _) => {
+                    plain.push(byte);
+                    StripState::Text
+                }
+                (StripState::Escape, b'[') => StripState::Csi,
+                (StripState::Escape, b']') => StripState::Osc,
+                (StripState::Escape, _) | (StripState::Csi, 0x40..=0x7e) => StripState::Text,
+                (StripState::Csi, _) => StripState::Csi,
+                (StripState::Osc | StripState::OscEscape, 0x07)
+                | (StripState::OscEscape, b'\\') => StripState::Text,
+                (StripState::Osc | StripState::OscEscape, 0x1b) => StripState::OscEscape,
+                (StripState::Osc | StripState::OscEscape, _) => StripState::Osc,
+            };
+        }
+        plain
+    }
+}
+
+/// Lines rendered ahead of time, replayed as-is.
+struct RenderedLines(Vec<Vec<Segment<'static>>>);
+
+impl Renderable for RenderedLines {
+    fn render<'a>(&'a self, _console: &Console, _options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        let mut segments = Vec::new();
+        for (index, line) in self.0.iter().enumerate() {
+            if index > 0 {
+                segments.push(Segment::line());
+            }
+            segments.extend(line.iter().cloned());
+        }
+        segments
+    }
+}
+
+/// An extra output target added with [`ConsoleBuilder::add_file`].
+struct Sink {
+    writer: Box<dyn Write + Send>,
+    /// `None` for sinks that receive styled output unchanged.
+    stripper: Option<AnsiStripper>,
+}
+
+/// Writes to the primary output and copies every byte to the extra sinks.
+struct TeeWriter {
+    primary: Box<dyn Write + Send>,
+    sinks: Vec<Sink>,
+}
+
+// Sink errors are ignored: the primary output already took the bytes, so
+// reporting a failure would invite a retry that duplicates them on screen.
+impl Write for TeeWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.primary.write(buf)?;
+        for sink in &mut self.sinks {
+            let _ = match sink.stripper.as_mut() {
+                Some(stripper) => sink.writer.write_all(&stripper.strip(&buf[..written])),
+                None => sink.writer.write_all(&buf[..written]),
+            };
+        }
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        for sink in &mut self.sinks {
+            let _ = sink.writer.flush();
+        }
+        self.primary.flush()
+    }
+}
+
+/// Builder for creating a Console with custom settings.
+#[derive(Default)]
+pub struct ConsoleBuilder {
+    color_system: Option<ColorSystem>,
+    force_terminal: Option<bool>,
+    tab_size: Option<usize>,
+    markup: Option<bool>,
+    emoji: Option<bool>,
+    highlight: Option<bool>,
+    highlighter: Option<Arc<dyn Highlighter>>,
+    width: Option<usize>,
+    height: Option<usize>,
+    safe_box: Option<bool>,
+    encoding: Option<String>,
+    theme: Option<Theme>,
+    background: Option<Background>,
+    notifications: Option<bool>,
+    line_ending: Option<LineEnding>,
+    overflow_marker: Option<char>,
+    wrap_continuation: Option<char>,
+    visual_bell: bool,
+    keyword_highlight: bool,
+    color_vision_filter: Option<ColorVisionDeficiency>,
+    downgrade_method: DowngradeMethod,
+    verbosity: Verbosity,
+    encoder: Option<Box<dyn OutputEncoder>>,
+    file: Option<Box<dyn Write + Send>>,
+    sinks: Vec<Sink>,
+    environment: Option<TerminalEnvironment>,
+    clock: Option<Arc<dyn Clock>>,
+}
+
+impl std::fmt::Debug for ConsoleBuilder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConsoleBuilder")
+            .field("color_system", &self.color_system)
+            .field("force_terminal", &self.force_terminal)
+            .field("tab_size", &self.tab_size)
+            .field("markup", &self.markup)
+            .field("emoji", &self.emoji)
+            .field("highlight", &self.highlight)
+            .field(
+                "highlighter",
+                &self.highlighter.as_ref().map(|_| "<Highlighter>"),
+            )
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("safe_box", &self.safe_box)
+            .field("encoding", &self.encoding)
+            .field("theme", &self.theme.as_ref().map(|_| "<Theme>"))
+            .field("background", &self.background)
+            .field("notifications", &self.notifications)
+            .field("line_ending", &self.line_ending)
+            .field("overflow_marker", &self.overflow_marker)
+            .field("wrap_continuation", &self.wrap_continuation)
+            .field("visual_bell", &self.visual_bell)
+            .field("keyword_highlight", &self.keyword_highlight)
+            .field("color_vision_filter", &self.color_vision_filter)
+            .field("downgrade_method", &self.downgrade_method)
+            .field("verbosity", &self.verbosity)
+            .field(
+                "encoder",
+                &self.encoder.as_ref().map(|_| "<dyn OutputEncoder>"),
+            )
+            .field("file", &self.file.as_ref().map(|_| "<dyn Write>"))
+            .field("sinks", &self.sinks.len())
+            .field("environment", &self.environment)
+            .field("clock", &self.clock.as_ref().map(|_| "<dyn Clock>"))
+            .finish()
+    }
+}
+
+impl ConsoleBuilder {
+    /// Set the color system.
+    #[must_use]
+    pub fn color_system(mut self, system: ColorSystem) -> Self {
+        self.color_system = Some(system);
+        self
+    }
+
+    /// Disable colors.
     #[must_use]
     pub fn no_color(mut self) -> Self {
         self.color_system = None;
@@ -2182,6 +3757,106 @@ impl ConsoleBuilder {
         self
     }
 
+    /// Force `OSC 9` notification and progress support on or off.
+    #[must_use]
+    pub fn notifications(mut self, enabled: bool) -> Self {
+        self.notifications = Some(enabled);
+        self
+    }
+
+    /// Set the line terminator written for newlines (default [`LineEnding::Lf`]).
+    #[must_use]
+    pub fn line_ending(mut self, ending: LineEnding) -> Self {
+        self.line_ending = Some(ending);
+        self
+    }
+
+    /// Set a marker (e.g. `'…'` or `'→'`) drawn in the last cell of lines that
+    /// are cropped to the width in no-wrap mode. `None` (the default) crops silently.
+    #[must_use]
+    pub fn overflow_marker(mut self, marker: Option<char>) -> Self {
+        self.overflow_marker = marker;
+        self
+    }
+
+    /// Set a marker (e.g. `'↳'`) drawn dim at the start of lines continued
+    /// by word wrapping, so they stand apart from new lines. The text after
+    /// it is indented by the marker's width. Lines broken by a newline and
+    /// unwrapped output are unaffected. `None` (the default) adds nothing.
+    #[must_use]
+    pub fn wrap_continuation(mut self, marker: Option<char>) -> Self {
+        self.wrap_continuation = marker;
+        self
+    }
+
+    /// Preview output as seen with a color vision deficiency.
+    ///
+    /// Every emitted foreground and background color is passed through
+    /// [`Color::simulate`](crate::color::Color::simulate). Recorded output used
+    /// for export keeps the original colors.
+    #[must_use]
+    pub fn color_vision_filter(mut self, deficiency: Option<ColorVisionDeficiency>) -> Self {
+        self.color_vision_filter = deficiency;
+        self
+    }
+
+    /// Choose how colors are matched to the palette on 256- and 16-color
+    /// terminals.
+    ///
+    /// [`DowngradeMethod::Perceptual`] picks the visually closest entry (see
+    /// [`Color::downgrade_with`](crate::color::Color::downgrade_with)).
+    #[must_use]
+    pub fn downgrade_method(mut self, method: DowngradeMethod) -> Self {
+        self.downgrade_method = method;
+        self
+    }
+
+    /// Set the most detailed [`Verbosity`] that is printed.
+    ///
+    /// Print calls tagged via [`PrintOptions::with_level`] with a more
+    /// detailed level are dropped. Untagged output, including renderables,
+    /// rules, and log lines, counts as [`Verbosity::Normal`], so
+    /// [`Verbosity::Quiet`] hides it. Interactive prompts and
+    /// [`Console::print_error`] are always shown.
+    #[must_use]
+    pub fn verbosity(mut self, verbosity: Verbosity) -> Self {
+        self.verbosity = verbosity;
+        self
+    }
+
+    /// Style status words such as `ERROR`, `WARN`, `OK` and `FAIL` in printed
+    /// text using the theme's `keyword.error`, `keyword.warning` and
+    /// `keyword.success` styles. Only whole, uppercase words match.
+    #[must_use]
+    pub fn keyword_highlight(mut self, enabled: bool) -> Self {
+        self.keyword_highlight = enabled;
+        self
+    }
+
+    /// Override the detected output encoding (e.g. `"utf-8"`, `"ascii"`).
+    ///
+    /// Non-UTF encodings make renderables fall back to ASCII glyphs.
+    #[must_use]
+    pub fn encoding(mut self, encoding: impl Into<String>) -> Self {
+        self.encoding = Some(encoding.into().to_ascii_lowercase());
+        self
+    }
+
+    /// Make [`Console::bell`] flash the display instead of sounding the bell.
+    #[must_use]
+    pub fn visual_bell(mut self, enabled: bool) -> Self {
+        self.visual_bell = enabled;
+        self
+    }
+
+    /// Set the encoder that turns styled segments into output bytes
+    /// (default [`AnsiEncoder`]).
+    #[must_use]
+    pub fn encoder(mut self, encoder: Box<dyn OutputEncoder>) -> Self {
+        self.encoder = Some(encoder);
+        self
+    }
+
     /// Set the initial console theme.
     #[must_use]
     pub fn theme(mut self, theme: Theme) -> Self {
@@ -2189,6 +3864,16 @@ impl ConsoleBuilder {
         self
     }
 
+    /// Override the detected terminal background.
+    ///
+    /// Unless an explicit [`theme`](Self::theme) is set, the base theme is
+    /// adjusted for the given background.
+    #[must_use]
+    pub fn background(mut self, background: Background) -> Self {
+        self.background = Some(background);
+        self
+    }
+
     /// Set the output stream.
     #[must_use]
     pub fn file(mut self, writer: Box<dyn Write + Send>) -> Self {
@@ -2196,10 +3881,64 @@ impl ConsoleBuilder {
         self
     }
 
+    /// Also write all output to `writer`, with styling stripped.
+    ///
+    /// Suited to log files: the text matches what the main output shows,
+    /// minus escape sequences. Use [`ConsoleBuilder::add_styled_file`] to
+    /// keep the styling. Write errors on extra files are ignored so they
+    /// never disturb the main output.
+    ///
+    /// ```rust,ignore
+    /// let log = std::fs::File::create("run.log")?;
+    /// let console = Console::builder().add_file(Box::new(log)).build();
+    /// console.print("[green]done[/]"); // colored on screen, plain in run.log
+    /// ```
+    #[must_use]
+    pub fn add_file(mut self, writer: Box<dyn Write + Send>) -> Self {
+        self.sinks.push(Sink {
+            writer,
+            stripper: Some(AnsiStripper::default()),
+        });
+        self
+    }
+
+    /// Also write all output to `writer`, byte-for-byte as sent to the main output.
+    #[must_use]
+    pub fn add_styled_file(mut self, writer: Box<dyn Write + Send>) -> Self {
+        self.sinks.push(Sink {
+            writer,
+            stripper: None,
+        });
+        self
+    }
+
+    /// Set the time source for log timestamps.
+    ///
+    /// Defaults to [`SystemClock`]; inject a fake clock for deterministic tests.
+    #[must_use]
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Replace every detected terminal capability with `environment`.
+    ///
+    /// Width, height, color system, TTY status, Unicode support, and hyperlink
+    /// support all come from the snapshot. Individual builder settings such as
+    /// [`Self::width`] or [`Self::color_system`] still take precedence.
+    #[must_use]
+    pub fn environment(mut self, environment: TerminalEnvironment) -> Self {
+        self.environment = Some(environment);
+        self
+    }
+
     /// Build the console.
     #[must_use]
     pub fn build(self) -> Console {
-        let mut console = Console::new();
+        let mut console = match &self.environment {
+            Some(env) => Console::from_environment(env, false),
+            None => Console::new(),
+        };
 
         if let Some(cs) = self.color_system {
             console.color_system = Some(cs);
@@ -2230,19 +3969,55 @@ impl ConsoleBuilder {
             console.highlighter = highlighter;
         }
         if let Some(w) = self.width {
-            console.width = Some(w);
+            console.width = Mutex::new(Some(w));
         }
         if let Some(h) = self.height {
-            console.height = Some(h);
+            console.height = Mutex::new(Some(h));
         }
         if let Some(sb) = self.safe_box {
             console.safe_box = sb;
         }
+        if let Some(background) = self.background {
+            console.background = Some(background);
+            if self.theme.is_none() {
+                console.theme_stack =
+                    Mutex::new(ThemeStack::new(Theme::for_background(background)));
+            }
+        }
         if let Some(theme) = self.theme {
             console.theme_stack = Mutex::new(ThemeStack::new(theme));
         }
+        if let Some(n) = self.notifications {
+            console.notifications = Some(n);
+        }
+        if let Some(ending) = self.line_ending {
+            console.line_ending = ending;
+        }
+        if let Some(encoding) = self.encoding {
+            console.encoding = encoding;
+        }
+        console.overflow_marker = self.overflow_marker;
+        console.wrap_continuation = self.wrap_continuation;
+        console.visual_bell = self.visual_bell;
+        console.keyword_highlight = self.keyword_highlight;
+        console.color_vision_filter = self.color_vision_filter;
+        console.downgrade_method = self.downgrade_method;
+        console.verbosity = self.verbosity;
+        if let Some(encoder) = self.encoder {
+            console.encoder = encoder;
+        }
+        if let Some(clock) = self.clock {
+            console.clock = clock;
+        }
         if let Some(f) = self.file {
-            console.file = Mutex::new(f);
+            console.file = Arc::new(Mutex::new(f));
+        }
+        if !self.sinks.is_empty() {
+            let primary = console.swap_file(Box::new(io::sink()));
+            console.file = Arc::new(Mutex::new(Box::new(TeeWriter {
+                primary,
+                sinks: self.sinks,
+            })));
         }
 
         console
@@ -2274,6 +4049,74 @@ mod tests {
         assert!(!console.markup);
     }
 
+    #[test]
+    fn test_console_builder_environment_overrides_detection() {
+        let env = TerminalEnvironment {
+            width: 42,
+            height: 7,
+            color_system: Some(ColorSystem::EightBit),
+            is_tty: true,
+            unicode: false,
+            hyperlinks: false,
+        };
+        let console = Console::builder().environment(env.clone()).build();
+
+        assert_eq!(console.width(), 42);
+        assert_eq!(console.height(), 7);
+        assert_eq!(console.color_system(), Some(ColorSystem::EightBit));
+        assert!(console.is_terminal());
+        assert!(!console.supports_unicode());
+        assert!(!console.supports_hyperlinks());
+
+        let plain = Console::builder()
+            .environment(TerminalEnvironment {
+                color_system: None,
+                is_tty: false,
+                unicode: true,
+                hyperlinks: true,
+                ..env
+            })
+            .build();
+        assert!(!plain.is_color_enabled());
+        assert!(!plain.is_terminal());
+        assert!(plain.supports_unicode());
+        assert!(plain.supports_hyperlinks());
+    }
+
+    #[test]
+    fn test_console_new_uses_detected_environment() {
+        let env = TerminalEnvironment::detect();
+        let console = Console::new();
+        assert_eq!(console.is_terminal(), env.is_tty);
+        assert_eq!(console.color_system(), env.color_system);
+        assert_eq!(console.supports_unicode(), env.unicode);
+        assert_eq!(console.supports_hyperlinks(), env.hyperlinks);
+        assert_eq!(console.encoding(), terminal::detect_encoding());
+    }
+
+    #[test]
+    fn test_console_without_hyperlinks_drops_osc8() {
+        let env = TerminalEnvironment {
+            width: 80,
+            height: 24,
+            color_system: Some(ColorSystem::TrueColor),
+            is_tty: true,
+            unicode: true,
+            hyperlinks: false,
+        };
+        let buf = Arc::new(Mutex::new(Vec::new()));
+        let console = Console::builder()
+            .environment(env)
+            .file(Box::new(BufferWriter(Arc::clone(&buf))))
+            .build();
+        console.print("[bold][link=https://example.com]docs[/link][/bold]");
+
+        let out = String::from_utf8(lock_recover(&buf).clone()).unwrap();
+        assert!(out.contains("docs"));
+        assert!(out.contains("\x1b["));
+        assert!(!out.contains("\x1b]8;"));
+    }
+
     #[test]
     fn test_console_default_highlighter_applies_when_enabled() {
         let console = Console::builder().markup(false).build();
@@ -2283,6 +4126,51 @@ mod tests {
         assert!(segments.iter().any(|s| s.style.as_ref() == Some(&expected)));
     }
 
+    #[test]
+    fn test_console_default_highlighter_styles_urls_and_numbers() {
+        let console = Console::builder().markup(false).build();
+        let opts = PrintOptions::new().with_no_newline(true);
+        let segments = console.render_str_segments("see http://x and 42", &opts);
+
+        let url = console.get_style("repr.url");
+        let number = console.get_style("repr.number");
+        let styled = |text: &str, style: &Style| {
+            segments
+                .iter()
+                .any(|s| s.text == text && s.style.as_ref() == Some(style))
+        };
+        assert!(styled("http://x", &url));
+        assert!(styled("42", &number));
+    }
+
+    #[test]
+    fn test_console_add_highlighter_runs_after_base() {
+        use crate::highlighter::RegexHighlighter;
+
+        let console = Console::builder().markup(false).build();
+        let todo = RegexHighlighter::new("repr.", &[r"(?P<none>TODO)"]).unwrap();
+        console.add_highlighter(Box::new(todo));
+
+        let opts = PrintOptions::new().with_no_newline(true);
+        let segments = console.render_str_segments("TODO 7", &opts);
+        let none = console.get_style("repr.none");
+        let number = console.get_style("repr.number");
+        assert!(
+            segments
+                .iter()
+                .any(|s| s.text == "TODO" && s.style.as_ref() == Some(&none))
+        );
+        assert!(
+            segments
+                .iter()
+                .any(|s| s.text == "7" && s.style.as_ref() == Some(&number))
+        );
+
+        let off = opts.with_highlight(false);
+        let segments = console.render_str_segments("TODO 7", &off);
+        assert!(segments.iter().all(|s| s.style.as_ref() != Some(&none)));
+    }
+
     #[test]
     fn test_console_highlight_override_off_disables_highlighter() {
         let console = Console::builder().markup(false).build();
@@ -2295,6 +4183,36 @@ mod tests {
         assert!(!segments.iter().any(|s| s.style.as_ref() == Some(&expected)));
     }
 
+    #[test]
+    fn test_console_keyword_highlight() {
+        let console = Console::builder()
+            .markup(false)
+            .keyword_highlight(true)
+            .build();
+        let opts = PrintOptions::new().with_markup(false).with_no_newline(true);
+        let segments = console.render_str_segments("build OK, test FAIL (OKAY)", &opts);
+        let style_of = |word: &str| {
+            segments
+                .iter()
+                .find(|s| s.text == word)
+                .and_then(|s| s.style.clone())
+        };
+        let green = crate::color::Color::parse("green").unwrap();
+        let red = crate::color::Color::parse("red").unwrap();
+        assert_eq!(style_of("OK").and_then(|s| s.color), Some(green));
+        assert_eq!(style_of("FAIL").and_then(|s| s.color), Some(red));
+        assert!(segments.iter().any(|s| s.text.contains("OKAY")));
+        assert!(style_of("OKAY").and_then(|s| s.color).is_none());
+
+        let plain = Console::builder().markup(false).build();
+        let segments = plain.render_str_segments("build OK", &opts);
+        assert!(
+            segments
+                .iter()
+                .all(|s| s.style.as_ref().is_none_or(|s| s.color.is_none()))
+        );
+    }
+
     #[test]
     fn test_console_builder_highlighter_override() {
         let console = Console::builder()
@@ -2511,6 +4429,23 @@ mod tests {
         assert_eq!(output, "hi :smile:\n");
     }
 
+    #[test]
+    fn test_print_replaces_emoji_shortcodes() {
+        let console = Console::builder().force_terminal(false).build();
+        assert_eq!(console.export_text(":rocket: launch"), "🚀 launch\n");
+        assert_eq!(
+            console.export_text("\\:rocket: launch"),
+            ":rocket: launch\n"
+        );
+        assert_eq!(console.export_text(":nope: launch"), ":nope: launch\n");
+
+        let console = Console::builder()
+            .force_terminal(false)
+            .emoji(false)
+            .build();
+        assert_eq!(console.export_text(":rocket: launch"), ":rocket: launch\n");
+    }
+
     #[test]
     fn test_export_text_with_options_no_newline() {
         let console = Console::new();
@@ -2521,8 +4456,277 @@ mod tests {
     }
 
     #[test]
-    fn test_export_renderable_text() {
-        use crate::renderables::Rule;
+    fn test_render_lines_splits_on_line_breaks() {
+        let console = Console::builder().width(40).build();
+        let text = Text::new("alpha\nbeta\ngamma");
+        let lines = console.render_lines(&text, 20);
+        assert_eq!(lines.len(), 3);
+        let plain: Vec<String> = lines.iter().map(|line| segments_to_plain(line)).collect();
+        assert_eq!(plain, vec!["alpha", "beta", "gamma"]);
+        for line in &lines {
+            assert!(line.iter().all(|segment| !segment.text.contains('\n')));
+        }
+    }
+
+    #[test]
+    fn test_non_unicode_encoding_falls_back_to_ascii() {
+        use crate::renderables::{Panel, ProgressBar, Spinner, Tree, TreeNode};
+
+        let console = Console::builder().width(40).encoding("ISO-8859-1").build();
+        assert_eq!(console.encoding(), "iso-8859-1");
+        assert!(!console.supports_unicode());
+        assert!(
+            Console::builder()
+                .encoding("UTF-8")
+                .build()
+                .supports_unicode()
+        );
+
+        let render = |renderable: &dyn Fn(&Console) -> Vec<Vec<Segment<'static>>>| {
+            renderable(&console)
+                .iter()
+                .map(|line| segments_to_plain(line))
+                .collect::<Vec<_>>()
+        };
+
+        let panel = Panel::from_text("hi").width(8);
+        let lines = render(&|c| c.render_lines(&panel, 8));
+        assert_eq!(lines, vec!["+------+", "| hi   |", "+------+"]);
+
+        let tree = Tree::new(TreeNode::new("root").child(TreeNode::new("leaf")));
+        let lines = render(&|c| c.render_lines(&tree, 20));
+        assert!(lines.iter().all(|line| line.is_ascii()), "{lines:?}");
+
+        let mut bar = ProgressBar::new().width(10);
+        bar.set_progress(0.5);
+        let lines = render(&|c| c.render_lines(&bar, 40));
+        assert_eq!(lines, vec!["[#####>----]  50%"]);
+
+        let lines = render(&|c| c.render_lines(&Spinner::dots(), 10));
+        assert_eq!(lines, vec!["|"]);
+    }
+
+    #[test]
+    fn test_print_table_of() {
+        use crate::renderables::ToRow;
+
+        struct Host {
+            name: &'static str,
+            up: bool,
+        }
+
+        impl ToRow for Host {
+            fn columns() -> Vec<String> {
+                vec!["Host".to_string(), "Up".to_string()]
+            }
+
+            fn cells(&self) -> Vec<String> {
+                vec![self.name.to_string(), self.up.to_string()]
+            }
+        }
+
+        let hosts = [
+            Host {
+                name: "alpha",
+                up: true,
+            },
+            Host {
+                name: "beta",
+                up: false,
+            },
+        ];
+        let console = Console::builder().width(40).markup(false).build();
+        console.begin_capture();
+        console.print_table_of(&hosts);
+        let plain = segments_to_plain(&console.end_capture());
+
+        let lines: Vec<&str> = plain.lines().collect();
+        assert_eq!(lines[1], "┃ Host  ┃ Up    ┃");
+        let body: Vec<&str> = lines
+            .iter()
+            .copied()
+            .filter(|l| l.starts_with('│'))
+            .collect();
+        assert_eq!(body, vec!["│ alpha │ true  │", "│ beta  │ false │"]);
+    }
+
+    #[test]
+    fn test_print_tree_of_walks_nested_data() {
+        struct Entry {
+            name: &'static str,
+            entries: Vec<Entry>,
+        }
+
+        let leaf = |name| Entry {
+            name,
+            entries: Vec::new(),
+        };
+        let root = Entry {
+            name: "project",
+            entries: vec![
+                Entry {
+                    name: "src",
+                    entries: vec![leaf("lib.rs"), leaf("main.rs")],
+                },
+                leaf("Cargo.toml"),
+            ],
+        };
+
+        let console = Console::builder().width(40).markup(false).build();
+        console.begin_capture();
+        console.print_tree_of(
+            &root,
+            |entry| entry.entries.iter().collect(),
+            |entry| Text::new(entry.name),
+        );
+        let plain = segments_to_plain(&console.end_capture());
+
+        let lines: Vec<&str> = plain.lines().map(str::trim_end).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "project",
+                "├── src",
+                "│   ├── lib.rs",
+                "│   └── main.rs",
+                "└── Cargo.toml",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_print_tree_of_marks_cycles() {
+        // Each node lists its children by index, so node 1 points back at 0.
+        let graph: Vec<(&str, Vec<usize>)> = vec![("a", vec![1]), ("b", vec![0])];
+
+        let console = Console::builder().width(40).markup(false).build();
+        console.begin_capture();
+        console.print_tree_of(
+            &graph[0],
+            |node| node.1.iter().map(|&index| &graph[index]).collect(),
+            |node| Text::new(node.0),
+        );
+        let plain = segments_to_plain(&console.end_capture());
+
+        let lines: Vec<&str> = plain.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["a", "└── b", "    └── a (cycle)"]);
+    }
+
+    #[test]
+    fn test_print_key_values_aligns_and_wraps() {
+        let console = Console::builder().force_terminal(false).width(30).build();
+        console.begin_capture();
+        console.print_key_values(&[
+            ("Name", Text::new("rich_rust")),
+            (
+                "Description",
+                Text::new("A Rust port of Python's Rich library."),
+            ),
+        ]);
+        let output = segments_to_plain(&console.end_capture());
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "       Name : rich_rust",
+                "Description : A Rust port of",
+                "              Python's Rich",
+                "              library.",
+            ]
+        );
+        assert_eq!(lines[0].find(':'), lines[1].find(':'));
+    }
+
+    #[test]
+    fn test_step_header_shows_marker_and_mini_bar() {
+        let console = Console::builder().force_terminal(false).width(40).build();
+        console.begin_capture();
+        console.step_header(2, 5, "Deploy");
+        let output = segments_to_plain(&console.end_capture());
+        let line = output.lines().next().unwrap();
+
+        assert!(line.starts_with("Deploy [2/5] "));
+        assert!(line.contains(
+            "\u{2588}\u{2588}\u{2588}\u{2588}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}\u{2591}"
+        ));
+        assert!(line.ends_with('\u{2500}'));
+        assert_eq!(crate::cells::cell_len(line), 40);
+    }
+
+    #[test]
+    fn test_fit_renderables_stacks_or_arranges_columns() {
+        use crate::renderables::table::{Cell, Column, Row};
+
+        let table = |title: &str| {
+            Table::new()
+                .with_column(Column::new(title))
+                .with_column(Column::new("Value"))
+                .with_row(Row::new(vec![
+                    Cell::new("a fairly long description"),
+                    Cell::new("42"),
+                ]))
+        };
+        let left = table("Left");
+        let right = table("Right");
+
+        let narrow = Console::builder().width(40).markup(false).build();
+        let stacked = narrow.fit_renderables(&[&left, &right]);
+        let lines: Vec<String> = narrow
+            .render_lines(&stacked, 40)
+            .iter()
+            .map(|line| line.iter().map(|s| s.text.as_ref()).collect())
+            .collect();
+        assert!(lines.iter().all(|line| line.matches('┏').count() <= 1));
+        assert_eq!(lines.iter().filter(|line| line.contains('┏')).count(), 2);
+
+        let wide = Console::builder().width(200).markup(false).build();
+        let arranged = wide.fit_renderables(&[&left, &right]);
+        let lines: Vec<String> = wide
+            .render_lines(&arranged, 200)
+            .iter()
+            .map(|line| line.iter().map(|s| s.text.as_ref()).collect())
+            .collect();
+        assert_eq!(lines[0].matches('┏').count(), 2);
+        assert!(lines[1].contains("Left") && lines[1].contains("Right"));
+        assert_eq!(lines.iter().filter(|line| line.contains('┏')).count(), 1);
+    }
+
+    #[test]
+    fn test_render_to_plain_strips_styling() {
+        use crate::renderables::{Column, Table};
+
+        let mut table = Table::new()
+            .with_column(Column::new("City").style(Style::parse("bold red").unwrap()))
+            .with_column(Column::new("Pop"))
+            .border_style(Style::parse("blue").unwrap());
+        table.add_row_cells(["東京", "14M"]);
+        table.add_row_cells(["Oslo", "1M"]);
+
+        let console = Console::builder()
+            .width(40)
+            .force_terminal(true)
+            .color_system(ColorSystem::TrueColor)
+            .build();
+        let plain = console.render_to_plain(&table);
+        assert!(!plain.contains('\x1b'), "{plain:?}");
+        assert_eq!(
+            plain,
+            "┏━━━━━━┳━━━━━┓\n\
+             ┃ City ┃ Pop ┃\n\
+             ┡━━━━━━╇━━━━━┩\n\
+             │ 東京 │ 14M │\n\
+             │ Oslo │ 1M  │\n\
+             └──────┴─────┘\n"
+        );
+
+        let pre_styled = Text::new("\x1b[1mready\x1b[0m now");
+        assert_eq!(console.render_to_plain(&pre_styled), "ready now");
+    }
+
+    #[test]
+    fn test_export_renderable_text() {
+        use crate::renderables::Rule;
 
         let console = Console::builder().width(20).build();
         let rule = Rule::with_title("Title");
@@ -2735,6 +4939,29 @@ mod tests {
         assert_eq!(text, "    Hi    ");
     }
 
+    #[test]
+    fn test_print_renderable_with_justify_aligns_block() {
+        let console = Console::builder().width(80).force_terminal(false).build();
+        let print = |justify| {
+            console.begin_capture();
+            console.print_renderable_with_options(
+                &Text::new("v1.2.3"),
+                &PrintOptions::new().with_justify(justify),
+            );
+            segments_to_plain(&console.end_capture())
+        };
+
+        let centered = print(JustifyMethod::Center);
+        let leading = centered.len() - centered.trim_start().len();
+        let trailing = centered.len() - centered.trim_end().len();
+        assert_eq!(centered.trim(), "v1.2.3");
+        assert_eq!((leading, trailing), (37, 37));
+        assert_eq!(
+            print(JustifyMethod::Right),
+            format!("{}v1.2.3", " ".repeat(74))
+        );
+    }
+
     #[test]
     fn test_print_options_width_wraps() {
         let console = Console::builder().width(80).markup(false).build();
@@ -2837,6 +5064,26 @@ mod tests {
         assert!(!console.highlight);
     }
 
+    #[test]
+    fn test_console_builder_background_override() {
+        let console = Console::builder().background(Background::Light).build();
+        assert_eq!(console.detect_background(), Some(Background::Light));
+        assert_eq!(
+            console.get_style("bar.back"),
+            Style::parse("grey82").unwrap()
+        );
+
+        let console = Console::builder()
+            .background(Background::Light)
+            .theme(Theme::default())
+            .build();
+        assert_eq!(console.detect_background(), Some(Background::Light));
+        assert_eq!(
+            console.get_style("bar.back"),
+            Style::parse("grey23").unwrap()
+        );
+    }
+
     #[test]
     fn test_console_builder_safe_box() {
         let console = Console::builder().safe_box(true).build();
@@ -3007,6 +5254,54 @@ mod tests {
         assert!(segments.is_empty());
     }
 
+    #[test]
+    fn test_print_options_base_style_composes_under_content() {
+        let console = Console::builder()
+            .width(40)
+            .markup(true)
+            .color_system(ColorSystem::TrueColor)
+            .force_terminal(true)
+            .build();
+        let options = PrintOptions::new()
+            .with_markup(true)
+            .with_style(Style::new().dim());
+
+        let segments = console.render_str_segments("[bold]hi[/]", &options);
+        let hi = segments
+            .iter()
+            .find(|s| s.text == "hi")
+            .expect("hi segment");
+        let style = hi.style.as_ref().expect("styled");
+        assert!(style.attributes.contains(Attributes::BOLD));
+        assert!(style.attributes.contains(Attributes::DIM));
+
+        // Segment styles win on conflict.
+        let options = PrintOptions::new()
+            .with_markup(true)
+            .with_style(Style::parse("red").unwrap());
+        let segments = console.render_str_segments("[blue]x[/]", &options);
+        let x = segments.iter().find(|s| s.text == "x").expect("x segment");
+        assert_eq!(
+            x.style.as_ref().and_then(|s| s.color.clone()),
+            Style::parse("blue").unwrap().color
+        );
+
+        // Renderables receive the base style too.
+        let text = Text::styled("ok", Style::new().bold());
+        let options = PrintOptions::new().with_style(Style::new().italic());
+        let segments = console.render_renderable_segments(&text, &options);
+        let ok = segments
+            .iter()
+            .find(|s| s.text == "ok")
+            .expect("ok segment");
+        let style = ok.style.as_ref().expect("styled");
+        assert!(
+            style
+                .attributes
+                .contains(Attributes::BOLD | Attributes::ITALIC)
+        );
+    }
+
     #[test]
     fn test_capture_with_styled_text() {
         use std::sync::{Arc, Mutex};
@@ -3526,7 +5821,7 @@ mod tests {
 
     #[test]
     fn test_format_timestamp_default() {
-        let ts = Console::format_timestamp(None);
+        let ts = Console::format_timestamp(SystemTime::now(), None);
         // Default format: [HH:MM:SS]
         assert!(ts.starts_with('['));
         assert!(ts.ends_with(']'));
@@ -3535,7 +5830,7 @@ mod tests {
 
     #[test]
     fn test_format_timestamp_custom() {
-        let ts = Console::format_timestamp(Some("%H-%M-%S"));
+        let ts = Console::format_timestamp(SystemTime::now(), Some("%H-%M-%S"));
         // Custom format: HH-MM-SS
         assert_eq!(ts.matches('-').count(), 2);
         assert!(!ts.contains(':'));
@@ -3543,7 +5838,7 @@ mod tests {
 
     #[test]
     fn test_format_timestamp_custom_with_date_tokens() {
-        let ts = Console::format_timestamp(Some("%Y-%m-%d %H:%M:%S"));
+        let ts = Console::format_timestamp(SystemTime::now(), Some("%Y-%m-%d %H:%M:%S"));
         // We don't assert wall-clock values; we only assert the substitutions happened.
         assert_eq!(ts.len(), "0000-00-00 00:00:00".len());
         assert_eq!(ts.matches('-').count(), 2);
@@ -3815,6 +6110,128 @@ mod tests {
         assert_eq!(text, "Hello");
     }
 
+    #[test]
+    fn test_legacy_windows_encoder_strips_escapes() {
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .color_system(ColorSystem::TrueColor)
+            .encoder(Box::new(LegacyWindowsEncoder::new()))
+            .file(Box::new(buffer.clone()))
+            .build();
+        console.print("[bold red]error[/] and [on blue]info[/]");
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output, "error and info\n");
+
+        let applied = Arc::new(Mutex::new(Vec::new()));
+        let sink = Arc::clone(&applied);
+        let encoder = LegacyWindowsEncoder::new().on_attributes(move |attributes| {
+            sink.lock().unwrap().push(attributes);
+        });
+        let console = Console::builder()
+            .force_terminal(true)
+            .color_system(ColorSystem::TrueColor)
+            .highlight(false)
+            .encoder(Box::new(encoder))
+            .file(Box::new(io::sink()))
+            .build();
+        console.print("[bold red]error[/]");
+        // Bright red foreground (red bit + intensity), then back to defaults.
+        assert_eq!(applied.lock().unwrap().as_slice(), &[0x0C, 0x07]);
+    }
+
+    #[test]
+    fn test_overflow_marker_replaces_last_visible_cell() {
+        let content: String = ('a'..='z').cycle().take(100).collect();
+        let options = PrintOptions::new()
+            .with_markup(false)
+            .with_no_wrap(true)
+            .with_no_newline(true);
+
+        let console = Console::builder()
+            .width(20)
+            .overflow_marker(Some('→'))
+            .build();
+        let mut output = Vec::new();
+        console.print_to(&mut output, &content, &options).unwrap();
+        let text = String::from_utf8(output).unwrap();
+        assert_eq!(crate::cells::cell_len(&text), 20);
+        assert_eq!(text, format!("{}→", &content[..19]));
+
+        let console = Console::builder().width(20).build();
+        let mut output = Vec::new();
+        console.print_to(&mut output, &content, &options).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), &content[..20]);
+    }
+
+    #[test]
+    fn test_wrap_continuation_marks_soft_wrapped_lines() {
+        let options = PrintOptions::new().with_markup(false).with_soft_wrap(true);
+        let console = Console::builder()
+            .width(20)
+            .wrap_continuation(Some('↳'))
+            .build();
+        console.begin_capture();
+        console.print_with_options(
+            "alpha beta gamma delta epsilon zeta eta theta\nnew line",
+            &options,
+        );
+        let segments = console.end_capture();
+        let plain = segments_to_plain(&segments);
+        let lines: Vec<&str> = plain.lines().map(str::trim_end).collect();
+
+        assert_eq!(
+            lines,
+            vec![
+                "alpha beta gamma",
+                "↳delta epsilon zeta",
+                "↳eta theta",
+                "new line"
+            ]
+        );
+
+        // Only continuation lines give up a cell to the marker.
+        console.begin_capture();
+        console.print_with_options("alpha beta gamma del epsilon zeta", &options);
+        let plain = segments_to_plain(&console.end_capture());
+        let lines: Vec<&str> = plain.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["alpha beta gamma del", "↳epsilon zeta"]);
+
+        // Cropped text is cut, not continued.
+        for overflow in [OverflowMethod::Crop, OverflowMethod::Ellipsis] {
+            console.begin_capture();
+            console.print_with_options(
+                "alpha beta gamma delta epsilon zeta",
+                &options.clone().with_overflow(overflow),
+            );
+            let plain = segments_to_plain(&console.end_capture());
+            assert_eq!(plain.lines().count(), 1, "{overflow:?}: {plain:?}");
+            assert!(!plain.contains('↳'), "{overflow:?}: {plain:?}");
+        }
+        let marker = segments
+            .iter()
+            .find(|segment| segment.text == "↳")
+            .expect("marker segment");
+        assert!(
+            marker
+                .style
+                .as_ref()
+                .is_some_and(|style| style.attributes.contains(Attributes::DIM))
+        );
+    }
+
     // ========================================================================
     // Console I/O Error Path Tests (bd-3761)
     // ========================================================================
@@ -4263,4 +6680,802 @@ mod tests {
                 || result.as_ref().map_err(std::io::Error::kind) == Err(io::ErrorKind::Interrupted)
         );
     }
+
+    #[test]
+    fn test_begin_section_prints_title_rule_and_closing_separator() {
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .width(12)
+            .safe_box(true)
+            .force_terminal(false)
+            .file(Box::new(buffer.clone()))
+            .build();
+        let section = console.begin_section("Build");
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output, "-- Build ---\n");
+
+        console.print_plain("step");
+        drop(section);
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output, "-- Build ---\nstep\n------------\n\n");
+    }
+
+    #[test]
+    fn test_print_error_renders_source_chain_panels() {
+        #[derive(Debug)]
+        struct ReadError;
+
+        impl std::fmt::Display for ReadError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("disk unreadable")
+            }
+        }
+
+        impl std::error::Error for ReadError {}
+
+        #[derive(Debug)]
+        struct ConfigError {
+            source: ReadError,
+        }
+
+        impl std::fmt::Display for ConfigError {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str("failed to load config")
+            }
+        }
+
+        impl std::error::Error for ConfigError {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                Some(&self.source)
+            }
+        }
+
+        let console = Console::builder()
+            .width(40)
+            .force_terminal(false)
+            .file(Box::new(io::sink()))
+            .build();
+        console.begin_capture();
+        console.print_error(&ConfigError { source: ReadError });
+        let segments = console.end_capture();
+        let plain = segments_to_plain(&segments);
+
+        let lines: Vec<&str> = plain.lines().collect();
+        let outer = lines
+            .iter()
+            .position(|line| line.contains("ConfigError: failed to load config"))
+            .expect("outer error line");
+        let cause = lines
+            .iter()
+            .position(|line| line.contains("ReadError: disk unreadable"))
+            .expect("cause line");
+        assert!(lines[0].contains("Error"));
+        assert!(cause > outer + 1, "cause should be in a separate panel");
+        assert!(
+            lines[outer + 1..cause]
+                .iter()
+                .any(|line| line.contains("Caused by"))
+        );
+
+        let type_style = Style::parse("bold bright_red").unwrap();
+        for name in ["ConfigError", "ReadError"] {
+            assert!(
+                segments
+                    .iter()
+                    .any(|seg| seg.text == name && seg.style.as_ref() == Some(&type_style)),
+                "{name} should be styled"
+            );
+        }
+    }
+
+    #[test]
+    fn test_backtrace_requested_values() {
+        assert!(backtrace_requested(Some("1")));
+        assert!(backtrace_requested(Some("full")));
+        assert!(!backtrace_requested(Some("0")));
+        assert!(!backtrace_requested(Some("")));
+        assert!(!backtrace_requested(None));
+    }
+
+    #[test]
+    fn test_alternate_screen_guard_brackets_session() {
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .width(40)
+            .force_terminal(true)
+            .file(Box::new(buffer.clone()))
+            .build();
+        {
+            let _screen = console.alternate_screen();
+            console.print_plain("inside");
+        }
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output, "\x1b[?1049hinside\n\x1b[?1049l\x1b[?25h");
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .width(40)
+            .force_terminal(false)
+            .file(Box::new(buffer.clone()))
+            .build();
+        {
+            let _screen = console.alternate_screen();
+            console.print_plain("inside");
+        }
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output, "inside\n");
+    }
+
+    #[test]
+    fn test_buffered_output_batches_writes() {
+        #[derive(Clone, Default)]
+        struct CountingWriter {
+            bytes: Arc<Mutex<Vec<u8>>>,
+            writes: Arc<Mutex<usize>>,
+        }
+
+        impl Write for CountingWriter {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                *self.writes.lock().unwrap() += 1;
+                self.bytes.lock().unwrap().extend_from_slice(buf);
+                Ok(buf.len())
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let table = || {
+            let mut table = Table::new()
+                .with_column(crate::renderables::table::Column::new("Name"))
+                .with_column(crate::renderables::table::Column::new("Value"));
+            for i in 0..50 {
+                table.add_row_cells([format!("row {i}"), i.to_string()]);
+            }
+            table
+        };
+
+        let direct = CountingWriter::default();
+        let console = Console::builder()
+            .force_terminal(true)
+            .width(40)
+            .file(Box::new(direct.clone()))
+            .build();
+        console.print_renderable(&table());
+        let direct_writes = *direct.writes.lock().unwrap();
+
+        let batched = CountingWriter::default();
+        let console = Console::builder()
+            .force_terminal(true)
+            .width(40)
+            .file(Box::new(batched.clone()))
+            .build();
+        {
+            let mut buffered = console.buffered();
+            console.print_renderable(&table());
+            assert_eq!(*batched.writes.lock().unwrap(), 0);
+            buffered.flush().unwrap();
+            assert_eq!(*batched.writes.lock().unwrap(), 1);
+            console.print("after flush");
+        }
+        assert_eq!(*batched.writes.lock().unwrap(), 2);
+        assert!(
+            direct_writes > 50,
+            "expected many direct writes, got {direct_writes}"
+        );
+
+        let direct_bytes = direct.bytes.lock().unwrap().clone();
+        let batched_bytes = batched.bytes.lock().unwrap().clone();
+        assert!(batched_bytes.starts_with(&direct_bytes));
+
+        // The original writer is back in place once the guard is gone.
+        console.print("unbuffered");
+        assert!(*batched.writes.lock().unwrap() > 2);
+    }
+
+    #[test]
+    fn test_cursor_helpers_only_on_terminal() {
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .file(Box::new(buffer.clone()))
+            .build();
+        console.save_cursor().unwrap();
+        console.move_to(5, 3).unwrap();
+        console.move_cursor(2, -1).unwrap();
+        console.restore_cursor().unwrap();
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output, "\x1b[s\x1b[4;6H\x1b[2C\x1b[1A\x1b[u");
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(false)
+            .file(Box::new(buffer.clone()))
+            .build();
+        console.save_cursor().unwrap();
+        console.move_to(5, 3).unwrap();
+        console.move_cursor(2, -1).unwrap();
+        console.restore_cursor().unwrap();
+        assert!(buffer.0.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_set_window_title_only_on_terminal() {
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .file(Box::new(buffer.clone()))
+            .build();
+        console.set_window_title("build \x07done").unwrap();
+        console.set_title("job\x1b");
+        {
+            let _title = console.window_title("deploy");
+        }
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            output,
+            "\x1b]0;build done\x07\x1b]0;job\x07\x1b[22;0t\x1b]0;deploy\x07\x1b[23;0t"
+        );
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(false)
+            .file(Box::new(buffer.clone()))
+            .build();
+        console.set_window_title("build").unwrap();
+        console.set_title("build");
+        drop(console.window_title("deploy"));
+        assert!(buffer.0.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_print_aligned_keeps_columns_with_wide_chars() {
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .width(80)
+            .force_terminal(false)
+            .file(Box::new(buffer.clone()))
+            .build();
+        console.print_aligned(
+            &[vec!["日本語", "ok"], vec!["a very long name", "fine"]],
+            &[10, 6],
+        );
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines, vec!["日本語     ok", "a very ... fine"]);
+        let offset =
+            |line: &str, needle: &str| crate::cells::cell_len(&line[..line.find(needle).unwrap()]);
+        assert_eq!(offset(lines[0], "ok"), 11);
+        assert_eq!(offset(lines[1], "fine"), 11);
+    }
+
+    #[test]
+    fn test_console_clear_and_control_codes() {
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .file(Box::new(buffer.clone()))
+            .build();
+        console.clear();
+        console.show_cursor(false).unwrap();
+        console
+            .control(ControlCode::with_params_vec(ControlType::ScrollUp, vec![3]))
+            .unwrap();
+        console
+            .control(ControlCode::with_params_vec(
+                ControlType::CursorMoveTo,
+                vec![4, 1],
+            ))
+            .unwrap();
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(output, "\x1b[2J\x1b[H\x1b[?25l\x1b[3S\x1b[2;5H");
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(false)
+            .file(Box::new(buffer.clone()))
+            .build();
+        console.clear();
+        console.show_cursor(false).unwrap();
+        console
+            .control(ControlCode::new(ControlType::ScrollDown))
+            .unwrap();
+        assert!(buffer.0.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_line_ending_crlf_translates_newlines() {
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let print_lines = |ending: LineEnding| {
+            let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+            let console = Console::builder()
+                .width(20)
+                .force_terminal(false)
+                .line_ending(ending)
+                .file(Box::new(buffer.clone()))
+                .build();
+            console.print_plain("one\ntwo");
+            console.line();
+            console.print_plain("three\r\n");
+            let bytes = buffer.0.lock().unwrap().clone();
+            String::from_utf8(bytes).unwrap()
+        };
+
+        assert_eq!(
+            print_lines(LineEnding::CrLf),
+            "one\r\ntwo\r\n\r\nthree\r\n\r\n"
+        );
+        let lf = print_lines(LineEnding::Lf);
+        assert_eq!(lf, "one\ntwo\n\nthree\r\n\n");
+        assert!(!lf.replace("three\r\n", "").contains('\r'));
+    }
+
+    #[test]
+    fn test_failing_sink_does_not_fail_main_output() {
+        struct Broken;
+
+        impl Write for Broken {
+            fn write(&mut self, _: &[u8]) -> io::Result<usize> {
+                Err(io::Error::other("disk full"))
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Err(io::Error::other("disk full"))
+            }
+        }
+
+        #[derive(Clone, Default)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let out = SharedBuffer::default();
+        let log = SharedBuffer::default();
+        let console = Console::builder()
+            .force_terminal(false)
+            .file(Box::new(out.clone()))
+            .add_file(Box::new(Broken))
+            .add_file(Box::new(log.clone()))
+            .build();
+        let mut writer = lock_recover(&console.file);
+        assert_eq!(writer.write(b"hello").unwrap(), 5);
+        writer.flush().unwrap();
+        drop(writer);
+        assert_eq!(*out.0.lock().unwrap(), b"hello");
+        assert_eq!(*log.0.lock().unwrap(), b"hello");
+    }
+
+    #[test]
+    fn test_add_file_tees_plain_copy() {
+        #[derive(Clone, Default)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let tty = SharedBuffer::default();
+        let log = SharedBuffer::default();
+        let styled = SharedBuffer::default();
+        let console = Console::builder()
+            .force_terminal(true)
+            .color_system(ColorSystem::TrueColor)
+            .file(Box::new(tty.clone()))
+            .add_file(Box::new(log.clone()))
+            .add_styled_file(Box::new(styled.clone()))
+            .build();
+        console.print("[bold red]alert[/] [link=https://example.com]docs[/link]");
+        console.set_title("job");
+
+        let tty = String::from_utf8(tty.0.lock().unwrap().clone()).unwrap();
+        let log = String::from_utf8(log.0.lock().unwrap().clone()).unwrap();
+        let styled = String::from_utf8(styled.0.lock().unwrap().clone()).unwrap();
+        assert!(tty.contains("\x1b[1;31m"), "{tty:?}");
+        assert_eq!(log, "alert docs\n");
+        assert_eq!(styled, tty);
+
+        // Sequences split across writes are still removed whole.
+        let mut stripper = AnsiStripper::default();
+        let mut plain = stripper.strip(b"a\x1b[3");
+        plain.extend(stripper.strip(b"8;5;1mb\x1b]8;;u\x1b"));
+        plain.extend(stripper.strip(b"\\c\x07"));
+        assert_eq!(plain, b"abc");
+    }
+
+    #[test]
+    fn test_verbosity_filters_print_levels() {
+        let printed = |verbosity: Verbosity| {
+            let console = Console::builder()
+                .width(40)
+                .markup(false)
+                .verbosity(verbosity)
+                .build();
+            console.begin_capture();
+            console.print_with_options(
+                "debug detail",
+                &PrintOptions::new().with_level(Verbosity::Debug),
+            );
+            console.print_with_options(
+                "normal line",
+                &PrintOptions::new().with_level(Verbosity::Normal),
+            );
+            segments_to_plain(&console.end_capture())
+        };
+
+        assert_eq!(printed(Verbosity::Normal), "normal line\n");
+        assert_eq!(printed(Verbosity::Verbose), "debug detail\nnormal line\n");
+        assert_eq!(printed(Verbosity::Quiet), "");
+    }
+
+    #[test]
+    fn test_verbosity_quiet_hides_untagged_renderables() {
+        let console = Console::builder()
+            .width(40)
+            .verbosity(Verbosity::Quiet)
+            .build();
+        let table = Table::new()
+            .with_column(crate::renderables::Column::new("Name"))
+            .with_row_cells(["a"]);
+
+        console.begin_capture();
+        console.print_renderable(&table);
+        console.print_text(&Text::new("text"));
+        console.print_segments(&[Segment::new("segment\n", None)]);
+        assert_eq!(segments_to_plain(&console.end_capture()), "");
+
+        console.begin_capture();
+        console.print_renderable_with_options(
+            &table,
+            &PrintOptions::new().with_level(Verbosity::Quiet),
+        );
+        assert!(segments_to_plain(&console.end_capture()).contains('a'));
+
+        let mut out = Vec::new();
+        console.print_text_to(&mut out, &Text::new("text")).unwrap();
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    fn test_verbosity_quiet_keeps_errors() {
+        let console = Console::builder()
+            .width(40)
+            .verbosity(Verbosity::Quiet)
+            .build();
+        let err = io::Error::other("disk unreadable");
+
+        console.begin_capture();
+        console.print_error(&err);
+        let plain = segments_to_plain(&console.end_capture());
+        assert!(plain.contains("Error"), "{plain}");
+        assert!(plain.contains("disk unreadable"), "{plain}");
+
+        let traceback = crate::renderables::Traceback::new(
+            vec![crate::renderables::TracebackFrame::new("main", 3)],
+            "ErrorType",
+            "boom",
+        );
+        console.begin_capture();
+        console.print_exception(&traceback);
+        let plain = segments_to_plain(&console.end_capture());
+        assert!(plain.contains("ErrorType: boom"), "{plain}");
+    }
+
+    #[test]
+    fn test_downgrade_method_applies_to_output() {
+        let print = |method| {
+            let console = Console::builder()
+                .force_terminal(true)
+                .color_system(ColorSystem::EightBit)
+                .downgrade_method(method)
+                .build();
+            let mut out = Vec::new();
+            console
+                .print_to(
+                    &mut out,
+                    "[#80808c]gray[/]",
+                    &PrintOptions::new().with_markup(true),
+                )
+                .unwrap();
+            String::from_utf8(out).unwrap()
+        };
+        assert!(print(DowngradeMethod::Nearest).contains("38;5;245m"));
+        assert!(print(DowngradeMethod::Perceptual).contains("38;5;244m"));
+    }
+
+    #[test]
+    fn test_color_vision_filter_applies_to_output() {
+        let console = Console::builder()
+            .force_terminal(true)
+            .color_system(ColorSystem::TrueColor)
+            .color_vision_filter(Some(ColorVisionDeficiency::Deuteranopia))
+            .build();
+        let mut out = Vec::new();
+        console
+            .print_to(
+                &mut out,
+                "[#ff0000]alert[/]",
+                &PrintOptions::new().with_markup(true),
+            )
+            .unwrap();
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.contains("38;2;163;144;0"), "got {output:?}");
+        assert!(!output.contains("38;2;255;0;0"));
+    }
+
+    #[test]
+    fn test_bell_only_on_terminal() {
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .file(Box::new(buffer.clone()))
+            .build();
+        console.bell();
+        assert_eq!(buffer.0.lock().unwrap().as_slice(), b"\x07");
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(false)
+            .file(Box::new(buffer.clone()))
+            .build();
+        console.bell();
+        assert!(buffer.0.lock().unwrap().is_empty());
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .visual_bell(true)
+            .file(Box::new(buffer.clone()))
+            .build();
+        let start = Instant::now();
+        console.bell();
+        assert!(start.elapsed() < VISUAL_BELL_DURATION);
+        assert_eq!(buffer.0.lock().unwrap().as_slice(), b"\x1b[?5h");
+        std::thread::sleep(VISUAL_BELL_DURATION * 5);
+        assert_eq!(buffer.0.lock().unwrap().as_slice(), b"\x1b[?5h\x1b[?5l");
+
+        // Undone early by the next write, or when the console goes away.
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .visual_bell(true)
+            .file(Box::new(buffer.clone()))
+            .build();
+        console.bell();
+        console.print_segments(&[Segment::new("x", None)]);
+        assert!(buffer.0.lock().unwrap().starts_with(b"\x1b[?5h\x1b[?5lx"));
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .visual_bell(true)
+            .file(Box::new(buffer.clone()))
+            .build();
+        console.bell();
+        drop(console);
+        assert_eq!(buffer.0.lock().unwrap().as_slice(), b"\x1b[?5h\x1b[?5l");
+    }
+
+    #[test]
+    fn test_notify_and_terminal_progress_osc9() {
+        #[derive(Clone)]
+        struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+        impl Write for SharedBuffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .notifications(true)
+            .file(Box::new(buffer.clone()))
+            .build();
+        console
+            .set_terminal_progress(TerminalProgressState::Normal, 42)
+            .unwrap();
+        console
+            .set_terminal_progress(TerminalProgressState::Error, 250)
+            .unwrap();
+        console.notify("Build", "done\x07 ok").unwrap();
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert_eq!(
+            output,
+            "\x1b]9;4;1;42\x07\x1b]9;4;2;100\x07\x1b]9;Build: done ok\x07"
+        );
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .notifications(false)
+            .file(Box::new(buffer.clone()))
+            .build();
+        assert!(!console.supports_notifications());
+        console
+            .set_terminal_progress(TerminalProgressState::Normal, 42)
+            .unwrap();
+        console.notify("Build", "done").unwrap();
+        assert!(buffer.0.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_osc_output_is_captured_as_control_segments() {
+        let console = Console::builder()
+            .force_terminal(true)
+            .notifications(true)
+            .file(Box::new(io::sink()))
+            .build();
+        console.begin_capture();
+        console.set_window_title("Build").unwrap();
+        console
+            .set_terminal_progress(TerminalProgressState::Normal, 42)
+            .unwrap();
+        console.notify("Build", "done").unwrap();
+        let captured = console.end_capture();
+
+        let controls: Vec<_> = captured
+            .iter()
+            .flat_map(|segment| segment.control.iter().flatten())
+            .map(|control| control.control_type)
+            .collect();
+        assert_eq!(
+            controls,
+            [
+                ControlType::SetWindowTitle,
+                ControlType::TerminalProgress,
+                ControlType::Notify
+            ]
+        );
+        assert_eq!(captured[2].text, "Build: done");
+        assert_eq!(segments_to_plain(&captured), "");
+    }
+
+    #[test]
+    fn test_push_pop_theme_scopes_markup_styles() {
+        let console = Console::builder().markup(true).highlight(false).build();
+        let opts = PrintOptions::new().with_markup(true).with_no_newline(true);
+        let red = Style::parse("red").unwrap();
+
+        console.push_theme(
+            Theme::from_style_definitions([("accent", "red")], false).unwrap(),
+            true,
+        );
+        let segments = console.render_str_segments("[accent]x[/]", &opts);
+        let styled = segments.iter().find(|s| s.text == "x").unwrap();
+        assert_eq!(styled.style.as_ref(), Some(&red));
+
+        console.pop_theme().unwrap();
+        let segments = console.render_str_segments("[accent]x[/]", &opts);
+        let unstyled = segments.iter().find(|s| s.text == "x").unwrap();
+        assert!(
+            unstyled
+                .style
+                .as_ref()
+                .is_none_or(|style| style.color.is_none())
+        );
+
+        {
+            let _guard = console.use_theme(
+                Theme::from_style_definitions([("accent", "red")], false).unwrap(),
+                true,
+            );
+            let segments = console.render_str_segments("[accent]x[/]", &opts);
+            assert_eq!(segments[0].style.as_ref(), Some(&red));
+        }
+        assert!(
+            console.pop_theme().is_err(),
+            "guard should have popped its theme"
+        );
+    }
 }
@@ -58,6 +58,7 @@ pub fn get(name: &str) -> Option<&'static str> {
 /// - `:smile:` is replaced if the emoji exists (lookup uses lowercased name).
 /// - Optional variants: `:smile-emoji:` / `:smile-text:` append U+FE0F / U+FE0E.
 /// - Unknown emoji codes are left unchanged.
+/// - A backslash before a known code (`\:rocket:`) keeps it literal and is removed.
 /// - If `default_variant` is provided, it applies when no explicit variant is present.
 #[must_use]
 pub fn replace(text: &str, default_variant: Option<EmojiVariant>) -> Cow<'_, str> {
@@ -75,13 +76,19 @@ pub fn replace(text: &str, default_variant: Option<EmojiVariant>) -> Cow<'_, str
     while let Some(rel_start) = text[search..].find(':') {
         let start = search + rel_start;
         if let Some((end, replacement)) = try_replace_at(text, start, default_selector) {
+            let escaped = start > 0 && text.as_bytes()[start - 1] == b'\\';
+            let (before, replacement) = if escaped {
+                (start - 1, &text[start..=end])
+            } else {
+                (start, replacement.as_str())
+            };
             if let Some(buf) = out.as_mut() {
-                buf.push_str(&text[cursor..start]);
-                buf.push_str(&replacement);
+                buf.push_str(&text[cursor..before]);
+                buf.push_str(replacement);
             } else {
                 let mut buf = String::with_capacity(text.len());
-                buf.push_str(&text[..start]);
-                buf.push_str(&replacement);
+                buf.push_str(&text[..before]);
+                buf.push_str(replacement);
                 out = Some(buf);
             }
             cursor = end + 1;
@@ -155,6 +162,13 @@ mod tests {
         assert_eq!(replace("hi :smile:", None), "hi 😄");
     }
 
+    #[test]
+    fn test_replace_escaped_code_stays_literal() {
+        assert_eq!(replace("\\:rocket: :rocket:", None), ":rocket: 🚀");
+        // Unknown codes keep their backslash.
+        assert_eq!(replace("\\:not-an-emoji:", None), "\\:not-an-emoji:");
+    }
+
     #[test]
     fn test_replace_lowercases_name() {
         assert_eq!(replace("hi :SMILE:", None), "hi 😄");
@@ -20,6 +20,15 @@
 //! // Custom precision
 //! use rich_rust::filesize::decimal_with_precision;
 //! assert_eq!(decimal_with_precision(1_536_000, 2), "1.54 MB");
+//!
+//! // Fixed unit, e.g. for aligned columns
+//! use rich_rust::filesize::{format, FormatOptions, Magnitude};
+//! let options = FormatOptions {
+//!     unit: Some(Magnitude::Mega),
+//!     precision: 2,
+//!     system: SizeUnit::Decimal,
+//! };
+//! assert_eq!(format(500_000, &options), "0.50 MB");
 //! ```
 
 /// Units for binary (1024-based) file sizes.
@@ -40,6 +49,96 @@ pub enum SizeUnit {
     Decimal,
 }
 
+/// A fixed unit magnitude for [`format`].
+///
+/// The printed suffix depends on the unit system: `Mega` is "MB" for
+/// decimal and "MiB" for binary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Magnitude {
+    /// Plain bytes.
+    Bytes,
+    /// `kB` / `KiB`.
+    Kilo,
+    /// `MB` / `MiB`.
+    Mega,
+    /// `GB` / `GiB`.
+    Giga,
+    /// `TB` / `TiB`.
+    Tera,
+    /// `PB` / `PiB`.
+    Peta,
+    /// `EB` / `EiB`.
+    Exa,
+    /// `ZB` / `ZiB`.
+    Zetta,
+    /// `YB` / `YiB`.
+    Yotta,
+}
+
+/// Options for [`format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// Always format in this unit; `None` picks the largest unit that keeps
+    /// the value at or above one.
+    pub unit: Option<Magnitude>,
+    /// Number of decimal places.
+    pub precision: usize,
+    /// Binary (1024) or decimal (1000) units.
+    pub system: SizeUnit,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            unit: None,
+            precision: 1,
+            system: SizeUnit::Decimal,
+        }
+    }
+}
+
+/// Format a size in bytes with explicit unit and precision control.
+///
+/// Forcing a unit keeps a column of sizes aligned; without one this behaves
+/// like [`format_size`].
+///
+/// # Examples
+///
+/// ```
+/// use rich_rust::filesize::{format, FormatOptions, Magnitude, SizeUnit};
+///
+/// let fixed = FormatOptions {
+///     unit: Some(Magnitude::Mega),
+///     precision: 2,
+///     system: SizeUnit::Decimal,
+/// };
+/// assert_eq!(format(500_000, &fixed), "0.50 MB");
+/// assert_eq!(format(500_000, &FormatOptions::default()), "500.0 kB");
+/// ```
+#[must_use]
+pub fn format(size: i64, options: &FormatOptions) -> String {
+    let Some(unit) = options.unit else {
+        return format_size(size, options.system, options.precision);
+    };
+    let (base, units): (f64, &[&str]) = match options.system {
+        SizeUnit::Binary => (1024.0, BINARY_UNITS),
+        SizeUnit::Decimal => (1000.0, DECIMAL_UNITS),
+    };
+    if unit == Magnitude::Bytes {
+        return format!("{size} bytes");
+    }
+
+    let unit_idx = unit as usize;
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_possible_wrap
+    )]
+    let value = size as f64 / base.powi(unit_idx as i32);
+    let precision = options.precision;
+    format!("{value:.precision$} {}", units[unit_idx])
+}
+
 /// Format a size in bytes to a human-readable string.
 ///
 /// # Arguments
@@ -255,6 +354,18 @@ pub fn binary_speed(bytes_per_second: f64) -> String {
     format_speed(bytes_per_second, SizeUnit::Binary, 1)
 }
 
+/// Insert `separator` between groups of three in a string of ASCII digits.
+pub(crate) fn group_digits(digits: &str, separator: char) -> String {
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    for (i, c) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i).is_multiple_of(3) {
+            grouped.push(separator);
+        }
+        grouped.push(c);
+    }
+    grouped
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -362,6 +473,31 @@ mod tests {
         assert_eq!(binary(1_152_921_504_606_846_976), "1.0 EiB");
     }
 
+    #[test]
+    fn test_format_fixed_unit() {
+        let mega = FormatOptions {
+            unit: Some(Magnitude::Mega),
+            precision: 2,
+            system: SizeUnit::Decimal,
+        };
+        assert_eq!(format(500_000, &mega), "0.50 MB");
+        assert_eq!(format(-1_500_000, &mega), "-1.50 MB");
+        assert_eq!(format(500_000, &FormatOptions::default()), "500.0 kB");
+
+        let kibi = FormatOptions {
+            unit: Some(Magnitude::Kilo),
+            precision: 0,
+            system: SizeUnit::Binary,
+        };
+        assert_eq!(format(10_240, &kibi), "10 KiB");
+
+        let bytes = FormatOptions {
+            unit: Some(Magnitude::Bytes),
+            ..FormatOptions::default()
+        };
+        assert_eq!(format(1_500, &bytes), "1500 bytes");
+    }
+
     #[test]
     fn test_speed_nan_handling() {
         assert_eq!(format_speed(f64::NAN, SizeUnit::Decimal, 1), "NaN");
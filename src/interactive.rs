@@ -196,13 +196,13 @@
 //! - **bd-fal7**: Wire `read_line_limited` into `Prompt::ask_from`
 
 use std::io;
-use std::io::Write as _;
+use std::io::{Read as _, Write as _};
 use std::process::{Command, Stdio};
 use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 use crate::console::Console;
-use crate::console::PrintOptions;
+use crate::console::{PrintOptions, Verbosity};
 use crate::live::{Live, LiveOptions};
 use crate::markup;
 use crate::style::Style;
@@ -548,6 +548,7 @@ impl Prompt {
             return self.default.clone().ok_or(PromptError::NotInteractive);
         }
 
+        let _suspended = console.suspend_live();
         loop {
             self.print_prompt(console);
 
@@ -597,7 +598,8 @@ impl Prompt {
             &PrintOptions::new()
                 .with_markup(self.markup)
                 .with_no_newline(true)
-                .with_highlight(self.markup),
+                .with_highlight(self.markup)
+                .with_level(Verbosity::Quiet),
         );
     }
 
@@ -605,7 +607,10 @@ impl Prompt {
         let style = Style::parse("bold red").unwrap_or_default();
         console.print_with_options(
             message,
-            &PrintOptions::new().with_markup(false).with_style(style),
+            &PrintOptions::new()
+                .with_markup(false)
+                .with_style(style)
+                .with_level(Verbosity::Quiet),
         );
     }
 }
@@ -718,7 +723,10 @@ fn spawn_pager(command: &str, args: &[String], content: &str) -> io::Result<()>
 fn print_exact(console: &Console, content: &str) {
     console.print_with_options(
         content,
-        &PrintOptions::new().with_markup(false).with_no_newline(true),
+        &PrintOptions::new()
+            .with_markup(false)
+            .with_no_newline(true)
+            .with_level(Verbosity::Quiet),
     );
 }
 
@@ -779,6 +787,157 @@ fn read_line_limited<R: io::BufRead>(
     String::from_utf8(buf).map_err(|e| PromptError::Validation(format!("invalid UTF-8: {e}")))
 }
 
+/// Read a secret up to Enter without relying on terminal line editing.
+///
+/// Works on raw-mode input (Enter arrives as `\r`, Backspace as DEL) as
+/// well as on plain lines. Ctrl-C aborts with [`io::ErrorKind::Interrupted`].
+pub(crate) fn read_secret<R: io::BufRead>(
+    reader: &mut R,
+    max_bytes: usize,
+) -> Result<String, PromptError> {
+    let mut buf = Vec::new();
+    let mut terminated = false;
+    for byte in reader.by_ref().bytes() {
+        match byte? {
+            b'\n' | b'\r' => {
+                terminated = true;
+                break;
+            }
+            0x03 => return Err(PromptError::Io(io::ErrorKind::Interrupted.into())),
+            0x7f | 0x08 => {
+                // Drop the last whole UTF-8 character.
+                while let Some(removed) = buf.pop() {
+                    if removed & 0xC0 != 0x80 {
+                        break;
+                    }
+                }
+            }
+            byte => {
+                if buf.len() >= max_bytes {
+                    return Err(PromptError::InputTooLong {
+                        limit: max_bytes,
+                        received: buf.len() + 1,
+                    });
+                }
+                buf.push(byte);
+            }
+        }
+    }
+    if !terminated && buf.is_empty() {
+        return Err(PromptError::Eof);
+    }
+    String::from_utf8(buf).map_err(|e| PromptError::Validation(format!("invalid UTF-8: {e}")))
+}
+
+/// Turns terminal echo off while alive and back on when dropped.
+///
+/// Dropping also runs during unwinding, so a panic while reading a password
+/// doesn't leave the terminal silent.
+pub(crate) struct EchoGuard {
+    restore: fn() -> io::Result<()>,
+}
+
+impl EchoGuard {
+    /// Disable echo by switching the terminal to raw mode.
+    pub(crate) fn disable() -> io::Result<Self> {
+        Self::with(
+            crate::terminal::enable_raw_mode,
+            crate::terminal::disable_raw_mode,
+        )
+    }
+
+    fn with(disable: fn() -> io::Result<()>, restore: fn() -> io::Result<()>) -> io::Result<Self> {
+        disable()?;
+        Ok(Self { restore })
+    }
+}
+
+impl Drop for EchoGuard {
+    fn drop(&mut self) {
+        let _ = (self.restore)();
+    }
+}
+
+/// A single key press read by [`Console::read_key_timeout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Key {
+    /// A printable character.
+    Char(char),
+    /// A letter pressed with Ctrl (`Ctrl('c')` for Ctrl-C).
+    Ctrl(char),
+    Enter,
+    Tab,
+    /// Shift-Tab.
+    BackTab,
+    Backspace,
+    Esc,
+    Up,
+    Down,
+    Left,
+    Right,
+    Home,
+    End,
+    PageUp,
+    PageDown,
+    Insert,
+    Delete,
+    /// A function key, `F(1)` through `F(12)`.
+    F(u8),
+}
+
+/// Wait until `deadline` for a key press from a stream of terminal events.
+///
+/// `next` waits up to the given duration for an event and returns `None` on
+/// timeout. Non-key events and unmapped keys are skipped.
+pub(crate) fn next_key(
+    mut next: impl FnMut(Duration) -> Option<crossterm::event::Event>,
+    deadline: Instant,
+) -> Option<Key> {
+    loop {
+        if let crossterm::event::Event::Key(event) =
+            next(deadline.saturating_duration_since(Instant::now()))?
+            && let Some(key) = key_from_event(&event)
+        {
+            return Some(key);
+        }
+        if Instant::now() >= deadline {
+            return None;
+        }
+    }
+}
+
+/// Map a terminal key event to a [`Key`], ignoring releases and unmapped keys.
+pub(crate) fn key_from_event(event: &crossterm::event::KeyEvent) -> Option<Key> {
+    use crossterm::event::{KeyCode, KeyEventKind, KeyModifiers};
+
+    if event.kind == KeyEventKind::Release {
+        return None;
+    }
+    Some(match event.code {
+        KeyCode::Char(c) if event.modifiers.contains(KeyModifiers::CONTROL) => {
+            Key::Ctrl(c.to_ascii_lowercase())
+        }
+        KeyCode::Char(c) => Key::Char(c),
+        KeyCode::Enter => Key::Enter,
+        KeyCode::Tab => Key::Tab,
+        KeyCode::BackTab => Key::BackTab,
+        KeyCode::Backspace => Key::Backspace,
+        KeyCode::Esc => Key::Esc,
+        KeyCode::Up => Key::Up,
+        KeyCode::Down => Key::Down,
+        KeyCode::Left => Key::Left,
+        KeyCode::Right => Key::Right,
+        KeyCode::Home => Key::Home,
+        KeyCode::End => Key::End,
+        KeyCode::PageUp => Key::PageUp,
+        KeyCode::PageDown => Key::PageDown,
+        KeyCode::Insert => Key::Insert,
+        KeyCode::Delete => Key::Delete,
+        KeyCode::F(n) => Key::F(n),
+        _ => return None,
+    })
+}
+
 fn trim_newline(line: &str) -> &str {
     line.trim_end_matches(&['\n', '\r'][..])
 }
@@ -933,6 +1092,7 @@ impl Select {
             return self.default.clone().ok_or(PromptError::NotInteractive);
         }
 
+        let _suspended = console.suspend_live();
         loop {
             self.print_choices(console);
             self.print_prompt(console);
@@ -987,7 +1147,12 @@ impl Select {
                 format!("  [cyan]{num}.[/] {display}")
             };
 
-            console.print_with_options(&line, &PrintOptions::new().with_markup(self.markup));
+            console.print_with_options(
+                &line,
+                &PrintOptions::new()
+                    .with_markup(self.markup)
+                    .with_level(Verbosity::Quiet),
+            );
         }
     }
 
@@ -1015,7 +1180,8 @@ impl Select {
             &PrintOptions::new()
                 .with_markup(self.markup)
                 .with_no_newline(true)
-                .with_highlight(self.markup),
+                .with_highlight(self.markup)
+                .with_level(Verbosity::Quiet),
         );
     }
 
@@ -1023,7 +1189,10 @@ impl Select {
         let style = Style::parse("bold red").unwrap_or_default();
         console.print_with_options(
             message,
-            &PrintOptions::new().with_markup(false).with_style(style),
+            &PrintOptions::new()
+                .with_markup(false)
+                .with_style(style)
+                .with_level(Verbosity::Quiet),
         );
     }
 }
@@ -1100,6 +1269,7 @@ impl Confirm {
             return self.default.ok_or(PromptError::NotInteractive);
         }
 
+        let _suspended = console.suspend_live();
         loop {
             self.print_prompt(console);
 
@@ -1141,7 +1311,8 @@ impl Confirm {
             &PrintOptions::new()
                 .with_markup(self.markup)
                 .with_no_newline(true)
-                .with_highlight(self.markup),
+                .with_highlight(self.markup)
+                .with_level(Verbosity::Quiet),
         );
     }
 
@@ -1149,7 +1320,10 @@ impl Confirm {
         let style = Style::parse("bold red").unwrap_or_default();
         console.print_with_options(
             message,
-            &PrintOptions::new().with_markup(false).with_style(style),
+            &PrintOptions::new()
+                .with_markup(false)
+                .with_style(style)
+                .with_level(Verbosity::Quiet),
         );
     }
 }
@@ -1190,6 +1364,135 @@ mod tests {
         assert!(text.contains("Working...\n"));
     }
 
+    #[test]
+    fn test_next_key_maps_events_and_times_out() {
+        use crossterm::event::{Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+
+        let mut release = KeyEvent::new(KeyCode::Char('x'), KeyModifiers::NONE);
+        release.kind = KeyEventKind::Release;
+        let mut events = std::collections::VecDeque::from([
+            Event::FocusGained,
+            Event::Key(release),
+            Event::Key(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)),
+            Event::Key(KeyEvent::new(KeyCode::Char('C'), KeyModifiers::CONTROL)),
+            Event::Key(KeyEvent::new(KeyCode::F(5), KeyModifiers::NONE)),
+        ]);
+        let timeout = Duration::from_millis(50);
+        let mut read = || next_key(|_| events.pop_front(), Instant::now() + timeout);
+        assert_eq!(read(), Some(Key::Up));
+        assert_eq!(read(), Some(Key::Ctrl('c')));
+        assert_eq!(read(), Some(Key::F(5)));
+        assert_eq!(read(), None);
+    }
+
+    #[test]
+    fn test_prompt_password_reads_secret_without_echo() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        static ECHO_OFF: AtomicBool = AtomicBool::new(false);
+        #[allow(clippy::unnecessary_wraps)]
+        fn echo_off() -> io::Result<()> {
+            ECHO_OFF.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+        #[allow(clippy::unnecessary_wraps)]
+        fn echo_on() -> io::Result<()> {
+            ECHO_OFF.store(false, Ordering::SeqCst);
+            Ok(())
+        }
+
+        /// Records whether echo was off whenever the prompt read input.
+        struct EchoProbe {
+            input: io::Cursor<Vec<u8>>,
+            read_with_echo: bool,
+        }
+
+        impl io::Read for EchoProbe {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.read_with_echo |= !ECHO_OFF.load(Ordering::SeqCst);
+                self.input.read(buf)
+            }
+        }
+
+        impl io::BufRead for EchoProbe {
+            fn fill_buf(&mut self) -> io::Result<&[u8]> {
+                self.read_with_echo |= !ECHO_OFF.load(Ordering::SeqCst);
+                self.input.fill_buf()
+            }
+            fn consume(&mut self, amount: usize) {
+                self.input.consume(amount);
+            }
+        }
+
+        struct PanickingReader;
+
+        impl io::Read for PanickingReader {
+            fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+                panic!("reader failed");
+            }
+        }
+
+        fn no_echo() -> io::Result<EchoGuard> {
+            EchoGuard::with(echo_off, echo_on)
+        }
+
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .file(Box::new(buffer.clone()))
+            .build();
+
+        let mut reader = EchoProbe {
+            input: io::Cursor::new(b"huntex\x7fr2\rleftover".to_vec()),
+            read_with_echo: false,
+        };
+        let secret = console.prompt_password_with("[bold]Password[/]", &mut reader, Some(no_echo));
+        assert_eq!(secret.expect("secret"), "hunter2");
+        assert!(!reader.read_with_echo, "echo was on while reading");
+        assert!(!ECHO_OFF.load(Ordering::SeqCst), "echo not restored");
+
+        let out = String::from_utf8_lossy(&buffer.0.lock().unwrap()).to_string();
+        assert!(out.contains("Password"), "{out:?}");
+        assert!(!out.contains("[bold]"), "{out:?}");
+        assert!(!out.contains("hunter"), "secret echoed: {out:?}");
+
+        // Echo comes back when reading fails.
+        let mut reader = EchoProbe {
+            input: io::Cursor::new(b"ab\x03".to_vec()),
+            read_with_echo: false,
+        };
+        let result = console.prompt_password_with("Password", &mut reader, Some(no_echo));
+        assert!(matches!(
+            result,
+            Err(PromptError::Io(e)) if e.kind() == io::ErrorKind::Interrupted
+        ));
+        assert!(!reader.read_with_echo, "echo was on while reading");
+        assert!(
+            !ECHO_OFF.load(Ordering::SeqCst),
+            "echo not restored on error"
+        );
+
+        // And when reading panics.
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            let mut reader = io::BufReader::new(PanickingReader);
+            console.prompt_password_with("Password", &mut reader, Some(no_echo))
+        }));
+        assert!(result.is_err());
+        assert!(
+            !ECHO_OFF.load(Ordering::SeqCst),
+            "echo not restored on panic"
+        );
+
+        assert!(matches!(
+            read_secret(&mut io::Cursor::new(b"".to_vec()), 16),
+            Err(PromptError::Eof)
+        ));
+        assert!(matches!(
+            read_secret(&mut io::Cursor::new(b"ab\x03".to_vec()), 16),
+            Err(PromptError::Io(e)) if e.kind() == io::ErrorKind::Interrupted
+        ));
+    }
+
     #[test]
     fn test_prompt_non_interactive_uses_default() {
         let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
@@ -1241,6 +1544,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_prompt_stays_visible_in_quiet_mode() {
+        let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .verbosity(Verbosity::Quiet)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+
+        let prompt = Prompt::new("Age").validate(|value| {
+            if value.chars().all(|c| c.is_ascii_digit()) {
+                Ok(())
+            } else {
+                Err("digits only".to_string())
+            }
+        });
+        let mut reader = io::Cursor::new(&b"nope\n42\n"[..]);
+        assert_eq!(prompt.ask_from(&console, &mut reader).unwrap(), "42");
+
+        let out = buffer.0.lock().unwrap();
+        let text = String::from_utf8_lossy(&out);
+        assert!(text.contains("Age: "), "{text:?}");
+        assert!(text.contains("digits only"), "{text:?}");
+    }
+
     #[test]
     fn test_pager_non_interactive_falls_back_to_print() {
         let buffer = SharedBuffer(Arc::new(Mutex::new(Vec::new())));
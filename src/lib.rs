@@ -64,6 +64,7 @@
 //! - **`markdown`**: Markdown rendering via pulldown-cmark
 //! - **`json`**: JSON formatting with syntax highlighting
 //! - **`tracing`**: Tracing integration via `RichTracingLayer`
+//! - **`tokio`**: Async refresh driver for [`live::Live`] (`Live::spawn_refresh`)
 //!
 //! ```toml
 //! [dependencies]
@@ -192,21 +193,22 @@ pub mod prelude {
     pub use crate::ansi::AnsiDecoder;
     pub use crate::r#box::BoxChars;
     pub use crate::color::{
-        Color, ColorSystem, ColorTriplet, ColorType, DEFAULT_TERMINAL_THEME, DIMMED_MONOKAI,
-        MONOKAI, NIGHT_OWLISH, SVG_EXPORT_THEME, TerminalTheme,
+        Color, ColorSystem, ColorTriplet, ColorType, ColorVisionDeficiency, DEFAULT_TERMINAL_THEME,
+        DIMMED_MONOKAI, DowngradeMethod, MONOKAI, NIGHT_OWLISH, SVG_EXPORT_THEME, TerminalTheme,
     };
     pub use crate::console::{
-        CONSOLE_HTML_FORMAT, CONSOLE_SVG_FORMAT, Console, ConsoleOptions, ExportHtmlOptions,
-        ExportSvgOptions, LogLevel, LogOptions,
+        AnsiEncoder, CONSOLE_HTML_FORMAT, CONSOLE_SVG_FORMAT, Clock, Console, ConsoleOptions,
+        ExportHtmlOptions, ExportSvgOptions, LegacyWindowsEncoder, LineEnding, LogLevel,
+        LogOptions, OutputEncoder, SystemClock, TerminalProgressState, Verbosity,
     };
     pub use crate::emoji::EmojiVariant;
     pub use crate::filesize::{
-        SizeUnit, binary, binary_speed, binary_with_precision, decimal, decimal_speed,
-        decimal_with_precision, format_size, format_speed,
+        FormatOptions, Magnitude, SizeUnit, binary, binary_speed, binary_with_precision, decimal,
+        decimal_speed, decimal_with_precision, format_size, format_speed,
     };
     pub use crate::highlighter::{Highlighter, NullHighlighter, RegexHighlighter, ReprHighlighter};
-    pub use crate::interactive::{Pager, Prompt, PromptError, Status};
-    pub use crate::live::{Live, LiveOptions, VerticalOverflowMethod};
+    pub use crate::interactive::{Key, Pager, Prompt, PromptError, Status};
+    pub use crate::live::{Live, LiveManager, LiveOptions, LiveRegion, VerticalOverflowMethod};
     pub use crate::logging::RichLogger;
     #[cfg(feature = "tracing")]
     pub use crate::logging::RichTracingLayer;
@@ -216,22 +218,24 @@ pub mod prelude {
         Align, AlignLines, AlignMethod, BarStyle, Cell, Column, Columns, Constrain, Control,
         DownloadColumn, Emoji, FileSizeColumn, Inspect, InspectOptions, Layout, LayoutSplitter,
         PaddingDimensions, Panel, Pretty, PrettyOptions, ProgressBar, Region, Row, Rule, Spinner,
-        Table, TotalFileSizeColumn, Traceback, TracebackFrame, TransferSpeedColumn, Tree,
+        Table, ToRow, TotalFileSizeColumn, Traceback, TracebackFrame, TransferSpeedColumn, Tree,
         TreeGuides, TreeNode, VerticalAlign, VerticalAlignMethod, align_text, inspect,
         print_exception,
     };
     pub use crate::segment::{
-        ControlCode, ControlType, Segment, escape_control_codes, strip_control_codes,
+        ControlCode, ControlType, Segment, escape_control_codes, segments_to_plain,
+        strip_control_codes,
     };
     pub use crate::style::{Attributes, Style};
-    pub use crate::text::{JustifyMethod, OverflowMethod, Span, Text};
+    pub use crate::terminal::{Background, TerminalEnvironment};
+    pub use crate::text::{JustifyMethod, OverflowMethod, Span, Text, WordOptions};
     pub use crate::theme::{Theme, ThemeError, ThemeStackError};
 
     #[cfg(feature = "syntax")]
-    pub use crate::renderables::{Syntax, SyntaxError};
+    pub use crate::renderables::{Syntax, SyntaxError, TokenKind};
 
     #[cfg(feature = "markdown")]
-    pub use crate::renderables::Markdown;
+    pub use crate::renderables::{Markdown, MarkdownTheme};
 
     #[cfg(feature = "json")]
     pub use crate::renderables::{Json, JsonError, JsonTheme};
@@ -244,7 +248,7 @@ pub use color::{
 };
 pub use console::Console;
 pub use console::{CONSOLE_HTML_FORMAT, CONSOLE_SVG_FORMAT, ExportHtmlOptions, ExportSvgOptions};
-pub use live::{Live, LiveOptions, VerticalOverflowMethod};
+pub use live::{Live, LiveManager, LiveOptions, LiveRegion, VerticalOverflowMethod};
 pub use logging::RichLogger;
 #[cfg(feature = "tracing")]
 pub use logging::RichTracingLayer;
@@ -4,6 +4,7 @@
 
 use std::io;
 use std::io::{Read, Write};
+use std::ops::Range;
 use std::sync::{
     Arc, Mutex, RwLock,
     atomic::{AtomicBool, Ordering},
@@ -34,6 +35,9 @@ pub enum VerticalOverflowMethod {
 /// Configuration for Live.
 #[derive(Debug, Clone)]
 pub struct LiveOptions {
+    /// Full-screen mode: draw in the alternate screen buffer, cleared and
+    /// redrawn from the top-left corner on every refresh. The primary screen
+    /// is restored on stop.
     pub screen: bool,
     pub auto_refresh: bool,
     pub refresh_per_second: f64,
@@ -177,8 +181,15 @@ pub(crate) struct LiveInner {
     alt_screen_active: AtomicBool,
     refresh_stop: Arc<AtomicBool>,
     refresh_thread: Mutex<Option<JoinHandle<()>>>,
+    /// Async refresh task started by [`Live::spawn_refresh`].
+    #[cfg(feature = "tokio")]
+    refresh_task: Mutex<Option<tokio::task::AbortHandle>>,
     live_render: Mutex<LiveRender>,
     stdio_redirect: Mutex<Option<StdioRedirect>>,
+    /// Draw the bottom line in reverse video (visual bell).
+    flashing: AtomicBool,
+    /// Erased and not redrawn while a prompt is reading input.
+    suspended: AtomicBool,
 }
 
 impl Live {
@@ -210,8 +221,12 @@ impl Live {
                 alt_screen_active: AtomicBool::new(false),
                 refresh_stop: Arc::new(AtomicBool::new(false)),
                 refresh_thread: Mutex::new(None),
+                #[cfg(feature = "tokio")]
+                refresh_task: Mutex::new(None),
                 live_render: Mutex::new(LiveRender::default()),
                 stdio_redirect: Mutex::new(None),
+                flashing: AtomicBool::new(false),
+                suspended: AtomicBool::new(false),
             }),
         }
     }
@@ -276,6 +291,46 @@ impl Live {
         Ok(())
     }
 
+    /// Refresh the display from a tokio task instead of a blocking thread.
+    ///
+    /// Ticks at `refresh_per_second` on the runtime behind `handle`. Start the
+    /// Live with `auto_refresh: false` so the background thread isn't also
+    /// running. The task ends when the Live is stopped or dropped.
+    ///
+    /// ```rust,ignore
+    /// let options = LiveOptions { auto_refresh: false, ..LiveOptions::default() };
+    /// let live = Live::with_options(console, options).renderable(table);
+    /// live.start(true)?;
+    /// let _refresh = live.spawn_refresh(&tokio::runtime::Handle::current());
+    /// ```
+    #[cfg(feature = "tokio")]
+    #[must_use]
+    pub fn spawn_refresh(&self, handle: &tokio::runtime::Handle) -> tokio::task::JoinHandle<()> {
+        let period = Duration::from_secs_f64(1.0 / self.inner.options().refresh_per_second);
+        let inner = Arc::downgrade(&self.inner);
+        let task = handle.spawn(async move {
+            let mut interval = tokio::time::interval(period);
+            interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            // The first tick completes immediately; `start` already drew a frame.
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                let Some(inner) = inner.upgrade() else {
+                    break;
+                };
+                if !inner.started.load(Ordering::SeqCst) {
+                    break;
+                }
+                let _ = inner.refresh_display();
+            }
+        });
+        if let Some(previous) = lock_recover(&self.inner.refresh_task).replace(task.abort_handle())
+        {
+            previous.abort();
+        }
+        task
+    }
+
     /// Stop the Live display.
     pub fn stop(&self) -> io::Result<()> {
         if !self.inner.started.swap(false, Ordering::SeqCst) {
@@ -293,6 +348,8 @@ impl Live {
             let mut options = self.inner.options_mut();
             options.vertical_overflow = VerticalOverflowMethod::Visible;
         }
+        // The final frame stays on screen, so don't leave it mid-flash.
+        self.inner.flashing.store(false, Ordering::SeqCst);
 
         if !self.inner.alt_screen_active.load(Ordering::SeqCst) && self.inner.console.is_terminal()
         {
@@ -305,11 +362,14 @@ impl Live {
 
         self.inner.stop_stdio_redirect();
 
-        if self.inner.alt_screen_active.swap(false, Ordering::SeqCst) {
+        // Leaving the alternate screen already restores the primary one, so
+        // there is no live region left to erase there.
+        let was_screen = self.inner.alt_screen_active.swap(false, Ordering::SeqCst);
+        if was_screen {
             let _ = self.inner.console.set_alt_screen(false);
         }
 
-        if self.inner.options().transient && !self.inner.alt_screen_active.load(Ordering::SeqCst) {
+        if self.inner.options().transient && !was_screen {
             let controls = self.inner.live_render_controls_restore();
             let _ = self.inner.console.write_control_codes(controls);
         }
@@ -333,6 +393,33 @@ impl Live {
         self.inner.refresh_display()
     }
 
+    /// Redraw immediately at the console's current size.
+    ///
+    /// Call this from a `SIGWINCH` handler, or after
+    /// [`Console::set_size`], instead of waiting for the next refresh. The
+    /// previous frame is erased as the terminal re-wrapped it, so narrowing
+    /// the window leaves no stale rows behind.
+    pub fn notify_resize(&self) -> io::Result<()> {
+        self.inner.refresh_display()
+    }
+
+    /// Number of terminal lines currently occupied by the live region.
+    #[must_use]
+    pub fn rendered_height(&self) -> usize {
+        lock_recover(&self.inner.live_render)
+            .shape
+            .map_or(0, |(_, height)| height)
+    }
+
+    /// Erase the live region without stopping the display.
+    ///
+    /// The cursor is left at the start of the erased region, so output written
+    /// next appears where the display was; the next refresh redraws it below.
+    /// Does nothing on non-interactive consoles.
+    pub fn clear(&self) -> io::Result<()> {
+        self.inner.erase()
+    }
+
     /// Create a stdout proxy writer that routes output through the Console.
     #[must_use]
     pub fn stdout_proxy(&self) -> LiveWriter {
@@ -352,6 +439,175 @@ impl Drop for Live {
     }
 }
 
+/// One region of a [`LiveManager`] display: its content and assigned rows.
+struct RegionSlot {
+    renderable: Option<Box<dyn Renderable + Send + Sync>>,
+    rows: Range<usize>,
+}
+
+type RegionSlots = Arc<Mutex<Vec<RegionSlot>>>;
+
+/// Coordinates several independently updated regions in one live display.
+///
+/// Regions are stacked top to bottom in the order they were added and drawn
+/// together by a single [`Live`], so updating one region never overwrites
+/// another. Each region is assigned the screen rows it occupied in the last
+/// render (see [`LiveRegion::rows`]).
+///
+/// ```rust,ignore
+/// let manager = LiveManager::new(console.shared());
+/// let progress = manager.add_region(Text::new("0%"));
+/// let log = manager.add_region(Text::new(""));
+/// manager.start()?;
+/// progress.update(Text::new("50%"), true);
+/// log.update(Text::new("fetched index"), true);
+/// manager.stop()?;
+/// ```
+pub struct LiveManager {
+    live: Live,
+    regions: RegionSlots,
+}
+
+impl LiveManager {
+    /// Create a manager drawing to `console` with default [`LiveOptions`].
+    #[must_use]
+    pub fn new(console: Arc<Console>) -> Self {
+        Self::with_options(console, LiveOptions::default())
+    }
+
+    /// Create a manager with explicit options for the underlying [`Live`].
+    #[must_use]
+    pub fn with_options(console: Arc<Console>, options: LiveOptions) -> Self {
+        let regions: RegionSlots = Arc::new(Mutex::new(Vec::new()));
+        let stacked = Arc::clone(&regions);
+        let live = Live::with_options(console, options).get_renderable(move || {
+            Box::new(StackedRegions {
+                regions: Arc::clone(&stacked),
+            })
+        });
+        Self { live, regions }
+    }
+
+    /// Add a region below the existing ones and return its handle.
+    pub fn add_region<R>(&self, renderable: R) -> LiveRegion
+    where
+        R: Renderable + Send + Sync + 'static,
+    {
+        let mut regions = lock_recover(&self.regions);
+        regions.push(RegionSlot {
+            renderable: Some(Box::new(renderable)),
+            rows: 0..0,
+        });
+        LiveRegion {
+            index: regions.len() - 1,
+            regions: Arc::clone(&self.regions),
+            live: Arc::clone(&self.live.inner),
+        }
+    }
+
+    /// Start drawing all regions.
+    pub fn start(&self) -> io::Result<()> {
+        self.live.start(true)
+    }
+
+    /// Stop the display, leaving the final frame on screen unless transient.
+    pub fn stop(&self) -> io::Result<()> {
+        self.live.stop()
+    }
+
+    /// Redraw all regions.
+    pub fn refresh(&self) -> io::Result<()> {
+        self.live.refresh()
+    }
+}
+
+impl std::fmt::Debug for LiveManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LiveManager")
+            .field("regions", &lock_recover(&self.regions).len())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Handle to one region of a [`LiveManager`].
+#[derive(Clone)]
+pub struct LiveRegion {
+    index: usize,
+    regions: RegionSlots,
+    live: Arc<LiveInner>,
+}
+
+impl LiveRegion {
+    /// Replace this region's content, optionally redrawing all regions.
+    pub fn update<R>(&self, renderable: R, refresh: bool)
+    where
+        R: Renderable + Send + Sync + 'static,
+    {
+        if let Some(slot) = lock_recover(&self.regions).get_mut(self.index) {
+            slot.renderable = Some(Box::new(renderable));
+        }
+        if refresh {
+            let _ = self.live.refresh_display();
+        }
+    }
+
+    /// Rows (relative to the top of the display) this region occupied in the
+    /// last render.
+    #[must_use]
+    pub fn rows(&self) -> Range<usize> {
+        lock_recover(&self.regions)
+            .get(self.index)
+            .map_or(0..0, |slot| slot.rows.clone())
+    }
+}
+
+impl std::fmt::Debug for LiveRegion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LiveRegion")
+            .field("index", &self.index)
+            .field("rows", &self.rows())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Renders every region of a [`LiveManager`] stacked, recording row spans.
+struct StackedRegions {
+    regions: RegionSlots,
+}
+
+impl Renderable for StackedRegions {
+    fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        let mut regions = lock_recover(&self.regions);
+        let mut output = Vec::new();
+        let mut row = 0;
+        for slot in regions.iter_mut() {
+            let segments = slot
+                .renderable
+                .as_ref()
+                .map_or_else(Vec::new, |renderable| {
+                    renderable
+                        .render(console, options)
+                        .into_iter()
+                        .map(Segment::into_owned)
+                        .collect()
+                });
+            let mut lines = split_lines(segments.into_iter());
+            if lines.last().is_some_and(Vec::is_empty) {
+                lines.pop();
+            }
+            slot.rows = row..row + lines.len();
+            for line in lines {
+                if row > 0 {
+                    output.push(Segment::line());
+                }
+                output.extend(line);
+                row += 1;
+            }
+        }
+        output
+    }
+}
+
 impl LiveInner {
     fn options(&self) -> LiveOptions {
         lock_recover(&self.options).clone()
@@ -460,8 +716,39 @@ impl LiveInner {
         output
     }
 
+    fn erase(&self) -> io::Result<()> {
+        if !self.console.is_interactive() {
+            return Ok(());
+        }
+        let controls = {
+            let mut render = lock_recover(&self.live_render);
+            render.rewrap(self.console.width());
+            let controls = render.position_cursor_controls();
+            render.shape = None;
+            controls
+        };
+        self.console.write_control_codes(controls)
+    }
+
+    /// Redraw with the bottom line reversed, restoring it from a background
+    /// thread after `duration`.
+    pub(crate) fn flash(this: &Arc<Self>, duration: Duration) {
+        this.flashing.store(true, Ordering::SeqCst);
+        let _ = this.refresh_display();
+        let inner = Arc::clone(this);
+        thread::spawn(move || {
+            thread::sleep(duration);
+            inner.flashing.store(false, Ordering::SeqCst);
+            if inner.started.load(Ordering::SeqCst) {
+                let _ = inner.refresh_display();
+            }
+        });
+    }
+
     fn live_render_controls_restore(&self) -> Vec<ControlCode> {
-        lock_recover(&self.live_render).restore_cursor_controls()
+        let mut render = lock_recover(&self.live_render);
+        render.rewrap(self.console.width());
+        render.restore_cursor_controls()
     }
 
     fn render_live_segments(
@@ -507,12 +794,25 @@ impl LiveInner {
             lines.push(ellipsis_segments);
         }
 
-        let mut max_width = 0usize;
-        for line in &lines {
-            let line_width: usize = line.iter().map(Segment::cell_length).sum();
-            max_width = max_width.max(line_width);
+        if self.flashing.load(Ordering::SeqCst)
+            && let Some(last) = lines.last_mut()
+        {
+            let reverse = Style::new().reverse();
+            for segment in last.iter_mut().filter(|segment| !segment.is_control()) {
+                segment.style = Some(match segment.style.take() {
+                    Some(style) => style.combine(&reverse),
+                    None => reverse.clone(),
+                });
+            }
         }
-        render.shape = Some((max_width, lines.len()));
+
+        render.line_widths = lines
+            .iter()
+            .map(|line| line.iter().map(Segment::cell_length).sum())
+            .collect();
+        render.width = options.max_width;
+        let max_width = render.line_widths.iter().copied().max().unwrap_or(0);
+        render.shape = Some((max_width, render.rows()));
 
         let mut flattened = Vec::new();
         let last_index = lines.len().saturating_sub(1);
@@ -550,6 +850,10 @@ impl LiveInner {
     }
 
     fn stop_refresh_thread(&self) {
+        #[cfg(feature = "tokio")]
+        if let Some(task) = lock_recover(&self.refresh_task).take() {
+            task.abort();
+        }
         self.refresh_stop.store(true, Ordering::Relaxed);
         if let Some(handle) = lock_recover(&self.refresh_thread).take() {
             let _ = handle.join();
@@ -564,10 +868,14 @@ impl LiveInner {
             return Ok(());
         }
 
+        if self.suspended.load(Ordering::SeqCst) {
+            return Ok(());
+        }
+
         if (self.console.is_terminal() && !self.console.is_dumb_terminal())
             || !self.options().transient
         {
-            self.console.print_segments(&[]);
+            self.console.refresh_live()?;
         }
         Ok(())
     }
@@ -736,15 +1044,22 @@ impl StdioRedirect {
 
 impl RenderHook for LiveInner {
     fn process(&self, console: &Console, segments: &[Segment<'static>]) -> Vec<Segment<'static>> {
+        if self.suspended.load(Ordering::SeqCst) {
+            return segments.to_vec();
+        }
         let options = console.options();
         let overflow = self.options().vertical_overflow;
 
         let mut render = lock_recover(&self.live_render);
+        render.rewrap(options.max_width);
 
         let mut output = Vec::new();
         if console.is_interactive() {
             if self.alt_screen_active.load(Ordering::SeqCst) {
-                output.push(Segment::control(vec![ControlCode::new(ControlType::Home)]));
+                output.push(Segment::control(vec![
+                    ControlCode::new(ControlType::Clear),
+                    ControlCode::new(ControlType::Home),
+                ]));
             } else {
                 let controls = render.position_cursor_controls();
                 if !controls.is_empty() {
@@ -766,12 +1081,69 @@ impl RenderHook for LiveInner {
     }
 }
 
+/// Keeps the root live display erased while held and redraws it on drop.
+///
+/// Obtained from `Console::suspend_live` around prompts, so the input line
+/// keeps its own row instead of being painted over by the live region.
+pub(crate) struct LiveSuspension {
+    live: Option<Arc<LiveInner>>,
+}
+
+impl LiveSuspension {
+    pub(crate) fn new(live: Option<Arc<LiveInner>>) -> Self {
+        // A nested suspension leaves resuming to the outer one.
+        let live = live.filter(|live| !live.suspended.swap(true, Ordering::SeqCst));
+        if let Some(live) = &live {
+            let _ = live.erase();
+        }
+        Self { live }
+    }
+}
+
+impl Drop for LiveSuspension {
+    fn drop(&mut self) {
+        if let Some(live) = self.live.take() {
+            live.suspended.store(false, Ordering::SeqCst);
+            let _ = live.refresh_display();
+        }
+    }
+}
+
 #[derive(Debug, Default)]
 pub(crate) struct LiveRender {
     shape: Option<(usize, usize)>,
+    /// Cell width of each line of the last frame.
+    line_widths: Vec<usize>,
+    /// Terminal width the last frame was drawn at.
+    width: usize,
 }
 
 impl LiveRender {
+    /// Update the shape for a terminal that is now `width` cells wide.
+    ///
+    /// Lines wider than the terminal wrap onto extra rows, so the last frame
+    /// may now span a different number of rows than when it was drawn.
+    fn rewrap(&mut self, width: usize) {
+        if width == 0 || width == self.width {
+            return;
+        }
+        self.width = width;
+        if let Some((max_width, _)) = self.shape {
+            self.shape = Some((max_width, self.rows()));
+        }
+    }
+
+    /// Terminal rows the last frame occupies at the current width.
+    fn rows(&self) -> usize {
+        if self.width == 0 {
+            return self.line_widths.len();
+        }
+        self.line_widths
+            .iter()
+            .map(|&line_width| line_width.div_ceil(self.width).max(1))
+            .sum()
+    }
+
     fn position_cursor_controls(&self) -> Vec<ControlCode> {
         let Some((_, height)) = self.shape else {
             return Vec::new();
@@ -1051,6 +1423,259 @@ mod tests {
         assert!(text.contains("Hello"), "output missing: {text}");
     }
 
+    #[test]
+    fn test_live_draws_in_quiet_mode() {
+        let buffer = SharedBuffer::new();
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .verbosity(crate::console::Verbosity::Quiet)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+        let options = LiveOptions {
+            auto_refresh: false,
+            transient: false,
+            ..LiveOptions::default()
+        };
+        let live = Live::with_options(console, options).renderable(Text::new("Hello"));
+        live.start(true).expect("start");
+        live.stop().expect("stop");
+
+        let text = buffer.text();
+        assert!(text.contains("Hello"), "output missing: {text}");
+    }
+
+    #[test]
+    fn test_live_notify_resize_redraws_at_new_width() {
+        let buffer = SharedBuffer::new();
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .width(20)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+        let options = LiveOptions {
+            auto_refresh: false,
+            ..LiveOptions::default()
+        };
+        let live = Live::with_options(Arc::clone(&console), options)
+            .renderable(Text::new("abcdefghij".repeat(3)));
+        live.start(true).expect("start");
+        // The 30-cell line wraps onto two rows of the 20-cell terminal.
+        assert_eq!(live.rendered_height(), 2);
+        buffer.clear();
+
+        console.set_size(10, 25);
+        live.notify_resize().expect("notify_resize");
+        let text = buffer.text();
+        // At 10 cells the old frame has re-wrapped onto three rows.
+        assert!(
+            text.starts_with("\r\x1b[2K\x1b[1A\x1b[2K\x1b[1A\x1b[2K"),
+            "{text:?}"
+        );
+        assert!(text.ends_with("abcdefghijabcdefghijabcdefghij"), "{text:?}");
+        assert_eq!(live.rendered_height(), 3);
+        live.stop().expect("stop");
+    }
+
+    #[test]
+    fn test_visual_bell_flashes_live_bottom_line() {
+        let buffer = SharedBuffer::new();
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .visual_bell(true)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+        let options = LiveOptions {
+            auto_refresh: false,
+            ..LiveOptions::default()
+        };
+        let live =
+            Live::with_options(Arc::clone(&console), options).renderable(Text::new("status"));
+        live.start(true).expect("start");
+        buffer.clear();
+
+        console.bell();
+        assert!(buffer.text().contains("\x1b[7mstatus"));
+        thread::sleep(Duration::from_millis(500));
+        let text = buffer.text();
+        assert!(
+            !text.contains('\x07'),
+            "visual bell must not ring: {text:?}"
+        );
+        let reversed = text.find("\x1b[7mstatus").expect("reversed status line");
+        let restored = text.rfind("status").expect("restored status line");
+        assert!(restored > reversed, "display not restored: {text:?}");
+        assert!(!text[..restored].ends_with("\x1b[7m"));
+
+        live.stop().expect("stop");
+    }
+
+    #[test]
+    fn test_live_stop_draws_final_frame_without_flash() {
+        let buffer = SharedBuffer::new();
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .visual_bell(true)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+        let options = LiveOptions {
+            auto_refresh: false,
+            transient: false,
+            ..LiveOptions::default()
+        };
+        let live =
+            Live::with_options(Arc::clone(&console), options).renderable(Text::new("status"));
+        live.start(true).expect("start");
+        console.bell();
+        buffer.clear();
+        live.stop().expect("stop");
+
+        let text = buffer.text();
+        assert!(text.contains("status"), "final frame missing: {text:?}");
+        assert!(!text.contains("\x1b[7m"), "final frame reversed: {text:?}");
+    }
+
+    #[test]
+    fn test_live_manager_regions_do_not_overwrite_each_other() {
+        let buffer = SharedBuffer::new();
+        let console = make_test_console(buffer.clone());
+        let options = LiveOptions {
+            auto_refresh: false,
+            ..LiveOptions::default()
+        };
+        let manager = LiveManager::with_options(console, options);
+        let progress = manager.add_region(Text::new("progress 10%"));
+        let log = manager.add_region(Text::new("log: started\nlog: fetching"));
+        manager.start().expect("start");
+        assert_eq!(progress.rows(), 0..1);
+        assert_eq!(log.rows(), 1..3);
+
+        buffer.clear();
+        progress.update(Text::new("progress 50%"), true);
+        let frame = buffer.text();
+        let bar = frame.find("progress 50%").expect("updated progress");
+        let tail = frame.find("log: fetching").expect("log kept");
+        assert!(bar < tail, "regions out of order: {frame:?}");
+        assert!(!frame.contains("progress 10%"));
+
+        buffer.clear();
+        log.update(Text::new("log: done"), true);
+        let frame = buffer.text();
+        assert!(frame.contains("progress 50%"), "progress lost: {frame:?}");
+        assert!(frame.find("progress 50%") < frame.find("log: done"));
+        assert_eq!(log.rows(), 1..2);
+        assert_eq!(manager.live.rendered_height(), 2);
+
+        manager.stop().expect("stop");
+    }
+
+    #[test]
+    fn test_prompt_suspends_live_region() {
+        /// Records what the terminal showed when input was first read.
+        struct SnapshotReader {
+            buffer: SharedBuffer,
+            seen: Option<String>,
+            input: io::Cursor<&'static [u8]>,
+        }
+
+        impl Read for SnapshotReader {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                self.input.read(buf)
+            }
+        }
+
+        impl io::BufRead for SnapshotReader {
+            fn fill_buf(&mut self) -> io::Result<&[u8]> {
+                if self.seen.is_none() {
+                    self.seen = Some(self.buffer.text());
+                }
+                self.input.fill_buf()
+            }
+
+            fn consume(&mut self, amount: usize) {
+                self.input.consume(amount);
+            }
+        }
+
+        let buffer = SharedBuffer::new();
+        let console = make_test_console(buffer.clone());
+        let options = LiveOptions {
+            auto_refresh: false,
+            ..LiveOptions::default()
+        };
+        let live = Live::with_options(Arc::clone(&console), options)
+            .renderable(Text::new("[=====     ] 50%"));
+        live.start(true).expect("start");
+
+        buffer.clear();
+        let mut reader = SnapshotReader {
+            buffer: buffer.clone(),
+            seen: None,
+            input: io::Cursor::new(b"bob\n"),
+        };
+        let answer = crate::interactive::Prompt::new("Name")
+            .ask_from(&console, &mut reader)
+            .expect("answer");
+        assert_eq!(answer, "bob");
+
+        let seen = reader.seen.expect("input was read");
+        assert!(seen.starts_with("\r\x1b[2K"), "bar not erased: {seen:?}");
+        assert!(seen.ends_with("Name: "), "prompt not last: {seen:?}");
+        assert!(!seen.contains("50%"), "bar drawn over prompt: {seen:?}");
+
+        let after = buffer.text();
+        let restored = &after[seen.len()..];
+        assert!(
+            restored.contains("[=====     ] 50%"),
+            "bar not restored: {restored:?}"
+        );
+        assert_eq!(live.rendered_height(), 1);
+        live.stop().expect("stop");
+    }
+
+    #[test]
+    fn test_live_rendered_height_and_clear() {
+        let buffer = SharedBuffer::new();
+        let console = make_test_console(buffer.clone());
+        let options = LiveOptions {
+            auto_refresh: false,
+            ..LiveOptions::default()
+        };
+        let live = Live::with_options(console, options).renderable(Text::new("one\ntwo\nthree"));
+        assert_eq!(live.rendered_height(), 0);
+
+        live.start(true).expect("start");
+        assert_eq!(live.rendered_height(), 3);
+
+        buffer.clear();
+        live.clear().expect("clear");
+        assert_eq!(live.rendered_height(), 0);
+        assert_eq!(
+            buffer.text(),
+            "\r\x1b[2K\x1b[1A\x1b[2K\x1b[1A\x1b[2K",
+            "region should be erased line by line"
+        );
+
+        // The next refresh redraws in place without moving further up.
+        buffer.clear();
+        live.refresh().expect("refresh");
+        let redraw = buffer.text();
+        assert!(redraw.contains("three"), "redraw missing: {redraw:?}");
+        assert!(
+            !redraw.contains("\x1b[1A"),
+            "unexpected cursor up: {redraw:?}"
+        );
+        assert_eq!(live.rendered_height(), 3);
+        live.stop().expect("stop");
+    }
+
     // =========================================================================
     // Live Start/Stop Lifecycle Tests
     // =========================================================================
@@ -1158,6 +1783,34 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_live_screen_mode_redraws_from_top_left() {
+        let buffer = SharedBuffer::new();
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+        let options = LiveOptions {
+            auto_refresh: false,
+            screen: true,
+            redirect_stdout: false,
+            redirect_stderr: false,
+            ..LiveOptions::default()
+        };
+        let live = Live::with_options(console, options).renderable(Text::new("First"));
+        live.start(true).expect("start");
+        live.update(Text::new("Second"), true);
+        live.stop().expect("stop");
+
+        let text = buffer.text();
+        assert!(text.starts_with("\x1b[?1049h"), "{text:?}");
+        assert!(text.ends_with("\x1b[?1049l"), "{text:?}");
+        assert!(text.contains("\x1b[2J\x1b[HFirst"), "{text:?}");
+        assert!(text.contains("\x1b[2J\x1b[HSecond"), "{text:?}");
+    }
+
     #[test]
     fn test_live_update_without_refresh() {
         let buffer = SharedBuffer::new();
@@ -1430,6 +2083,7 @@ mod tests {
     fn test_live_render_position_cursor_zero_height() {
         let render = LiveRender {
             shape: Some((10, 0)),
+            ..LiveRender::default()
         };
         let controls = render.position_cursor_controls();
         assert!(controls.is_empty());
@@ -1439,6 +2093,7 @@ mod tests {
     fn test_live_render_position_cursor_single_line() {
         let render = LiveRender {
             shape: Some((10, 1)),
+            ..LiveRender::default()
         };
         let controls = render.position_cursor_controls();
         // Should have CarriageReturn and EraseInLine
@@ -1450,6 +2105,7 @@ mod tests {
     fn test_live_render_position_cursor_multiple_lines() {
         let render = LiveRender {
             shape: Some((10, 3)),
+            ..LiveRender::default()
         };
         let controls = render.position_cursor_controls();
         // CR + EraseLine + (CursorUp + EraseLine) * 2
@@ -1468,6 +2124,7 @@ mod tests {
     fn test_live_render_restore_cursor_zero_height() {
         let render = LiveRender {
             shape: Some((10, 0)),
+            ..LiveRender::default()
         };
         let controls = render.restore_cursor_controls();
         assert!(controls.is_empty());
@@ -1477,6 +2134,7 @@ mod tests {
     fn test_live_render_restore_cursor_with_height() {
         let render = LiveRender {
             shape: Some((10, 2)),
+            ..LiveRender::default()
         };
         let controls = render.restore_cursor_controls();
         // CR + (CursorUp + EraseLine) * height
@@ -1604,6 +2262,48 @@ mod tests {
         );
     }
 
+    #[cfg(feature = "tokio")]
+    #[tokio::test(start_paused = true)]
+    async fn test_live_spawn_refresh_updates_until_dropped() {
+        let buffer = SharedBuffer::new();
+        let console = Console::builder()
+            .force_terminal(true)
+            .markup(false)
+            .file(Box::new(buffer.clone()))
+            .build()
+            .shared();
+        let counter = Arc::new(Mutex::new(0));
+        let counter_clone = Arc::clone(&counter);
+
+        let options = LiveOptions {
+            auto_refresh: false,
+            refresh_per_second: 10.0,
+            ..LiveOptions::default()
+        };
+        let live = Live::with_options(console, options).get_renderable(move || {
+            let mut c = counter_clone.lock().unwrap();
+            *c += 1;
+            Box::new(Text::new(format!("Tick {}", *c)))
+        });
+
+        live.start(true).expect("start");
+        let task = live.spawn_refresh(&tokio::runtime::Handle::current());
+        tokio::time::sleep(Duration::from_millis(350)).await;
+
+        let text = buffer.text();
+        assert!(text.contains("Tick 1"));
+        assert!(
+            text.contains("Tick 4"),
+            "expected async refreshes: {text:?}"
+        );
+
+        drop(live);
+        let refreshes = *counter.lock().unwrap();
+        assert!(task.await.is_err_and(|e| e.is_cancelled()));
+        tokio::time::sleep(Duration::from_millis(300)).await;
+        assert_eq!(*counter.lock().unwrap(), refreshes);
+    }
+
     // =========================================================================
     // Edge Cases
     // =========================================================================
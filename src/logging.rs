@@ -8,13 +8,13 @@ use std::sync::{Arc, Mutex};
 use crate::sync::lock_recover;
 
 use log::{Level, LevelFilter, Log, Metadata, Record, SetLoggerError};
-use time::{OffsetDateTime, format_description::OwnedFormatItem};
+use time::format_description::OwnedFormatItem;
 
-use crate::console::Console;
+use crate::console::{Console, local_datetime};
 use crate::markup;
 use crate::renderables::traceback::Traceback;
 use crate::style::Style;
-use crate::text::Text;
+use crate::text::{Text, WordOptions};
 
 #[cfg(not(feature = "backtrace"))]
 use crate::renderables::traceback::TracebackFrame;
@@ -168,8 +168,9 @@ impl RichLogger {
         log::set_boxed_logger(Box::new(self))
     }
 
+    /// Format the current time of the console's [`Clock`](crate::console::Clock).
     fn format_time(&self) -> String {
-        let now = OffsetDateTime::now_local().unwrap_or_else(|_| OffsetDateTime::now_utc());
+        let now = local_datetime(self.console.clock().system_now());
         now.format(&self.time_format)
             .unwrap_or_else(|_| now.to_string())
     }
@@ -219,7 +220,14 @@ impl RichLogger {
 
         if !self.keywords.is_empty() {
             let keywords: Vec<&str> = self.keywords.iter().map(String::as_str).collect();
-            message.highlight_words(&keywords, &self.keyword_style, false);
+            message.highlight_words(
+                &keywords,
+                &self.keyword_style,
+                WordOptions {
+                    case_insensitive: true,
+                    ..WordOptions::default()
+                },
+            );
         }
 
         line.append_text(&message);
@@ -566,6 +574,38 @@ mod tests {
         let _ = logger.format_time();
     }
 
+    #[test]
+    fn test_rich_logger_time_from_console_clock() {
+        use crate::console::Clock;
+        use std::time::{Duration, Instant, SystemTime};
+
+        struct FixedClock(SystemTime);
+        impl Clock for FixedClock {
+            fn now(&self) -> Instant {
+                Instant::now()
+            }
+            fn system_now(&self) -> SystemTime {
+                self.0
+            }
+        }
+
+        let at = SystemTime::UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+        let console = Arc::new(Console::builder().clock(Arc::new(FixedClock(at))).build());
+        let logger = RichLogger::new(console).time_format("[year]-[month]-[day] [second]");
+
+        let expected = local_datetime(at);
+        assert_eq!(
+            logger.format_time(),
+            format!(
+                "{}-{:02}-{:02} 20",
+                expected.year(),
+                u8::from(expected.month()),
+                expected.day()
+            )
+        );
+        assert_eq!(logger.format_time(), logger.format_time());
+    }
+
     #[test]
     fn test_rich_logger_time_format_invalid() {
         let console = Arc::new(Console::builder().force_terminal(true).build());
@@ -443,6 +443,36 @@ mod tests {
         assert_eq!(text.spans().len(), 2);
     }
 
+    #[test]
+    fn test_render_extended_color_tags() {
+        use crate::color::{Color, ColorSystem, ColorType};
+
+        let text = render("[color(196)]x[/]").unwrap();
+        let color = text.spans()[0].style.color.clone().unwrap();
+        assert_eq!(color.color_type, ColorType::EightBit);
+        assert_eq!(color.number, Some(196));
+
+        let text = render("[rgb(10,20,30)]y[/]").unwrap();
+        let color = text.spans()[0].style.color.clone().unwrap();
+        assert_eq!(color.color_type, ColorType::TrueColor);
+        assert_eq!(color.get_truecolor().rgb(), "rgb(10,20,30)");
+        assert_eq!(
+            color.downgrade(ColorSystem::EightBit).color_type,
+            ColorType::EightBit
+        );
+
+        let text = render("[#ff0000 on rgb(0, 0, 255)]z[/]").unwrap();
+        let style = &text.spans()[0].style;
+        assert_eq!(style.color, Some(Color::from_rgb(255, 0, 0)));
+        assert_eq!(style.bgcolor, Some(Color::from_rgb(0, 0, 255)));
+
+        let text = render("[on color(22)]w[/]").unwrap();
+        assert_eq!(
+            text.spans()[0].style.bgcolor.as_ref().unwrap().number,
+            Some(22)
+        );
+    }
+
     #[test]
     fn test_render_uppercase_tag() {
         let text = render("[BOLD]hello[/BOLD]").unwrap();
@@ -506,6 +536,40 @@ mod tests {
         assert!(style.color.is_some());
     }
 
+    #[test]
+    fn test_attribute_tag_vocabulary() {
+        use crate::style::Attributes;
+
+        let cases = [
+            ("[blink]x[/]", Attributes::BLINK),
+            ("[reverse]x[/]", Attributes::REVERSE),
+            ("[conceal]x[/]", Attributes::CONCEAL),
+            ("[strike]x[/]", Attributes::STRIKE),
+            ("[s]x[/s]", Attributes::STRIKE),
+            ("[overline]x[/overline]", Attributes::OVERLINE),
+        ];
+        for (markup, attribute) in cases {
+            let text = render(markup).unwrap();
+            assert_eq!(text.plain(), "x", "{markup}");
+            assert_eq!(text.spans().len(), 1, "{markup}");
+            assert!(
+                text.spans()[0].style.attributes.contains(attribute),
+                "{markup}"
+            );
+        }
+
+        // Closing unwinds only the matching attribute.
+        let text = render("[reverse]a[strike]b[/strike]c[/]d").unwrap();
+        assert_eq!(text.plain(), "abcd");
+        let spans: Vec<(usize, usize, Attributes)> = text
+            .spans()
+            .iter()
+            .map(|span| (span.start, span.end, span.style.attributes))
+            .collect();
+        assert!(spans.contains(&(0, 3, Attributes::REVERSE)), "{spans:?}");
+        assert!(spans.contains(&(1, 2, Attributes::STRIKE)), "{spans:?}");
+    }
+
     #[test]
     fn test_style_on_background() {
         let text = render("[red on blue]text[/]").unwrap();
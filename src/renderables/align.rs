@@ -18,7 +18,7 @@
 use crate::cells::cell_len;
 use crate::console::{Console, ConsoleOptions};
 use crate::renderables::Renderable;
-use crate::segment::Segment;
+use crate::segment::{Segment, pop_trailing_empty_line, split_lines};
 use crate::style::Style;
 
 /// Horizontal alignment method.
@@ -31,6 +31,10 @@ pub enum AlignMethod {
     Center,
     /// Align content to the right.
     Right,
+    /// Stretch inter-word gaps so every line but a paragraph's last fills the
+    /// width. Only [`align_text`] justifies; single-line alignment treats
+    /// this as [`AlignMethod::Left`].
+    Justify,
 }
 
 /// Vertical alignment method for multi-line content.
@@ -76,6 +80,33 @@ impl<'a> Align<'a> {
         Self::new(vec![Segment::new(text, None)], width)
     }
 
+    /// Render any renderable and align each of its lines within `width`.
+    ///
+    /// The child is rendered at `width`, split into visual lines, and each
+    /// line is padded according to `method` (measured with [`cell_len`]).
+    /// Useful for centering a small table or panel within a region.
+    #[must_use]
+    pub fn renderable<R: Renderable + ?Sized>(
+        renderable: &'a R,
+        console: &Console,
+        method: AlignMethod,
+        width: usize,
+    ) -> Vec<Segment<'a>> {
+        let options = console.options().update_width(width);
+        let mut lines = split_lines(renderable.render(console, &options).into_iter());
+        let trailing_newline = pop_trailing_empty_line(&mut lines);
+
+        let count = lines.len();
+        let mut result = Vec::new();
+        for (i, line) in lines.into_iter().enumerate() {
+            result.extend(Align::new(line, width).method(method).render());
+            if i + 1 < count || trailing_newline {
+                result.push(Segment::line());
+            }
+        }
+        result
+    }
+
     /// Set the alignment method.
     #[must_use]
     pub fn method(mut self, method: AlignMethod) -> Self {
@@ -128,7 +159,7 @@ impl<'a> Align<'a> {
         let mut result = Vec::with_capacity(self.content.len() + 2);
 
         match self.method {
-            AlignMethod::Left => {
+            AlignMethod::Left | AlignMethod::Justify => {
                 // Content first, then right padding
                 result.extend(self.content);
                 if padding_total > 0 {
@@ -274,8 +305,31 @@ impl Renderable for AlignLines<'_> {
 }
 
 /// Convenience function to align a single line of text.
+///
+/// With [`AlignMethod::Justify`], `text` may span several lines: each line is
+/// justified except the last line of every paragraph (paragraphs are separated
+/// by blank lines), which is left-aligned along with single-word lines.
 #[must_use]
 pub fn align_text(text: &str, width: usize, method: AlignMethod) -> String {
+    if method == AlignMethod::Justify {
+        let lines: Vec<&str> = text.split('\n').collect();
+        return lines
+            .iter()
+            .enumerate()
+            .map(|(index, line)| {
+                let ends_paragraph = lines
+                    .get(index + 1)
+                    .is_none_or(|next| next.trim().is_empty());
+                if ends_paragraph {
+                    align_text(line, width, AlignMethod::Left)
+                } else {
+                    justify_line(line, width)
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+
     let content_width = cell_len(text);
 
     if content_width >= width {
@@ -285,7 +339,7 @@ pub fn align_text(text: &str, width: usize, method: AlignMethod) -> String {
     let padding_total = width - content_width;
 
     match method {
-        AlignMethod::Left => {
+        AlignMethod::Left | AlignMethod::Justify => {
             format!("{text}{}", " ".repeat(padding_total))
         }
         AlignMethod::Center => {
@@ -299,6 +353,37 @@ pub fn align_text(text: &str, width: usize, method: AlignMethod) -> String {
     }
 }
 
+/// Expand the gaps between words so `line` fills `width` cells.
+///
+/// As in Python Rich's full justification, the extra spaces go to the
+/// rightmost gaps first.
+fn justify_line(line: &str, width: usize) -> String {
+    let words: Vec<&str> = line.split_whitespace().collect();
+    let words_width: usize = words.iter().map(|word| cell_len(word)).sum();
+    let gaps = words.len().saturating_sub(1);
+    if gaps == 0 || words_width + gaps >= width {
+        return align_text(line, width, AlignMethod::Left);
+    }
+
+    let mut spaces = vec![1usize; gaps];
+    let mut extra = width - words_width - gaps;
+    let mut index = gaps;
+    while extra > 0 {
+        index = if index == 0 { gaps - 1 } else { index - 1 };
+        spaces[index] += 1;
+        extra -= 1;
+    }
+
+    let mut justified = String::with_capacity(width);
+    for (i, word) in words.iter().enumerate() {
+        justified.push_str(word);
+        if let Some(&count) = spaces.get(i) {
+            justified.push_str(&" ".repeat(count));
+        }
+    }
+    justified
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -416,6 +501,30 @@ mod tests {
         assert_eq!(align_text("Hi", 10, AlignMethod::Right), "        Hi");
     }
 
+    #[test]
+    fn test_align_text_justify_paragraph() {
+        let text = "The quick brown fox\njumps over the\nlazy dog";
+        let justified = align_text(text, 20, AlignMethod::Justify);
+        let lines: Vec<&str> = justified.lines().collect();
+        assert_eq!(lines.len(), 3);
+        assert_eq!(lines[0], "The quick brown  fox");
+        assert_eq!(lines[1], "jumps    over    the");
+        assert_eq!(cell_len(lines[0]), 20);
+        assert_eq!(cell_len(lines[1]), 20);
+        // The last line of the paragraph stays left-aligned.
+        assert_eq!(lines[2], "lazy dog            ");
+    }
+
+    #[test]
+    fn test_align_text_justify_single_word_and_paragraphs() {
+        let text = "Heading\nbody text\n\nnext para";
+        let justified = align_text(text, 12, AlignMethod::Justify);
+        let lines: Vec<&str> = justified.split('\n').collect();
+        assert_eq!(lines[0], "Heading     ");
+        assert_eq!(lines[1], "body text   ");
+        assert_eq!(lines[3], "next para   ");
+    }
+
     #[test]
     fn test_align_with_cjk() {
         // CJK characters are 2 cells wide
@@ -429,6 +538,26 @@ mod tests {
         assert!(text.ends_with("   "));
     }
 
+    #[test]
+    fn test_align_renderable_centers_table() {
+        use crate::renderables::table::{Column, Table};
+
+        let mut table = Table::new().with_column(Column::new("abcdef"));
+        table.add_row_cells(["xyz"]);
+        let console = Console::builder().width(80).build();
+
+        let segments = Align::renderable(&table, &console, AlignMethod::Center, 30);
+        let output: String = segments.iter().map(|s| s.text.as_ref()).collect();
+        let lines: Vec<&str> = output.lines().collect();
+        assert!(lines.len() >= 3);
+        for line in lines {
+            assert_eq!(cell_len(line), 30);
+            let leading = line.len() - line.trim_start().len();
+            assert_eq!(leading, 10, "line {line:?}");
+        }
+        assert!(output.ends_with('\n'));
+    }
+
     #[test]
     fn test_content_width() {
         let content = vec![
@@ -28,6 +28,20 @@ use crate::style::Style;
 
 use super::align::{Align, AlignMethod};
 
+/// Order in which [`Columns`] places items into its grid.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Flow {
+    /// Fill each row left to right, rows top to bottom.
+    #[default]
+    RowMajor,
+    /// Fill each column top to bottom, columns left to right.
+    ColumnMajor,
+    /// Fill each row right to left, rows top to bottom.
+    RightToLeft,
+    /// Fill each column bottom to top, columns left to right.
+    BottomToTop,
+}
+
 /// A renderable that arranges items in columns.
 #[derive(Debug, Clone)]
 pub struct Columns<'a> {
@@ -50,6 +64,8 @@ pub struct Columns<'a> {
     /// Maximum total width for the columns layout.
     /// When set, prevents columns from spreading across very wide terminals.
     max_width: Option<usize>,
+    /// Order in which items fill the grid.
+    flow: Flow,
 }
 
 impl Default for Columns<'_> {
@@ -64,6 +80,7 @@ impl Default for Columns<'_> {
             padding: 0,
             gutter_style: Style::new(),
             max_width: None,
+            flow: Flow::RowMajor,
         }
     }
 }
@@ -154,6 +171,38 @@ impl<'a> Columns<'a> {
         self
     }
 
+    /// Set the order in which items fill the grid.
+    ///
+    /// ```rust,ignore
+    /// // 1 3 5
+    /// // 2 4 6
+    /// let cols = Columns::from_strings(&["1", "2", "3", "4", "5", "6"])
+    ///     .column_count(3)
+    ///     .flow(Flow::ColumnMajor);
+    /// ```
+    #[must_use]
+    pub fn flow(mut self, flow: Flow) -> Self {
+        self.flow = flow;
+        self
+    }
+
+    /// Index of the item placed at `(row, col)` in a grid of the given size.
+    fn item_at(
+        &self,
+        row: usize,
+        col: usize,
+        num_rows: usize,
+        num_columns: usize,
+    ) -> Option<usize> {
+        let index = match self.flow {
+            Flow::RowMajor => row * num_columns + col,
+            Flow::RightToLeft => row * num_columns + (num_columns - 1 - col),
+            Flow::ColumnMajor => col * num_rows + row,
+            Flow::BottomToTop => col * num_rows + (num_rows - 1 - row),
+        };
+        (index < self.items.len()).then_some(index)
+    }
+
     /// Get the width of an item in cells.
     fn item_width(item: &[Segment<'_>]) -> usize {
         item.iter().map(|s| cell_len(&s.text)).sum()
@@ -176,11 +225,15 @@ impl<'a> Columns<'a> {
         } else {
             // Calculate max width for each column based on content
             let mut max_widths = vec![0usize; num_columns];
+            let num_rows = self.items.len().div_ceil(num_columns);
 
-            for (idx, item) in self.items.iter().enumerate() {
-                let col = idx % num_columns;
-                let item_w = Self::item_width(item) + self.padding * 2;
-                max_widths[col] = max_widths[col].max(item_w);
+            for row in 0..num_rows {
+                for (col, max_width) in max_widths.iter_mut().enumerate() {
+                    if let Some(idx) = self.item_at(row, col, num_rows, num_columns) {
+                        let item_w = Self::item_width(&self.items[idx]) + self.padding * 2;
+                        *max_width = (*max_width).max(item_w);
+                    }
+                }
             }
 
             if self.expand {
@@ -320,7 +373,7 @@ impl<'a> Columns<'a> {
                 reason = "col_idx used for multiple purposes"
             )]
             for col_idx in 0..num_columns {
-                let item_idx = row_idx * num_columns + col_idx;
+                let item_idx = self.item_at(row_idx, col_idx, num_rows, num_columns);
                 let column_width = column_widths[col_idx];
 
                 // Add gutter before columns (except first)
@@ -331,7 +384,7 @@ impl<'a> Columns<'a> {
                     ));
                 }
 
-                if item_idx < self.items.len() {
+                if let Some(item_idx) = item_idx {
                     // Add padding, content, padding
                     let effective_padding = self.padding.min(column_width / 2);
                     if effective_padding > 0 {
@@ -448,6 +501,26 @@ mod tests {
         assert_eq!(lines.len(), 2);
     }
 
+    #[test]
+    fn test_columns_flow_places_items() {
+        let render = |flow: Flow| -> Vec<String> {
+            Columns::from_strings(&["1", "2", "3", "4", "5", "6"])
+                .column_count(2)
+                .gutter(1)
+                .expand(false)
+                .flow(flow)
+                .render(20)
+                .iter()
+                .map(|line| line.iter().map(|s| s.text.as_ref()).collect())
+                .collect()
+        };
+
+        assert_eq!(render(Flow::RowMajor), vec!["1 2", "3 4", "5 6"]);
+        assert_eq!(render(Flow::ColumnMajor), vec!["1 4", "2 5", "3 6"]);
+        assert_eq!(render(Flow::RightToLeft), vec!["2 1", "4 3", "6 5"]);
+        assert_eq!(render(Flow::BottomToTop), vec!["3 6", "2 5", "1 4"]);
+    }
+
     #[test]
     fn test_columns_render_three_columns() {
         let cols = Columns::from_strings(&["A", "B", "C"])
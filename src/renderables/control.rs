@@ -125,12 +125,29 @@ impl Control {
         }
     }
 
+    /// Save the cursor position so [`Control::restore_cursor`] can return to it.
+    #[must_use]
+    pub fn save_cursor() -> Self {
+        Self::new(vec![ControlCode::new(ControlType::SaveCursor)])
+    }
+
+    /// Return the cursor to the position saved by [`Control::save_cursor`].
+    #[must_use]
+    pub fn restore_cursor() -> Self {
+        Self::new(vec![ControlCode::new(ControlType::RestoreCursor)])
+    }
+
     #[must_use]
     pub fn title(title: impl Into<String>) -> Self {
         let mut control = Self::new(vec![ControlCode::new(ControlType::SetWindowTitle)]);
         control.title = Some(title.into());
         control
     }
+
+    /// Consume the renderable, returning its control codes.
+    pub(crate) fn into_codes(self) -> Vec<ControlCode> {
+        self.codes
+    }
 }
 
 impl Renderable for Control {
@@ -28,9 +28,20 @@
 //!
 //! By default, each renderable is rendered on its own lines. Use `fit(true)`
 //! to attempt to render items inline when they fit.
+//!
+//! # Indent Guides
+//!
+//! `indent_guides(true)` draws a dim `│` in the left margin of every line.
+//! Nested groups with guides add one more column per level:
+//!
+//! ```text
+//! │ outer
+//! │ │ inner
+//! ```
 
 use crate::console::{Console, ConsoleOptions};
-use crate::segment::Segment;
+use crate::segment::{Segment, pop_trailing_empty_line, split_lines};
+use crate::style::Style;
 
 use super::Renderable;
 
@@ -45,8 +56,16 @@ pub struct Group<'a> {
     children: Vec<Box<dyn Renderable + 'a>>,
     /// Whether to fit items inline when possible.
     fit: bool,
+    /// Whether to draw a guide column in the left margin.
+    indent_guides: bool,
 }
 
+/// Margin drawn on each line of a group with indent guides.
+const INDENT_GUIDE: &str = "│ ";
+
+/// [`INDENT_GUIDE`] for consoles without Unicode box drawing.
+const ASCII_INDENT_GUIDE: &str = "| ";
+
 impl<'a> Group<'a> {
     /// Create a new empty group.
     #[must_use]
@@ -81,6 +100,16 @@ impl<'a> Group<'a> {
         self
     }
 
+    /// Set whether to draw an indent guide (`│ `) before every line.
+    ///
+    /// Children are rendered two cells narrower to make room for the guide,
+    /// which falls back to `| ` on consoles without Unicode box drawing.
+    #[must_use]
+    pub fn indent_guides(mut self, indent_guides: bool) -> Self {
+        self.indent_guides = indent_guides;
+        self
+    }
+
     /// Check if the group is empty.
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -96,6 +125,10 @@ impl<'a> Group<'a> {
 
 impl Renderable for Group<'_> {
     fn render(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'_>> {
+        if self.indent_guides {
+            return self.render_with_guides(console, options);
+        }
+
         let mut segments = Vec::new();
 
         for (i, child) in self.children.iter().enumerate() {
@@ -113,6 +146,46 @@ impl Renderable for Group<'_> {
     }
 }
 
+impl Group<'_> {
+    /// Render the children in a narrower area and prefix each line with a guide.
+    fn render_with_guides(&self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'_>> {
+        let guide_width = crate::cells::cell_len(INDENT_GUIDE);
+        let inner_options = options.update_width(options.max_width.saturating_sub(guide_width));
+
+        let mut content = Vec::new();
+        for (i, child) in self.children.iter().enumerate() {
+            if i > 0 && !self.fit {
+                content.push(Segment::line());
+            }
+            content.extend(
+                child
+                    .render(console, &inner_options)
+                    .into_iter()
+                    .map(Segment::into_owned),
+            );
+        }
+
+        let glyph = if console.safe_box() || !console.supports_unicode() {
+            ASCII_INDENT_GUIDE
+        } else {
+            INDENT_GUIDE
+        };
+        let guide = Segment::new(glyph, Some(Style::new().dim()));
+        let mut lines = split_lines(content.into_iter());
+        pop_trailing_empty_line(&mut lines);
+
+        let mut segments = Vec::new();
+        for (i, line) in lines.into_iter().enumerate() {
+            if i > 0 {
+                segments.push(Segment::line());
+            }
+            segments.push(guide.clone());
+            segments.extend(line);
+        }
+        segments
+    }
+}
+
 /// Create a group from an iterator of renderables.
 ///
 /// This is a convenience function for creating groups from iterators.
@@ -762,4 +835,42 @@ mod tests {
         assert!(output.contains("Before fit"));
         assert!(output.contains("After fit"));
     }
+
+    #[test]
+    fn test_group_indent_guides_nested() {
+        let inner = Group::new()
+            .indent_guides(true)
+            .push("Inner 1")
+            .push("Inner 2");
+        let outer = Group::new().indent_guides(true).push("Outer").push(inner);
+
+        let console = Console::builder()
+            .force_terminal(false)
+            .markup(false)
+            .width(20)
+            .build();
+        let options = console.options();
+
+        let segments = outer.render(&console, &options);
+        let output: String = segments.iter().map(|s| s.text.as_ref()).collect();
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+
+        assert_eq!(lines, vec!["│ Outer", "│ │ Inner 1", "│ │ Inner 2"]);
+        assert!(segments.iter().filter(|s| s.text == "│ ").all(|s| {
+            s.style
+                .as_ref()
+                .is_some_and(|st| st.attributes.contains(crate::style::Attributes::DIM))
+        }));
+
+        let ascii = Console::builder()
+            .force_terminal(false)
+            .markup(false)
+            .width(20)
+            .encoding("ascii")
+            .build();
+        let segments = outer.render(&ascii, &ascii.options());
+        let output: String = segments.iter().map(|s| s.text.as_ref()).collect();
+        let lines: Vec<&str> = output.lines().map(str::trim_end).collect();
+        assert_eq!(lines, vec!["| Outer", "| | Inner 1", "| | Inner 2"]);
+    }
 }
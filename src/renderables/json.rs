@@ -111,8 +111,9 @@
 //! # Known Limitations
 //!
 //! - **Large JSON**: Very large JSON documents may be slow to render due to
-//!   per-token segment creation
-//! - **Streaming**: Does not support streaming JSON parsing; the entire document
+//!   per-token segment creation; use [`Json::render_streaming`] to write tokens
+//!   to a sink without collecting them
+//! - **Streaming**: Does not support streaming JSON parsing; the parsed document
 //!   must fit in memory
 //! - **Trailing commas**: Standard JSON only; no trailing comma support
 //! - **Python Rich JSON option parity**: `rich_rust` matches Python Rich JSON formatting
@@ -121,12 +122,13 @@
 //!   exist in Python's `json.dumps` API but don't map cleanly to Rust's `serde_json`
 //!   value model.
 
+use std::collections::VecDeque;
 use std::fmt::Write as _;
 
 use serde::Serialize;
 use serde_json::Value;
 
-use crate::segment::Segment;
+use crate::segment::{Segment, segments_to_plain};
 use crate::style::Style;
 
 /// Default theme colors for JSON syntax highlighting.
@@ -240,6 +242,12 @@ pub struct Json {
     highlight: bool,
     /// Theme for syntax highlighting.
     theme: JsonTheme,
+    /// Previous document to compare against (set by [`Json::diff`]).
+    diff_base: Option<Value>,
+    /// Emit identifier-like keys without quotes (JSON5-style).
+    unquoted_keys: bool,
+    /// Delimit strings with `'` instead of `"` (JSON5-style).
+    single_quotes: bool,
 }
 
 impl Json {
@@ -253,9 +261,35 @@ impl Json {
             ensure_ascii: false,
             highlight: true,
             theme: JsonTheme::default(),
+            diff_base: None,
+            unquoted_keys: false,
+            single_quotes: false,
         }
     }
 
+    /// Render `new` as a structural diff against `old`.
+    ///
+    /// Keys and items only present in `new` are shown in green, entries only
+    /// present in `old` are shown in red with a `-` prefix, and changed scalar
+    /// values are bold yellow. Unchanged parts keep the regular theme.
+    ///
+    /// ```
+    /// use rich_rust::renderables::json::Json;
+    /// use serde_json::json;
+    ///
+    /// let diff = Json::diff(&json!({"a": 1, "c": 0}), &json!({"a": 2, "b": 3}));
+    /// assert_eq!(
+    ///     diff.to_plain_string(),
+    ///     "{\n  \"a\": 2,\n  \"b\": 3,\n- \"c\": 0\n}"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn diff(old: &Value, new: &Value) -> Self {
+        let mut json = Self::new(new.clone());
+        json.diff_base = Some(old.clone());
+        json
+    }
+
     /// Create a new Json renderable from a `serde_json::Value` with explicit options.
     #[must_use]
     pub fn with_options(value: Value, options: JsonOptions) -> Self {
@@ -266,6 +300,9 @@ impl Json {
             ensure_ascii: options.ensure_ascii,
             highlight: options.highlight,
             theme: JsonTheme::default(),
+            diff_base: None,
+            unquoted_keys: false,
+            single_quotes: false,
         }
     }
 
@@ -338,6 +375,25 @@ impl Json {
         self
     }
 
+    /// Set whether object keys that are valid identifiers are left unquoted.
+    ///
+    /// Together with [`Json::single_quotes`] this gives a JSON5-like view for
+    /// human-facing previews; the output is no longer strict JSON.
+    #[must_use]
+    pub fn unquoted_keys(mut self, unquoted: bool) -> Self {
+        self.unquoted_keys = unquoted;
+        self
+    }
+
+    /// Set whether strings and quoted keys use `'` instead of `"`.
+    ///
+    /// Escaping follows the delimiter: `'` is escaped and `"` is left as is.
+    #[must_use]
+    pub fn single_quotes(mut self, single: bool) -> Self {
+        self.single_quotes = single;
+        self
+    }
+
     /// Set whether to apply syntax highlighting.
     #[must_use]
     pub fn highlight(mut self, highlight: bool) -> Self {
@@ -361,6 +417,22 @@ impl Json {
         }
     }
 
+    /// Quote and escape a string value with the configured delimiter.
+    fn quote(&self, s: &str) -> String {
+        let quote = if self.single_quotes { '\'' } else { '"' };
+        let escaped = escape_json_string(s, self.ensure_ascii, quote);
+        format!("{quote}{escaped}{quote}")
+    }
+
+    /// Format an object key, leaving identifiers bare when enabled.
+    fn key(&self, key: &str) -> String {
+        if self.unquoted_keys && is_identifier(key) {
+            key.to_string()
+        } else {
+            self.quote(key)
+        }
+    }
+
     fn is_compact(&self) -> bool {
         matches!(self.indent, JsonIndent::None)
     }
@@ -373,175 +445,486 @@ impl Json {
         }
     }
 
-    /// Render a JSON value at the given depth.
-    fn render_value(&self, value: &Value, depth: usize, tab_size: usize) -> Vec<Segment<'_>> {
+    /// Render the JSON to segments, using the given tab size for indentation expansion.
+    #[must_use]
+    pub fn render_with_tab_size(&self, tab_size: usize) -> Vec<Segment<'_>> {
+        self.render_streaming_with_tab_size(tab_size).collect()
+    }
+
+    /// Render the JSON to segments using the default tab size (8).
+    #[must_use]
+    pub fn render(&self) -> Vec<Segment<'_>> {
+        self.render_with_tab_size(8)
+    }
+
+    /// Lazily render the JSON as a stream of segments using the default tab size (8).
+    ///
+    /// The value tree is walked with an explicit stack, so only the current path
+    /// (plus a handful of pending tokens) is held in memory. Callers can write
+    /// each segment to a sink as it is produced. The token sequence is identical
+    /// to [`Json::render`].
+    ///
+    /// ```
+    /// use rich_rust::renderables::json::Json;
+    ///
+    /// let json = Json::from_str(r#"{"a": [1, 2]}"#).unwrap();
+    /// let plain: String = json.render_streaming().map(|s| s.text.into_owned()).collect();
+    /// assert_eq!(plain, json.to_plain_string());
+    /// ```
+    pub fn render_streaming(&self) -> impl Iterator<Item = Segment<'_>> {
+        self.render_streaming_with_tab_size(8)
+    }
+
+    /// Lazily render the JSON as a stream of segments with an explicit tab size.
+    pub fn render_streaming_with_tab_size(
+        &self,
+        tab_size: usize,
+    ) -> impl Iterator<Item = Segment<'_>> {
+        if let Some(old) = &self.diff_base {
+            let mut writer = DiffWriter {
+                json: self,
+                tab_size,
+                out: VecDeque::new(),
+            };
+            writer.diff(old, &self.value, 0);
+            return JsonStream {
+                json: self,
+                tab_size,
+                stack: Vec::new(),
+                pending: writer.out,
+            };
+        }
+        JsonStream {
+            json: self,
+            tab_size,
+            stack: vec![Frame::Value {
+                value: &self.value,
+                depth: 0,
+            }],
+            pending: VecDeque::new(),
+        }
+    }
+
+    /// Render to a plain string without ANSI codes.
+    #[must_use]
+    pub fn to_plain_string(&self) -> String {
+        segments_to_plain(&self.render())
+    }
+}
+
+/// Values nested deeper than this render as `[...]` / `{...}`.
+const MAX_DEPTH: usize = 20;
+
+/// A unit of pending work in [`JsonStream`].
+enum Frame<'a> {
+    /// A value that hasn't been started yet.
+    Value { value: &'a Value, depth: usize },
+    /// An open array; `next` is the index of the next item to emit.
+    Array {
+        items: &'a [Value],
+        next: usize,
+        depth: usize,
+    },
+    /// An open object; `next` is the index of the next entry to emit.
+    Object {
+        entries: Vec<(&'a String, &'a Value)>,
+        next: usize,
+        depth: usize,
+    },
+}
+
+/// Iterator produced by [`Json::render_streaming`].
+struct JsonStream<'a> {
+    json: &'a Json,
+    tab_size: usize,
+    stack: Vec<Frame<'a>>,
+    pending: VecDeque<Segment<'a>>,
+}
+
+impl<'a> JsonStream<'a> {
+    fn emit(&mut self, text: impl Into<std::borrow::Cow<'a, str>>, style: Option<Style>) {
+        self.pending.push_back(Segment::new(text, style));
+    }
+
+    fn bracket(&self) -> Option<Style> {
+        self.json.style(&self.json.theme.bracket)
+    }
+
+    fn punctuation(&self) -> Option<Style> {
+        self.json.style(&self.json.theme.punctuation)
+    }
+
+    fn start_value(&mut self, value: &'a Value, depth: usize) {
+        let json = self.json;
         match value {
-            Value::Null => vec![Segment::new("null", self.style(&self.theme.null))],
+            Value::Null => self.emit("null", json.style(&json.theme.null)),
             Value::Bool(b) => {
-                let text = if *b { "true" } else { "false" };
-                let style = if *b {
-                    self.style(&self.theme.bool_true)
+                let (text, style) = if *b {
+                    ("true", &json.theme.bool_true)
                 } else {
-                    self.style(&self.theme.bool_false)
+                    ("false", &json.theme.bool_false)
                 };
-                vec![Segment::new(text, style)]
-            }
-            Value::Number(n) => {
-                vec![Segment::new(n.to_string(), self.style(&self.theme.number))]
+                self.emit(text, json.style(style));
             }
+            Value::Number(n) => self.emit(n.to_string(), json.style(&json.theme.number)),
             Value::String(s) => {
-                // Escape and quote the string
-                let escaped = escape_json_string(s, self.ensure_ascii);
-                vec![Segment::new(
-                    format!("\"{escaped}\""),
-                    self.style(&self.theme.string),
-                )]
+                self.emit(json.quote(s), json.style(&json.theme.string));
+            }
+            Value::Array(items) => {
+                if depth > MAX_DEPTH {
+                    self.emit("[...]", self.bracket());
+                } else if items.is_empty() {
+                    self.emit("[]", self.bracket());
+                } else {
+                    self.emit("[", self.bracket());
+                    if !json.is_compact() {
+                        self.emit("\n", None);
+                    }
+                    self.stack.push(Frame::Array {
+                        items,
+                        next: 0,
+                        depth,
+                    });
+                }
+            }
+            Value::Object(obj) => {
+                if depth > MAX_DEPTH {
+                    self.emit("{...}", self.bracket());
+                } else if obj.is_empty() {
+                    self.emit("{}", self.bracket());
+                } else {
+                    let mut entries: Vec<(&String, &Value)> = obj.iter().collect();
+                    if json.sort_keys {
+                        entries.sort_by(|a, b| a.0.cmp(b.0));
+                    }
+                    self.emit("{", self.bracket());
+                    if !json.is_compact() {
+                        self.emit("\n", None);
+                    }
+                    self.stack.push(Frame::Object {
+                        entries,
+                        next: 0,
+                        depth,
+                    });
+                }
             }
-            Value::Array(arr) => self.render_array(arr, depth, tab_size),
-            Value::Object(obj) => self.render_object(obj, depth, tab_size),
         }
     }
 
-    /// Render an array.
-    fn render_array(&self, arr: &[Value], depth: usize, tab_size: usize) -> Vec<Segment<'_>> {
-        const MAX_DEPTH: usize = 20;
-        if depth > MAX_DEPTH {
-            return vec![Segment::new("[...]", self.style(&self.theme.bracket))];
-        }
-
-        if arr.is_empty() {
-            return vec![Segment::new("[]", self.style(&self.theme.bracket))];
+    /// Emit the separator before item `next` (or the closing bracket after the last item).
+    /// Returns `true` if another item follows.
+    fn continue_container(
+        &mut self,
+        next: usize,
+        len: usize,
+        depth: usize,
+        close: &'a str,
+    ) -> bool {
+        let compact = self.json.is_compact();
+        if next < len {
+            if next > 0 {
+                if compact {
+                    self.emit(", ", self.punctuation());
+                } else {
+                    self.emit(",", self.punctuation());
+                    self.emit("\n", None);
+                }
+            }
+            if !compact {
+                self.emit(self.json.indent_prefix(depth + 1, self.tab_size), None);
+            }
+            true
+        } else {
+            if !compact {
+                self.emit("\n", None);
+                self.emit(self.json.indent_prefix(depth, self.tab_size), None);
+            }
+            self.emit(close, self.bracket());
+            false
         }
+    }
 
-        let mut segments = Vec::new();
-
-        // Opening bracket
-        segments.push(Segment::new("[", self.style(&self.theme.bracket)));
-        if self.is_compact() {
-            for (i, item) in arr.iter().enumerate() {
-                segments.extend(self.render_value(item, depth + 1, tab_size));
-                if i < arr.len() - 1 {
-                    segments.push(Segment::new(", ", self.style(&self.theme.punctuation)));
+    fn step(&mut self) -> bool {
+        let Some(frame) = self.stack.pop() else {
+            return false;
+        };
+        match frame {
+            Frame::Value { value, depth } => self.start_value(value, depth),
+            Frame::Array { items, next, depth } => {
+                if self.continue_container(next, items.len(), depth, "]") {
+                    self.stack.push(Frame::Array {
+                        items,
+                        next: next + 1,
+                        depth,
+                    });
+                    self.stack.push(Frame::Value {
+                        value: &items[next],
+                        depth: depth + 1,
+                    });
                 }
             }
-            segments.push(Segment::new("]", self.style(&self.theme.bracket)));
-        } else {
-            let indent_str = self.indent_prefix(depth + 1, tab_size);
-            let close_indent = self.indent_prefix(depth, tab_size);
-
-            segments.push(Segment::new("\n", None));
-            for (i, item) in arr.iter().enumerate() {
-                segments.push(Segment::new(indent_str.clone(), None));
-                segments.extend(self.render_value(item, depth + 1, tab_size));
-                if i < arr.len() - 1 {
-                    segments.push(Segment::new(",", self.style(&self.theme.punctuation)));
+            Frame::Object {
+                entries,
+                next,
+                depth,
+            } => {
+                if self.continue_container(next, entries.len(), depth, "}") {
+                    let (key, value) = entries[next];
+                    self.emit(self.json.key(key), self.json.style(&self.json.theme.key));
+                    self.emit(": ", self.punctuation());
+                    self.stack.push(Frame::Object {
+                        entries,
+                        next: next + 1,
+                        depth,
+                    });
+                    self.stack.push(Frame::Value {
+                        value,
+                        depth: depth + 1,
+                    });
                 }
-                segments.push(Segment::new("\n", None));
             }
-            segments.push(Segment::new(close_indent, None));
-            segments.push(Segment::new("]", self.style(&self.theme.bracket)));
         }
-
-        segments
+        true
     }
+}
 
-    /// Render an object.
-    fn render_object(
-        &self,
-        obj: &serde_json::Map<String, Value>,
-        depth: usize,
-        tab_size: usize,
-    ) -> Vec<Segment<'_>> {
-        const MAX_DEPTH: usize = 20;
-        if depth > MAX_DEPTH {
-            return vec![Segment::new("{...}", self.style(&self.theme.bracket))];
-        }
+impl<'a> Iterator for JsonStream<'a> {
+    type Item = Segment<'a>;
 
-        if obj.is_empty() {
-            return vec![Segment::new("{}", self.style(&self.theme.bracket))];
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(segment) = self.pending.pop_front() {
+                return Some(segment);
+            }
+            if !self.step() {
+                return None;
+            }
         }
+    }
+}
 
-        let mut segments = Vec::new();
+/// How a node rendered by [`Json::diff`] relates to the old document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiffMark {
+    Unchanged,
+    Added,
+    Removed,
+    Changed,
+}
 
-        // Get keys, optionally sorted
-        let keys: Vec<&String> = if self.sort_keys {
-            let mut k: Vec<_> = obj.keys().collect();
-            k.sort();
-            k
-        } else {
-            obj.keys().collect()
+/// One entry of a container being diffed.
+enum DiffEntry<'a> {
+    Both(&'a Value, &'a Value),
+    Added(&'a Value),
+    Removed(&'a Value),
+}
+
+/// Eagerly renders a [`Json::diff`] document.
+struct DiffWriter<'a> {
+    json: &'a Json,
+    tab_size: usize,
+    out: VecDeque<Segment<'a>>,
+}
+
+impl<'a> DiffWriter<'a> {
+    fn emit(&mut self, text: impl Into<std::borrow::Cow<'a, str>>, base: &Style, mark: DiffMark) {
+        let overlay = match mark {
+            DiffMark::Unchanged => None,
+            DiffMark::Added => Style::parse("green").ok(),
+            DiffMark::Removed => Style::parse("red").ok(),
+            DiffMark::Changed => Style::parse("bold yellow").ok(),
+        };
+        let style = match overlay {
+            Some(overlay) => Some(self.json.style(base).unwrap_or_default().combine(&overlay)),
+            None => self.json.style(base),
         };
+        self.out.push_back(Segment::new(text, style));
+    }
 
-        // Opening brace
-        segments.push(Segment::new("{", self.style(&self.theme.bracket)));
-        if self.is_compact() {
-            for (i, key) in keys.iter().enumerate() {
-                let value = &obj[*key];
-                let escaped_key = escape_json_string(key, self.ensure_ascii);
-                segments.push(Segment::new(
-                    format!("\"{escaped_key}\""),
-                    self.style(&self.theme.key),
-                ));
-                segments.push(Segment::new(": ", self.style(&self.theme.punctuation)));
-                segments.extend(self.render_value(value, depth + 1, tab_size));
-                if i < keys.len() - 1 {
-                    segments.push(Segment::new(", ", self.style(&self.theme.punctuation)));
+    fn raw(&mut self, text: impl Into<std::borrow::Cow<'a, str>>) {
+        self.out.push_back(Segment::new(text, None));
+    }
+
+    fn diff(&mut self, old: &'a Value, new: &'a Value, depth: usize) {
+        if old == new {
+            self.value(new, depth, DiffMark::Unchanged);
+            return;
+        }
+        match (old, new) {
+            (Value::Object(old_obj), Value::Object(new_obj)) if depth <= MAX_DEPTH => {
+                let mut keys: Vec<&String> = new_obj.keys().collect();
+                if self.json.sort_keys {
+                    keys.sort();
                 }
-            }
-            segments.push(Segment::new("}", self.style(&self.theme.bracket)));
-        } else {
-            let indent_str = self.indent_prefix(depth + 1, tab_size);
-            let close_indent = self.indent_prefix(depth, tab_size);
-
-            segments.push(Segment::new("\n", None));
-            for (i, key) in keys.iter().enumerate() {
-                let value = &obj[*key];
-                segments.push(Segment::new(indent_str.clone(), None));
-
-                let escaped_key = escape_json_string(key, self.ensure_ascii);
-                segments.push(Segment::new(
-                    format!("\"{escaped_key}\""),
-                    self.style(&self.theme.key),
-                ));
-                segments.push(Segment::new(": ", self.style(&self.theme.punctuation)));
-                segments.extend(self.render_value(value, depth + 1, tab_size));
-                if i < keys.len() - 1 {
-                    segments.push(Segment::new(",", self.style(&self.theme.punctuation)));
+                let mut entries: Vec<(Option<&'a String>, DiffEntry<'a>)> = keys
+                    .into_iter()
+                    .map(|key| {
+                        let entry = match old_obj.get(key) {
+                            Some(old_value) => DiffEntry::Both(old_value, &new_obj[key]),
+                            None => DiffEntry::Added(&new_obj[key]),
+                        };
+                        (Some(key), entry)
+                    })
+                    .collect();
+                let mut removed: Vec<(&String, &Value)> = old_obj
+                    .iter()
+                    .filter(|(key, _)| !new_obj.contains_key(*key))
+                    .collect();
+                if self.json.sort_keys {
+                    removed.sort_by(|a, b| a.0.cmp(b.0));
                 }
-                segments.push(Segment::new("\n", None));
+                entries.extend(
+                    removed
+                        .into_iter()
+                        .map(|(key, value)| (Some(key), DiffEntry::Removed(value))),
+                );
+                self.container(entries, depth, ("{", "}"), DiffMark::Unchanged);
+            }
+            (Value::Array(old_items), Value::Array(new_items)) if depth <= MAX_DEPTH => {
+                let mut entries: Vec<(Option<&'a String>, DiffEntry<'a>)> = new_items
+                    .iter()
+                    .enumerate()
+                    .map(|(i, item)| {
+                        let entry = match old_items.get(i) {
+                            Some(old_item) => DiffEntry::Both(old_item, item),
+                            None => DiffEntry::Added(item),
+                        };
+                        (None, entry)
+                    })
+                    .collect();
+                entries.extend(
+                    old_items
+                        .iter()
+                        .skip(new_items.len())
+                        .map(|item| (None, DiffEntry::Removed(item))),
+                );
+                self.container(entries, depth, ("[", "]"), DiffMark::Unchanged);
             }
-            segments.push(Segment::new(close_indent, None));
-            segments.push(Segment::new("}", self.style(&self.theme.bracket)));
+            _ => self.value(new, depth, DiffMark::Changed),
         }
-
-        segments
     }
 
-    /// Render the JSON to segments, using the given tab size for indentation expansion.
-    #[must_use]
-    pub fn render_with_tab_size(&self, tab_size: usize) -> Vec<Segment<'_>> {
-        self.render_value(&self.value, 0, tab_size)
+    /// Render a container; `mark` applies to its brackets, punctuation and
+    /// `Both` entries, while added/removed entries carry their own mark.
+    fn container(
+        &mut self,
+        entries: Vec<(Option<&'a String>, DiffEntry<'a>)>,
+        depth: usize,
+        (open, close): (&'a str, &'a str),
+        mark: DiffMark,
+    ) {
+        let theme = &self.json.theme;
+        let compact = self.json.is_compact();
+        self.emit(open, &theme.bracket, mark);
+        if !compact {
+            self.raw("\n");
+        }
+        for (index, (key, entry)) in entries.into_iter().enumerate() {
+            if index > 0 {
+                if compact {
+                    self.emit(", ", &theme.punctuation, mark);
+                } else {
+                    self.emit(",", &theme.punctuation, mark);
+                    self.raw("\n");
+                }
+            }
+            let entry_mark = match entry {
+                DiffEntry::Both(..) => mark,
+                DiffEntry::Added(_) => DiffMark::Added,
+                DiffEntry::Removed(_) => DiffMark::Removed,
+            };
+            let mut prefix = if compact {
+                String::new()
+            } else {
+                self.json.indent_prefix(depth + 1, self.tab_size)
+            };
+            if matches!(entry, DiffEntry::Removed(_)) {
+                // Put the marker in the last two indent columns when there is room.
+                let keep = prefix.chars().count().saturating_sub(2);
+                prefix = prefix.chars().take(keep).collect::<String>() + "- ";
+            }
+            if !prefix.is_empty() {
+                self.raw(prefix);
+            }
+            if let Some(key) = key {
+                self.emit(self.json.key(key), &theme.key, entry_mark);
+                self.emit(": ", &theme.punctuation, mark);
+            }
+            match entry {
+                DiffEntry::Both(old, new) if mark == DiffMark::Unchanged => {
+                    self.diff(old, new, depth + 1);
+                }
+                DiffEntry::Both(_, value) | DiffEntry::Added(value) | DiffEntry::Removed(value) => {
+                    self.value(value, depth + 1, entry_mark);
+                }
+            }
+        }
+        if !compact {
+            self.raw("\n");
+            self.raw(self.json.indent_prefix(depth, self.tab_size));
+        }
+        self.emit(close, &theme.bracket, mark);
     }
 
-    /// Render the JSON to segments using the default tab size (8).
-    #[must_use]
-    pub fn render(&self) -> Vec<Segment<'_>> {
-        self.render_with_tab_size(8)
+    /// Render a whole value with a single diff mark.
+    fn value(&mut self, value: &'a Value, depth: usize, mark: DiffMark) {
+        let theme = &self.json.theme;
+        match value {
+            Value::Null => self.emit("null", &theme.null, mark),
+            Value::Bool(true) => self.emit("true", &theme.bool_true, mark),
+            Value::Bool(false) => self.emit("false", &theme.bool_false, mark),
+            Value::Number(n) => self.emit(n.to_string(), &theme.number, mark),
+            Value::String(s) => {
+                self.emit(self.json.quote(s), &theme.string, mark);
+            }
+            Value::Array(items) => {
+                if depth > MAX_DEPTH {
+                    self.emit("[...]", &theme.bracket, mark);
+                } else if items.is_empty() {
+                    self.emit("[]", &theme.bracket, mark);
+                } else {
+                    let entries = items.iter().map(|item| (None, DiffEntry::Both(item, item)));
+                    self.container(entries.collect(), depth, ("[", "]"), mark);
+                }
+            }
+            Value::Object(obj) => {
+                if depth > MAX_DEPTH {
+                    self.emit("{...}", &theme.bracket, mark);
+                } else if obj.is_empty() {
+                    self.emit("{}", &theme.bracket, mark);
+                } else {
+                    let mut entries: Vec<(&String, &Value)> = obj.iter().collect();
+                    if self.json.sort_keys {
+                        entries.sort_by(|a, b| a.0.cmp(b.0));
+                    }
+                    let entries = entries
+                        .into_iter()
+                        .map(|(key, value)| (Some(key), DiffEntry::Both(value, value)));
+                    self.container(entries.collect(), depth, ("{", "}"), mark);
+                }
+            }
+        }
     }
+}
 
-    /// Render to a plain string without ANSI codes.
-    #[must_use]
-    pub fn to_plain_string(&self) -> String {
-        self.render().iter().map(|s| s.text.as_ref()).collect()
-    }
+/// Check whether `key` is an ECMAScript identifier, so JSON5 allows it bare.
+fn is_identifier(key: &str) -> bool {
+    let mut chars = key.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+        && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
 }
 
-/// Escape special characters in a JSON string.
-fn escape_json_string(s: &str, ensure_ascii: bool) -> String {
+/// Escape special characters in a JSON string delimited by `quote`.
+fn escape_json_string(s: &str, ensure_ascii: bool, quote: char) -> String {
     let mut result = String::with_capacity(s.len());
     for c in s.chars() {
         match c {
-            '"' => result.push_str("\\\""),
+            c if c == quote => {
+                result.push('\\');
+                result.push(c);
+            }
             '\\' => result.push_str("\\\\"),
             '\u{0008}' => result.push_str("\\b"),
             '\u{000c}' => result.push_str("\\f"),
@@ -846,13 +1229,44 @@ mod tests {
 
     #[test]
     fn test_escape_json_string() {
-        assert_eq!(escape_json_string("hello", false), "hello");
-        assert_eq!(escape_json_string("say \"hi\"", false), "say \\\"hi\\\"");
-        assert_eq!(escape_json_string("a\\b", false), "a\\\\b");
-        assert_eq!(escape_json_string("line1\nline2", false), "line1\\nline2");
-        assert_eq!(escape_json_string("tab\there", false), "tab\\there");
-        assert_eq!(escape_json_string("\u{0008}", false), "\\b");
-        assert_eq!(escape_json_string("\u{000c}", false), "\\f");
+        assert_eq!(escape_json_string("hello", false, '"'), "hello");
+        assert_eq!(
+            escape_json_string("say \"hi\"", false, '"'),
+            "say \\\"hi\\\""
+        );
+        assert_eq!(escape_json_string("a\\b", false, '"'), "a\\\\b");
+        assert_eq!(
+            escape_json_string("line1\nline2", false, '"'),
+            "line1\\nline2"
+        );
+        assert_eq!(escape_json_string("tab\there", false, '"'), "tab\\there");
+        assert_eq!(escape_json_string("\u{0008}", false, '"'), "\\b");
+        assert_eq!(escape_json_string("\u{000c}", false, '"'), "\\f");
+        assert_eq!(
+            escape_json_string("it's \"x\"", false, '\''),
+            "it\\'s \"x\""
+        );
+    }
+
+    #[test]
+    fn test_json_unquoted_keys_and_single_quotes() {
+        let json = Json::new(serde_json::json!({"name": "a"}))
+            .unquoted_keys(true)
+            .single_quotes(true);
+        let text = json.to_plain_string();
+        assert_eq!(
+            text.split_whitespace().collect::<Vec<_>>().join(" "),
+            "{ name: 'a' }"
+        );
+
+        let json = Json::new(serde_json::json!({"first name": "it's"}))
+            .unquoted_keys(true)
+            .single_quotes(true)
+            .compact();
+        assert_eq!(json.to_plain_string(), "{'first name': 'it\\'s'}");
+
+        let json = Json::new(serde_json::json!({"1st": "a"})).unquoted_keys(true);
+        assert_eq!(json.compact().to_plain_string(), "{\"1st\": \"a\"}");
     }
 
     #[test]
@@ -874,4 +1288,94 @@ mod tests {
         // Should have styled segments
         assert!(segments.iter().any(|s| s.style.is_some()));
     }
+
+    #[test]
+    fn test_json_diff_marks_changed_added_and_removed() {
+        let old = serde_json::json!({"a": 1, "c": {"x": true}});
+        let new = serde_json::json!({"a": 2, "b": 3});
+        let json = Json::diff(&old, &new);
+        let segments = json.render();
+
+        let style_of = |text: &str| {
+            segments
+                .iter()
+                .find(|seg| seg.text == text)
+                .and_then(|seg| seg.style.clone())
+                .unwrap_or_else(|| panic!("missing segment {text:?}"))
+        };
+        let theme = JsonTheme::default();
+        let green = Style::parse("green").unwrap();
+        let red = Style::parse("red").unwrap();
+        let changed = Style::parse("bold yellow").unwrap();
+
+        assert_eq!(style_of("2"), theme.number.combine(&changed));
+        assert_eq!(style_of("\"b\""), theme.key.combine(&green));
+        assert_eq!(style_of("3"), theme.number.combine(&green));
+        assert_eq!(style_of("\"a\""), theme.key);
+        assert_eq!(style_of("\"c\""), theme.key.combine(&red));
+        assert_eq!(style_of("true"), theme.bool_true.combine(&red));
+
+        assert_eq!(
+            json.to_plain_string(),
+            "{\n  \"a\": 2,\n  \"b\": 3,\n- \"c\": {\n    \"x\": true\n  }\n}"
+        );
+    }
+
+    #[test]
+    fn test_json_diff_arrays_and_identical_documents() {
+        let old = serde_json::json!([1, 2, 3]);
+        let new = serde_json::json!([1, 5]);
+        let plain = Json::diff(&old, &new).compact().to_plain_string();
+        assert_eq!(plain, "[1, 5, - 3]");
+
+        let same = serde_json::json!({"k": [1, {"z": null}]});
+        assert_eq!(
+            Json::diff(&same, &same).to_plain_string(),
+            Json::new(same.clone()).to_plain_string()
+        );
+    }
+
+    #[test]
+    fn test_json_render_streaming_matches_render() {
+        let json = Json::new(serde_json::json!({
+            "name": "rich",
+            "tags": ["a", {"nested": [1, 2.5, null]}, []],
+            "flags": {"on": true, "off": false},
+            "empty": {}
+        }))
+        .sort_keys(true);
+
+        let streamed: Vec<Segment<'_>> = json.render_streaming().collect();
+        assert_eq!(streamed, json.render());
+
+        let plain: String = streamed.iter().map(|s| s.text.as_ref()).collect();
+        assert_eq!(plain, json.to_plain_string());
+        assert_eq!(
+            plain,
+            "{\n  \"empty\": {},\n  \"flags\": {\n    \"off\": false,\n    \"on\": true\n  },\n  \"name\": \"rich\",\n  \"tags\": [\n    \"a\",\n    {\n      \"nested\": [\n        1,\n        2.5,\n        null\n      ]\n    },\n    []\n  ]\n}"
+        );
+    }
+
+    #[test]
+    fn test_json_render_streaming_compact() {
+        let json = Json::new(serde_json::json!({"b": [1, 2], "a": {"x": null}}))
+            .compact()
+            .sort_keys(true);
+        let plain: String = json
+            .render_streaming()
+            .map(|s| s.text.into_owned())
+            .collect();
+        assert_eq!(plain, r#"{"a": {"x": null}, "b": [1, 2]}"#);
+    }
+
+    #[test]
+    fn test_json_render_streaming_depth_limit() {
+        let mut value = serde_json::json!(1);
+        for _ in 0..25 {
+            value = serde_json::json!([value]);
+        }
+        let plain = Json::new(value).compact().to_plain_string();
+        assert!(plain.contains("[...]"));
+        assert!(!plain.contains('1'));
+    }
 }
@@ -89,7 +89,10 @@
 //!
 //! # Known Limitations
 //!
-//! - **Images**: Rendered as an emoji + alt text. With `hyperlinks=true`, the alt text is an OSC8 hyperlink.
+//! - **Images**: Rendered as an emoji + alt text. With `hyperlinks=true`, the alt text is an OSC8 hyperlink;
+//!   with `hyperlinks=false`, the URL follows as ` (url)`. With `.inline_images(true)` and an
+//!   `.image_dir(...)`, image files under that directory are drawn via the iTerm2 inline image
+//!   protocol on terminals that support it.
 //! - **HTML**: Inline HTML is ignored
 //! - **Footnotes**: Supported by the parser; rendering is minimal and may differ from Python Rich
 //! - **Task lists**: GitHub-style task lists (`- [ ]` / `- [x]`) render as checkboxes
@@ -97,62 +100,74 @@
 //!   unless the `syntax` feature is enabled and a language is present (then it renders via `Syntax`).
 //! - **Hyperlinks**: Use `.hyperlinks(false)` to disable OSC8 and show a URL suffix (Python Rich-compatible).
 
+use std::borrow::Cow;
 use std::fmt::Write;
+use std::io::Read;
+use std::path::{Path, PathBuf};
 
 use crate::cells;
-use crate::segment::Segment;
+use crate::segment::{ControlCode, ControlType, Segment};
 use crate::style::Style;
+use crate::terminal;
 
-use pulldown_cmark::{Alignment, CodeBlockKind, Event, HeadingLevel, Options, Parser, Tag, TagEnd};
+use pulldown_cmark::{Alignment, CodeBlockKind, Event, Options, Parser, Tag, TagEnd};
 
 #[cfg(feature = "syntax")]
 use crate::renderables::Syntax;
 
-/// A markdown document that can be rendered to the terminal.
-#[derive(Debug, Clone)]
-pub struct Markdown {
-    /// The markdown source text.
-    source: String,
+/// Styles for each kind of markdown element.
+///
+/// Pass to [`Markdown::theme`] to restyle a document in one go; the
+/// individual `*_style` builders on [`Markdown`] adjust single entries.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MarkdownTheme {
     /// Style for H1 headings.
-    h1_style: Style,
+    pub h1_style: Style,
     /// Style for H2 headings.
-    h2_style: Style,
+    pub h2_style: Style,
     /// Style for H3 headings.
-    h3_style: Style,
-    /// Style for H4-H6 headings.
-    h4_style: Style,
+    pub h3_style: Style,
+    /// Style for H4 headings.
+    pub h4_style: Style,
+    /// Style for H5 headings.
+    pub h5_style: Style,
+    /// Style for H6 headings.
+    pub h6_style: Style,
     /// Style for emphasis (italic).
-    emphasis_style: Style,
+    pub emphasis_style: Style,
     /// Style for strong emphasis (bold).
-    strong_style: Style,
+    pub strong_style: Style,
     /// Style for strikethrough text.
-    strikethrough_style: Style,
+    pub strikethrough_style: Style,
     /// Style for inline code.
-    code_style: Style,
+    pub code_style: Style,
     /// Style for code blocks.
-    code_block_style: Style,
+    pub code_block_style: Style,
     /// Style for links.
-    link_style: Style,
+    pub link_style: Style,
     /// Style for link text when OSC8 hyperlinks are disabled (`hyperlinks=false`).
-    link_text_style: Style,
+    pub link_text_style: Style,
     /// Style for blockquotes.
-    quote_style: Style,
+    pub quote_style: Style,
     /// Style for table headers.
-    table_header_style: Style,
+    pub table_header_style: Style,
     /// Style for table borders.
-    table_border_style: Style,
-    /// Character for bullet points.
-    bullet_char: char,
-    /// Indent for nested lists.
-    list_indent: usize,
-    /// Whether to emit OSC8 hyperlinks for links and images.
-    hyperlinks: bool,
+    pub table_border_style: Style,
+    /// Style for the `☑` marker of completed task-list items.
+    pub task_checked_style: Style,
+    /// Style for the `☐` marker of open task-list items.
+    pub task_unchecked_style: Style,
+    /// Style for the label of completed task-list items (plain by default).
+    pub task_done_style: Style,
 }
 
-impl Default for Markdown {
+impl Default for MarkdownTheme {
     fn default() -> Self {
+        let minor_heading = Style::new()
+            .bold()
+            .color_str("bright_blue")
+            .unwrap_or_default();
         Self {
-            source: String::new(),
             h1_style: Style::new()
                 .bold()
                 .underline()
@@ -160,10 +175,9 @@ impl Default for Markdown {
                 .unwrap_or_default(),
             h2_style: Style::new().bold().color_str("cyan").unwrap_or_default(),
             h3_style: Style::new().bold().color_str("blue").unwrap_or_default(),
-            h4_style: Style::new()
-                .bold()
-                .color_str("bright_blue")
-                .unwrap_or_default(),
+            h4_style: minor_heading.clone(),
+            h5_style: minor_heading.clone(),
+            h6_style: minor_heading,
             emphasis_style: Style::new().italic(),
             strong_style: Style::new().bold(),
             strikethrough_style: Style::new().strike(),
@@ -191,9 +205,65 @@ impl Default for Markdown {
                 .color_str("bright_white")
                 .unwrap_or_default(),
             table_border_style: Style::new().color_str("bright_black").unwrap_or_default(),
+            task_checked_style: Style::new().color_str("green").unwrap_or_default(),
+            task_unchecked_style: Style::new().color_str("bright_black").unwrap_or_default(),
+            task_done_style: Style::new(),
+        }
+    }
+}
+
+impl MarkdownTheme {
+    /// Style for a heading of the given level (1-6).
+    ///
+    /// Levels outside that range are clamped to it.
+    #[must_use]
+    pub fn heading_style(&self, level: u8) -> &Style {
+        match level {
+            0 | 1 => &self.h1_style,
+            2 => &self.h2_style,
+            3 => &self.h3_style,
+            4 => &self.h4_style,
+            5 => &self.h5_style,
+            _ => &self.h6_style,
+        }
+    }
+}
+
+/// Largest image file (in bytes) that is drawn inline.
+pub const MAX_INLINE_IMAGE_BYTES: u64 = 4 * 1024 * 1024;
+
+/// A markdown document that can be rendered to the terminal.
+#[derive(Debug, Clone)]
+pub struct Markdown {
+    /// The markdown source text.
+    source: String,
+    /// Element styles.
+    theme: MarkdownTheme,
+    /// Prefix headings with hierarchical numbers (`1`, `1.1`, ...).
+    number_headings: bool,
+    /// Character for bullet points.
+    bullet_char: char,
+    /// Indent for nested lists.
+    list_indent: usize,
+    /// Whether to emit OSC8 hyperlinks for links and images.
+    hyperlinks: bool,
+    /// Whether to draw local images inline (iTerm2 `OSC 1337`) when supported.
+    inline_images: bool,
+    /// Directory inline images may be read from; no local reads when `None`.
+    image_dir: Option<PathBuf>,
+}
+
+impl Default for Markdown {
+    fn default() -> Self {
+        Self {
+            source: String::new(),
+            theme: MarkdownTheme::default(),
+            number_headings: false,
             bullet_char: '•',
             list_indent: 2,
             hyperlinks: true,
+            inline_images: false,
+            image_dir: None,
         }
     }
 }
@@ -208,87 +278,116 @@ impl Markdown {
         }
     }
 
+    /// Replace all element styles.
+    #[must_use]
+    pub fn theme(mut self, theme: MarkdownTheme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// Prefix headings with hierarchical numbers: `1`, `1.1`, `1.2`, `2`, ...
+    ///
+    /// Each heading increments the counter for its level and resets the
+    /// deeper ones. Levels above the first heading in the document are
+    /// left out of the number.
+    #[must_use]
+    pub fn number_headings(mut self, enabled: bool) -> Self {
+        self.number_headings = enabled;
+        self
+    }
+
     /// Set the style for H1 headings.
     #[must_use]
     pub fn h1_style(mut self, style: Style) -> Self {
-        self.h1_style = style;
+        self.theme.h1_style = style;
         self
     }
 
     /// Set the style for H2 headings.
     #[must_use]
     pub fn h2_style(mut self, style: Style) -> Self {
-        self.h2_style = style;
+        self.theme.h2_style = style;
         self
     }
 
     /// Set the style for H3 headings.
     #[must_use]
     pub fn h3_style(mut self, style: Style) -> Self {
-        self.h3_style = style;
+        self.theme.h3_style = style;
         self
     }
 
     /// Set the style for H4-H6 headings.
     #[must_use]
     pub fn h4_style(mut self, style: Style) -> Self {
-        self.h4_style = style;
+        self.theme.h5_style = style.clone();
+        self.theme.h6_style = style.clone();
+        self.theme.h4_style = style;
         self
     }
 
     /// Set the style for emphasis (italic).
     #[must_use]
     pub fn emphasis_style(mut self, style: Style) -> Self {
-        self.emphasis_style = style;
+        self.theme.emphasis_style = style;
         self
     }
 
     /// Set the style for strong emphasis (bold).
     #[must_use]
     pub fn strong_style(mut self, style: Style) -> Self {
-        self.strong_style = style;
+        self.theme.strong_style = style;
         self
     }
 
     /// Set the style for inline code.
     #[must_use]
     pub fn code_style(mut self, style: Style) -> Self {
-        self.code_style = style;
+        self.theme.code_style = style;
         self
     }
 
     /// Set the style for code blocks.
     #[must_use]
     pub fn code_block_style(mut self, style: Style) -> Self {
-        self.code_block_style = style;
+        self.theme.code_block_style = style;
         self
     }
 
     /// Set the style for links.
     #[must_use]
     pub fn link_style(mut self, style: Style) -> Self {
-        self.link_style = style;
+        self.theme.link_style = style;
         self
     }
 
     /// Set the style for blockquotes.
     #[must_use]
     pub fn quote_style(mut self, style: Style) -> Self {
-        self.quote_style = style;
+        self.theme.quote_style = style;
         self
     }
 
     /// Set the style for table headers.
     #[must_use]
     pub fn table_header_style(mut self, style: Style) -> Self {
-        self.table_header_style = style;
+        self.theme.table_header_style = style;
         self
     }
 
     /// Set the style for table borders.
     #[must_use]
     pub fn table_border_style(mut self, style: Style) -> Self {
-        self.table_border_style = style;
+        self.theme.table_border_style = style;
+        self
+    }
+
+    /// Set the style for the label of completed task-list items.
+    ///
+    /// Use `Style::new().dim().strike()` to fade finished tasks.
+    #[must_use]
+    pub fn task_done_style(mut self, style: Style) -> Self {
+        self.theme.task_done_style = style;
         self
     }
 
@@ -317,6 +416,30 @@ impl Markdown {
         self
     }
 
+    /// Enable or disable inline images.
+    ///
+    /// When enabled and the terminal supports the iTerm2 inline image protocol
+    /// (see [`terminal::supports_inline_images`]), images whose URL names a
+    /// file under [`Markdown::image_dir`] are drawn in place. Otherwise images
+    /// render as an emoji + alt text placeholder.
+    #[must_use]
+    pub fn inline_images(mut self, enabled: bool) -> Self {
+        self.inline_images = enabled;
+        self
+    }
+
+    /// Allow inline images to be read from files under `dir`.
+    ///
+    /// Relative image URLs resolve against `dir`; paths that end up outside
+    /// it, and files over [`MAX_INLINE_IMAGE_BYTES`], are left as
+    /// placeholders. Without this, no local files are read, so untrusted
+    /// Markdown cannot pull arbitrary files into the output.
+    #[must_use]
+    pub fn image_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.image_dir = Some(dir.into());
+        self
+    }
+
     /// Render the markdown to segments.
     #[must_use]
     #[allow(clippy::too_many_lines)]
@@ -326,6 +449,7 @@ impl Markdown {
         let mut list_stack: Vec<(bool, usize)> = Vec::new(); // (is_ordered, item_number)
         let mut list_item_prefix_len: Vec<usize> = Vec::new();
         let mut list_item_first_paragraph: Vec<bool> = Vec::new();
+        let mut list_item_task_done: Vec<bool> = Vec::new();
         let mut list_item_prefix_pending = false;
         let mut in_code_block = false;
         let mut code_block_text = String::new();
@@ -337,6 +461,9 @@ impl Markdown {
         let mut blockquote_first_paragraph = false;
         let mut current_link_url = String::new();
         let mut image_style_pushed = false;
+        let mut current_image_url = String::new();
+        let mut in_inline_image = false;
+        let mut heading_counters = [0usize; 6];
 
         // Table state
         let mut in_table = false;
@@ -377,7 +504,7 @@ impl Markdown {
         macro_rules! ensure_blockquote_prefix {
             ($segs:expr) => {
                 if in_blockquote && blockquote_prefix_pending {
-                    $segs.push(Segment::new("│ ", Some(self.quote_style.clone())));
+                    $segs.push(Segment::new("│ ", Some(self.theme.quote_style.clone())));
                     blockquote_prefix_pending = false;
                 }
             };
@@ -405,12 +532,23 @@ impl Markdown {
                             if !segments.is_empty() {
                                 segments.push(Segment::new("\n\n", None));
                             }
-                            let style = match level {
-                                HeadingLevel::H1 => self.h1_style.clone(),
-                                HeadingLevel::H2 => self.h2_style.clone(),
-                                HeadingLevel::H3 => self.h3_style.clone(),
-                                _ => self.h4_style.clone(),
-                            };
+                            let style = self.theme.heading_style(level as u8).clone();
+                            if self.number_headings {
+                                let depth = level as usize;
+                                heading_counters[depth - 1] += 1;
+                                heading_counters[depth..].fill(0);
+                                let first = heading_counters[..depth]
+                                    .iter()
+                                    .position(|&count| count > 0)
+                                    .unwrap_or(0);
+                                let number = heading_counters[first..depth]
+                                    .iter()
+                                    .map(usize::to_string)
+                                    .collect::<Vec<_>>()
+                                    .join(".");
+                                segments
+                                    .push(Segment::new(format!("{number} "), Some(style.clone())));
+                            }
                             style_stack.push(style);
                         }
                         Tag::Paragraph => {
@@ -439,13 +577,13 @@ impl Markdown {
                             }
                         }
                         Tag::Emphasis => {
-                            style_stack.push(self.emphasis_style.clone());
+                            style_stack.push(self.theme.emphasis_style.clone());
                         }
                         Tag::Strong => {
-                            style_stack.push(self.strong_style.clone());
+                            style_stack.push(self.theme.strong_style.clone());
                         }
                         Tag::Strikethrough => {
-                            style_stack.push(self.strikethrough_style.clone());
+                            style_stack.push(self.theme.strikethrough_style.clone());
                         }
                         Tag::CodeBlock(kind) => {
                             in_code_block = true;
@@ -467,29 +605,46 @@ impl Markdown {
                                 cfg!(feature = "syntax") && !code_block_language.is_empty();
 
                             if !code_block_use_syntax {
-                                style_stack.push(self.code_block_style.clone());
+                                style_stack.push(self.theme.code_block_style.clone());
                                 code_block_style_pushed = true;
                             }
                         }
                         Tag::Link { dest_url, .. } => {
                             current_link_url = dest_url.to_string();
                             if self.hyperlinks {
-                                style_stack
-                                    .push(self.link_style.clone().link(current_link_url.clone()));
+                                style_stack.push(
+                                    self.theme.link_style.clone().link(current_link_url.clone()),
+                                );
                             } else {
-                                style_stack.push(self.link_text_style.clone());
+                                style_stack.push(self.theme.link_text_style.clone());
                             }
                         }
                         Tag::Image { dest_url, .. } => {
-                            // Python Rich renders images as an emoji + alt text, optionally linked.
                             ensure_blockquote_prefix!(segments);
                             ensure_list_prefix!(segments);
-                            segments.push(Segment::new("🌆 ", None));
                             image_style_pushed = false;
+                            if self.inline_images
+                                && let Some(dir) = &self.image_dir
+                                && terminal::supports_inline_images()
+                                && let Some(data) = read_local_image(dir, &dest_url)
+                            {
+                                let mut image = Segment::control(vec![ControlCode::new(
+                                    ControlType::InlineImage,
+                                )]);
+                                image.text = Cow::Owned(base64_encode(&data));
+                                segments.push(image);
+                                in_inline_image = true;
+                                continue;
+                            }
+                            // Python Rich renders images as an emoji + alt text, optionally linked.
+                            segments.push(Segment::new("🌆 ", None));
                             if self.hyperlinks {
                                 style_stack.push(Style::new().link(dest_url.to_string()));
-                                image_style_pushed = true;
+                            } else {
+                                style_stack.push(self.theme.link_text_style.clone());
+                                current_image_url = dest_url.to_string();
                             }
+                            image_style_pushed = true;
                         }
                         Tag::BlockQuote(_) => {
                             in_blockquote = true;
@@ -498,7 +653,7 @@ impl Markdown {
                             if !segments.is_empty() {
                                 segments.push(Segment::new("\n", None));
                             }
-                            style_stack.push(self.quote_style.clone());
+                            style_stack.push(self.theme.quote_style.clone());
                         }
                         Tag::List(start_num) => {
                             if !segments.is_empty() {
@@ -531,6 +686,7 @@ impl Markdown {
                                 }
                             }
                             list_item_first_paragraph.push(true);
+                            list_item_task_done.push(false);
                         }
                         Tag::Table(alignments) => {
                             in_table = true;
@@ -663,17 +819,27 @@ impl Markdown {
                                 segments.push(Segment::new(" (", None));
                                 segments.push(Segment::new(
                                     current_link_url.clone(),
-                                    Some(self.link_style.clone()),
+                                    Some(self.theme.link_style.clone()),
                                 ));
                                 segments.push(Segment::new(")", None));
                             }
                             current_link_url.clear();
                         }
                         TagEnd::Image => {
+                            in_inline_image = false;
                             if image_style_pushed {
                                 style_stack.pop();
                             }
                             image_style_pushed = false;
+                            if !current_image_url.is_empty() && !in_table {
+                                segments.push(Segment::new(" (", None));
+                                segments.push(Segment::new(
+                                    current_image_url.clone(),
+                                    Some(self.theme.link_style.clone()),
+                                ));
+                                segments.push(Segment::new(")", None));
+                            }
+                            current_image_url.clear();
                         }
                         TagEnd::BlockQuote(_) => {
                             in_blockquote = false;
@@ -688,6 +854,9 @@ impl Markdown {
                             segments.push(Segment::new("\n", None));
                             list_item_prefix_len.pop();
                             list_item_first_paragraph.pop();
+                            if list_item_task_done.pop() == Some(true) {
+                                style_stack.pop();
+                            }
                             list_item_prefix_pending = false;
                             if in_blockquote {
                                 blockquote_prefix_pending = true;
@@ -721,6 +890,10 @@ impl Markdown {
                     }
                 }
                 Event::Text(text) => {
+                    if in_inline_image {
+                        // The image itself replaces its alt text.
+                        continue;
+                    }
                     if in_table {
                         current_cell_content.push_str(&text.replace('\n', " "));
                     } else {
@@ -744,7 +917,7 @@ impl Markdown {
                         ensure_list_prefix!(segments);
                         segments.push(Segment::new(
                             format!(" {code} "),
-                            Some(self.code_style.clone()),
+                            Some(self.theme.code_style.clone()),
                         ));
                     }
                 }
@@ -781,13 +954,20 @@ impl Markdown {
                 Event::TaskListMarker(checked) => {
                     // Render checkbox for task list items
                     // This event comes right after Start(Tag::Item), so the bullet is already rendered
-                    let checkbox = if checked { "☑ " } else { "☐ " };
-                    let style = if checked {
-                        Style::new().color_str("green").unwrap_or_default()
+                    let (checkbox, style) = if checked {
+                        ("☑ ", &self.theme.task_checked_style)
                     } else {
-                        Style::new().color_str("bright_black").unwrap_or_default()
+                        ("☐ ", &self.theme.task_unchecked_style)
                     };
-                    segments.push(Segment::new(checkbox.to_string(), Some(style)));
+                    segments.push(Segment::new(checkbox, Some(style.clone())));
+                    // Wrapped lines of the label line up after the checkbox.
+                    if let Some(prefix_len) = list_item_prefix_len.last_mut() {
+                        *prefix_len += cells::cell_len(checkbox);
+                    }
+                    if checked && let Some(done) = list_item_task_done.last_mut() {
+                        *done = true;
+                        style_stack.push(self.theme.task_done_style.clone());
+                    }
                 }
                 _ => {}
             }
@@ -840,7 +1020,7 @@ impl Markdown {
             *w = (*w).max(3);
         }
 
-        let border_style = Some(self.table_border_style.clone());
+        let border_style = Some(self.theme.table_border_style.clone());
 
         // Helper to render a horizontal border
         let render_border =
@@ -866,7 +1046,10 @@ impl Markdown {
                     let padded = Self::pad_cell(content, *width, alignment);
                     segs.push(Segment::new(" ", None));
                     if is_header {
-                        segs.push(Segment::new(padded, Some(self.table_header_style.clone())));
+                        segs.push(Segment::new(
+                            padded,
+                            Some(self.theme.table_header_style.clone()),
+                        ));
                     } else {
                         segs.push(Segment::new(padded, style.clone()));
                     }
@@ -925,6 +1108,54 @@ impl Markdown {
     }
 }
 
+/// Read the bytes of an image URL that names a local file (plain path or `file://`).
+fn read_local_image(dir: &Path, url: &str) -> Option<Vec<u8>> {
+    let path = match url.strip_prefix("file://") {
+        Some(path) => path,
+        None if url.contains("://") => return None,
+        None => url,
+    };
+    // Canonicalize both sides so `..` and symlinks can't escape `dir`.
+    let dir = dir.canonicalize().ok()?;
+    let path = dir.join(path).canonicalize().ok()?;
+    if !path.starts_with(&dir) {
+        return None;
+    }
+    let file = std::fs::File::open(&path).ok()?;
+    let metadata = file.metadata().ok()?;
+    if !metadata.is_file() || metadata.len() > MAX_INLINE_IMAGE_BYTES {
+        return None;
+    }
+    // The file may grow after the size check, so bound the read as well.
+    let mut data = Vec::new();
+    file.take(MAX_INLINE_IMAGE_BYTES + 1)
+        .read_to_end(&mut data)
+        .ok()?;
+    (data.len() as u64 <= MAX_INLINE_IMAGE_BYTES).then_some(data)
+}
+
+/// Standard (RFC 4648) base64 with padding, as the inline image protocol expects.
+fn base64_encode(data: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let b = [
+            chunk[0],
+            chunk.get(1).copied().unwrap_or(0),
+            chunk.get(2).copied().unwrap_or(0),
+        ];
+        let n = (u32::from(b[0]) << 16) | (u32::from(b[1]) << 8) | u32::from(b[2]);
+        for (i, shift) in [18u32, 12, 6, 0].into_iter().enumerate() {
+            if i <= chunk.len() {
+                out.push(char::from(ALPHABET[((n >> shift) & 0x3f) as usize]));
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
 fn pad_segments_to_width(segments: Vec<Segment<'_>>, width: usize) -> Vec<Segment<'_>> {
     let mut padded = Vec::new();
     let mut line_width = 0usize;
@@ -1004,6 +1235,15 @@ mod tests {
         assert!(text.contains("Title"));
     }
 
+    #[test]
+    fn test_theme_heading_style_by_level() {
+        let theme = MarkdownTheme::default();
+        assert_eq!(theme.heading_style(1), &theme.h1_style);
+        assert_eq!(theme.heading_style(3), &theme.h3_style);
+        assert_eq!(theme.heading_style(0), &theme.h1_style);
+        assert_eq!(theme.heading_style(9), &theme.h6_style);
+    }
+
     #[test]
     fn test_render_multiple_headings() {
         let md = Markdown::new("# H1\n## H2\n### H3");
@@ -1014,6 +1254,31 @@ mod tests {
         assert!(text.contains("H3"));
     }
 
+    #[test]
+    fn test_number_headings_prefixes_hierarchical_numbers() {
+        let source = "# Intro\n## First\n## Second";
+        let md = Markdown::new(source).number_headings(true);
+        let numbered = md.render(80);
+        let text: String = numbered.iter().map(|s| s.text.as_ref()).collect();
+        assert!(text.contains("1 Intro"));
+        assert!(text.contains("1.1 First"));
+        assert!(text.contains("1.2 Second"));
+
+        let plain_md = Markdown::new(source);
+        let segments = plain_md.render(80);
+        let style_of = |needle: &str| {
+            segments
+                .iter()
+                .find(|seg| seg.text.contains(needle))
+                .and_then(|seg| seg.style.clone())
+        };
+        let plain: String = segments.iter().map(|s| s.text.as_ref()).collect();
+        assert!(!plain.contains("1.1"));
+        assert_eq!(style_of("First"), style_of("Second"));
+        assert_ne!(style_of("Intro"), style_of("First"));
+        assert_eq!(style_of("First"), Some(MarkdownTheme::default().h2_style));
+    }
+
     #[test]
     fn test_render_emphasis() {
         let md = Markdown::new("This is *italic* and **bold**.");
@@ -1134,6 +1399,63 @@ mod tests {
         assert!(text.contains(" (https://example.com)"));
     }
 
+    #[test]
+    fn test_render_image_placeholder() {
+        let md = Markdown::new("![Logo](https://example.com/logo.png)")
+            .hyperlinks(false)
+            .inline_images(false);
+        let segments = md.render(80);
+        assert!(segments.iter().all(|s| !s.is_control()));
+        let text: String = segments.iter().map(|s| s.text.as_ref()).collect();
+        assert!(
+            text.contains("🌆 Logo (https://example.com/logo.png)"),
+            "got {text:?}"
+        );
+
+        let linked = Markdown::new("![Logo](https://example.com/logo.png)");
+        let segments = linked.render(80);
+        let text: String = segments.iter().map(|s| s.text.as_ref()).collect();
+        assert!(text.contains("🌆 Logo"));
+        assert!(!text.contains("(https://"));
+    }
+
+    #[test]
+    fn test_read_local_image_stays_in_image_dir() {
+        let root = std::env::temp_dir().join(format!("rich_rust_md_images_{}", std::process::id()));
+        let dir = root.join("images");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("logo.png"), b"png").unwrap();
+        std::fs::write(root.join("secret.txt"), b"secret").unwrap();
+        let too_big = usize::try_from(MAX_INLINE_IMAGE_BYTES).unwrap() + 1;
+        std::fs::write(dir.join("huge.png"), vec![0u8; too_big]).unwrap();
+
+        assert_eq!(
+            read_local_image(&dir, "logo.png").as_deref(),
+            Some(&b"png"[..])
+        );
+        let absolute = format!("file://{}", dir.join("logo.png").display());
+        assert_eq!(
+            read_local_image(&dir, &absolute).as_deref(),
+            Some(&b"png"[..])
+        );
+        assert_eq!(read_local_image(&dir, "../secret.txt"), None);
+        let outside = root.join("secret.txt").display().to_string();
+        assert_eq!(read_local_image(&dir, &outside), None);
+        assert_eq!(read_local_image(&dir, "huge.png"), None);
+        assert_eq!(read_local_image(&dir, "https://example.com/logo.png"), None);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_base64_encode() {
+        assert_eq!(base64_encode(b""), "");
+        assert_eq!(base64_encode(b"f"), "Zg==");
+        assert_eq!(base64_encode(b"fo"), "Zm8=");
+        assert_eq!(base64_encode(b"foo"), "Zm9v");
+        assert_eq!(base64_encode(b"foobar"), "Zm9vYmFy");
+    }
+
     #[test]
     fn test_render_blockquote() {
         let md = Markdown::new("> This is a quote");
@@ -1260,6 +1582,30 @@ mod tests {
         assert!(style.color.is_some(), "checkbox should have a color");
     }
 
+    #[test]
+    fn test_render_task_list_done_style() {
+        let md = Markdown::new("- [x] Ship it\n- [ ] Write docs")
+            .task_done_style(Style::new().dim().strike());
+        let segments = md.render(0);
+        let text: String = segments.iter().map(|s| s.text.as_ref()).collect();
+        assert_eq!(text, "  • ☑ Ship it\n  • ☐ Write docs\n");
+
+        let style_of = |label: &str| {
+            segments
+                .iter()
+                .find(|seg| seg.text == label)
+                .and_then(|seg| seg.style.clone())
+                .unwrap_or_default()
+        };
+        assert!(style_of("Ship it").attributes.contains(Attributes::STRIKE));
+        assert!(style_of("Ship it").attributes.contains(Attributes::DIM));
+        assert!(
+            !style_of("Write docs")
+                .attributes
+                .contains(Attributes::STRIKE)
+        );
+    }
+
     #[test]
     fn test_render_task_list_mixed_with_regular() {
         let md = Markdown::new("- Regular item\n- [ ] Task item\n- Another regular");
@@ -116,7 +116,7 @@ pub mod tree;
 
 // Re-export commonly used types
 pub use align::{Align, AlignLines, AlignMethod, VerticalAlignMethod, align_text};
-pub use columns::Columns;
+pub use columns::{Columns, Flow};
 pub use constrain::Constrain;
 pub use control::Control;
 pub use emoji::{Emoji, NoEmoji};
@@ -126,11 +126,11 @@ pub use padding::{Padding, PaddingDimensions};
 pub use panel::Panel;
 pub use pretty::{Inspect, InspectOptions, Pretty, PrettyOptions, inspect};
 pub use progress::{
-    BarStyle, DownloadColumn, FileSizeColumn, ProgressBar, Spinner, TotalFileSizeColumn,
+    BarStyle, DownloadColumn, FileSizeColumn, ProgressBar, Spinner, TotalFileSizeColumn, Track,
     TransferSpeedColumn,
 };
 pub use rule::Rule;
-pub use table::{Cell, Column, Row, Table, VerticalAlign};
+pub use table::{Cell, Column, NumberFormat, Row, Table, ToRow, VerticalAlign};
 pub use traceback::{Traceback, TracebackFrame, print_exception};
 pub use tree::{Tree, TreeGuides, TreeNode};
 
@@ -178,7 +178,7 @@ impl<T: Renderable + ?Sized> Renderable for &T {
 pub mod syntax;
 
 #[cfg(feature = "syntax")]
-pub use syntax::{Syntax, SyntaxError};
+pub use syntax::{Syntax, SyntaxError, TokenKind};
 
 #[cfg(feature = "syntax")]
 impl Renderable for Syntax {
@@ -196,7 +196,7 @@ impl Renderable for Syntax {
 pub mod markdown;
 
 #[cfg(feature = "markdown")]
-pub use markdown::Markdown;
+pub use markdown::{MAX_INLINE_IMAGE_BYTES, Markdown, MarkdownTheme};
 
 #[cfg(feature = "markdown")]
 impl Renderable for Markdown {
@@ -7,7 +7,7 @@
 
 use crate::console::{Console, ConsoleOptions};
 use crate::renderables::Renderable;
-use crate::segment::Segment;
+use crate::segment::{Segment, pop_trailing_empty_line, split_lines};
 use crate::style::Style;
 
 /// CSS-style padding dimensions.
@@ -138,6 +138,25 @@ impl<'a> Padding<'a> {
         }
     }
 
+    /// Render `renderable` inside padding that is `width` cells wide.
+    ///
+    /// The child is rendered at the inner width (`width` minus left and right
+    /// padding), so wrapped content never overflows the padded box.
+    #[must_use]
+    pub fn from_renderable<R: Renderable + ?Sized>(
+        renderable: &'a R,
+        console: &Console,
+        pad: impl Into<PaddingDimensions>,
+        width: usize,
+    ) -> Self {
+        let pad = pad.into();
+        let inner_width = width.saturating_sub(pad.horizontal()).max(1);
+        let options = console.options().update_width(inner_width);
+        let mut lines = split_lines(renderable.render(console, &options).into_iter());
+        pop_trailing_empty_line(&mut lines);
+        Self::new(lines, pad, width)
+    }
+
     /// Set the padding style.
     #[must_use]
     pub fn style(mut self, style: Style) -> Self {
@@ -286,6 +305,38 @@ mod tests {
         assert_eq!(pad, PaddingDimensions::all(5));
     }
 
+    #[test]
+    fn test_padding_from_renderable_wraps_to_inner_width() {
+        use crate::text::Text;
+
+        /// Wraps its text to whatever width the parent offers.
+        struct Wrapping(Text);
+
+        impl Renderable for Wrapping {
+            fn render<'a>(&'a self, _: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+                let mut segments = Vec::new();
+                for line in self.0.wrap(options.max_width) {
+                    segments.extend(line.render("\n").into_iter().map(Segment::into_owned));
+                }
+                segments
+            }
+        }
+
+        let console = Console::builder().width(80).build();
+        let text = Wrapping(Text::new(
+            "the quick brown fox jumps over the lazy dog again and again",
+        ));
+        let lines = Padding::from_renderable(&text, &console, (0, 2, 0, 2), 20).render();
+
+        assert!(lines.len() > 1);
+        for line in &lines {
+            let rendered: String = line.iter().map(|s| s.text.as_ref()).collect();
+            assert_eq!(cell_len(&rendered), 20, "line {rendered:?}");
+            assert!(rendered.starts_with("  ") && rendered.ends_with("  "));
+            assert!(cell_len(rendered[2..rendered.len() - 2].trim_end()) <= 16);
+        }
+    }
+
     #[test]
     fn test_padding_from_tuple2() {
         let pad: PaddingDimensions = (1, 2).into();
@@ -3,19 +3,23 @@
 //! A Panel renders content inside a decorative border with optional
 //! title and subtitle.
 
+use std::fmt;
+use std::sync::Arc;
+
 use crate::r#box::{ASCII, BoxChars, ROUNDED, SQUARE};
 use crate::cells;
+use crate::color::Color;
 use crate::console::{Console, ConsoleOptions};
 use crate::markup;
 use crate::renderables::Renderable;
-use crate::segment::{Segment, adjust_line_length};
+use crate::segment::{Segment, adjust_line_length, segments_to_plain, split_lines};
 use crate::style::Style;
 use crate::text::{JustifyMethod, OverflowMethod, Text};
 
 use super::padding::PaddingDimensions;
 
 /// A bordered panel containing content.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct Panel<'a> {
     /// Content lines to render inside the panel.
     content_lines: Vec<Vec<Segment<'a>>>,
@@ -43,6 +47,41 @@ pub struct Panel<'a> {
     subtitle: Option<Text>,
     /// Subtitle alignment.
     subtitle_align: JustifyMethod,
+    /// Renderable drawn as the title, taking precedence over `title`.
+    title_renderable: Option<Arc<dyn Renderable + Send + Sync>>,
+    /// Renderable drawn as the subtitle, taking precedence over `subtitle`.
+    subtitle_renderable: Option<Arc<dyn Renderable + Send + Sync>>,
+    /// Draw a drop shadow to the right of and below the box.
+    shadow: bool,
+}
+
+impl fmt::Debug for Panel<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Panel")
+            .field("content_lines", &self.content_lines)
+            .field("box_style", &self.box_style)
+            .field("safe_box", &self.safe_box)
+            .field("expand", &self.expand)
+            .field("style", &self.style)
+            .field("border_style", &self.border_style)
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("padding", &self.padding)
+            .field("title", &self.title)
+            .field("title_align", &self.title_align)
+            .field("subtitle", &self.subtitle)
+            .field("subtitle_align", &self.subtitle_align)
+            .field(
+                "title_renderable",
+                &self.title_renderable.as_ref().map(|_| "<renderable>"),
+            )
+            .field(
+                "subtitle_renderable",
+                &self.subtitle_renderable.as_ref().map(|_| "<renderable>"),
+            )
+            .field("shadow", &self.shadow)
+            .finish()
+    }
 }
 
 impl Default for Panel<'_> {
@@ -61,6 +100,9 @@ impl Default for Panel<'_> {
             title_align: JustifyMethod::Center,
             subtitle: None,
             subtitle_align: JustifyMethod::Center,
+            title_renderable: None,
+            subtitle_renderable: None,
+            shadow: false,
         }
     }
 }
@@ -157,6 +199,16 @@ impl<'a> Panel<'a> {
         self
     }
 
+    /// Draw a dim drop shadow one cell to the right of and below the box.
+    ///
+    /// The shadow is clipped where it would exceed the available width, so an
+    /// expanded panel only gets the bottom shadow line.
+    #[must_use]
+    pub fn shadow(mut self, shadow: bool) -> Self {
+        self.shadow = shadow;
+        self
+    }
+
     /// Set the background style.
     #[must_use]
     pub fn style(mut self, style: Style) -> Self {
@@ -203,6 +255,19 @@ impl<'a> Panel<'a> {
         self
     }
 
+    /// Set the title to any renderable, e.g. a badge or text with links.
+    ///
+    /// The first line of its output is drawn inline on the top border and
+    /// truncated to fit, like a text title. It is rendered with the printing
+    /// console when the panel goes through [`Renderable::render`] (e.g.
+    /// [`Console::print_renderable`]), and with a default console by
+    /// [`Panel::render`].
+    #[must_use]
+    pub fn title_renderable(mut self, title: Box<dyn Renderable + Send + Sync>) -> Self {
+        self.title_renderable = Some(Arc::from(title));
+        self
+    }
+
     /// Set title alignment.
     #[must_use]
     pub fn title_align(mut self, align: JustifyMethod) -> Self {
@@ -239,6 +304,15 @@ impl<'a> Panel<'a> {
         self
     }
 
+    /// Set the subtitle to any renderable, drawn on the bottom border.
+    ///
+    /// See [`Panel::title_renderable`].
+    #[must_use]
+    pub fn subtitle_renderable(mut self, subtitle: Box<dyn Renderable + Send + Sync>) -> Self {
+        self.subtitle_renderable = Some(Arc::from(subtitle));
+        self
+    }
+
     /// Set subtitle alignment.
     #[must_use]
     pub fn subtitle_align(mut self, align: JustifyMethod) -> Self {
@@ -283,9 +357,52 @@ impl<'a> Panel<'a> {
             .unwrap_or(0)
     }
 
+    /// Copy of the panel with renderable titles replaced by their first line.
+    fn with_rendered_titles(&self, console: &Console, width: usize) -> Self {
+        let first_line = |renderable: &Arc<dyn Renderable + Send + Sync>| {
+            let options = console.options().update_width(width.max(1));
+            let rendered = renderable.render(console, &options);
+            let mut text = Text::new("");
+            for segment in split_lines(rendered.into_iter())
+                .into_iter()
+                .next()
+                .unwrap_or_default()
+            {
+                if segment.is_control() {
+                    continue;
+                }
+                match segment.style {
+                    Some(style) => text.append_styled(&segment.text, style),
+                    None => text.append(&segment.text),
+                }
+            }
+            text
+        };
+
+        let mut panel = self.clone();
+        if let Some(title) = panel.title_renderable.take() {
+            panel.title = Some(first_line(&title));
+        }
+        if let Some(subtitle) = panel.subtitle_renderable.take() {
+            panel.subtitle = Some(first_line(&subtitle));
+        }
+        panel
+    }
+
     /// Render the panel to segments.
+    ///
+    /// Renderable titles and subtitles are rendered with a default console
+    /// here; go through [`Renderable::render`] to use the printing console's
+    /// theme and settings instead.
     #[must_use]
     pub fn render(&self, max_width: usize) -> Vec<Segment<'a>> {
+        if self.title_renderable.is_some() || self.subtitle_renderable.is_some() {
+            let console = Console::builder().width(max_width).build();
+            return self
+                .with_rendered_titles(&console, max_width)
+                .render(max_width);
+        }
+
         let box_chars = self.effective_box();
 
         // Calculate panel width
@@ -442,9 +559,51 @@ impl<'a> Panel<'a> {
         segments.extend(self.render_bottom_border(box_chars, inner_width));
         segments.push(Segment::line());
 
+        if self.shadow {
+            return Self::add_shadow(segments, box_chars, panel_width, max_width);
+        }
         segments
     }
 
+    /// Append a shadow column to each box line after the first, plus a
+    /// shadow line offset one cell to the right below the box.
+    fn add_shadow(
+        segments: Vec<Segment<'a>>,
+        box_chars: &BoxChars,
+        panel_width: usize,
+        max_width: usize,
+    ) -> Vec<Segment<'a>> {
+        let shade = if box_chars.ascii { " " } else { "\u{2591}" };
+        let shadow_style = Some(Style::new().dim().bgcolor(Color::from_ansi(0)));
+        let has_column = panel_width < max_width;
+
+        let mut lines = split_lines(segments.into_iter());
+        if lines.last().is_some_and(Vec::is_empty) {
+            lines.pop();
+        }
+
+        let mut output = Vec::new();
+        for (index, line) in lines.into_iter().enumerate() {
+            output.extend(line);
+            if has_column {
+                if index == 0 {
+                    output.push(Segment::new(" ", None));
+                } else {
+                    output.push(Segment::new(shade, shadow_style.clone()));
+                }
+            }
+            output.push(Segment::line());
+        }
+
+        let bottom_width = (panel_width + 1).min(max_width);
+        if bottom_width > 1 {
+            output.push(Segment::new(" ", None));
+            output.push(Segment::new(shade.repeat(bottom_width - 1), shadow_style));
+            output.push(Segment::line());
+        }
+        output
+    }
+
     /// Render the top border with optional title.
     fn render_top_border(&self, box_chars: &BoxChars, inner_width: usize) -> Vec<Segment<'a>> {
         let border_style = Some(self.border_style.clone());
@@ -663,21 +822,17 @@ impl<'a> Panel<'a> {
     /// Render to plain text.
     #[must_use]
     pub fn render_plain(&self, max_width: usize) -> String {
-        self.render(max_width)
-            .into_iter()
-            .map(|seg| seg.text.into_owned())
-            .collect()
+        segments_to_plain(&self.render(max_width))
     }
 }
 
 impl Renderable for Panel<'_> {
     fn render<'b>(&'b self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'b>> {
-        if self.safe_box.is_some() {
-            return self.render(options.max_width).into_iter().collect();
+        let mut effective = self.with_rendered_titles(console, options.max_width);
+        if effective.safe_box.is_none() {
+            // Inherit the Console's safe_box setting unless explicitly overridden.
+            effective.safe_box = Some(console.safe_box() || !console.supports_unicode());
         }
-
-        // Inherit the Console's safe_box setting unless explicitly overridden.
-        let effective = self.clone().safe_box(console.safe_box());
         effective.render(options.max_width).into_iter().collect()
     }
 }
@@ -707,6 +862,32 @@ mod tests {
         assert_eq!(panel.content_lines.len(), 2);
     }
 
+    #[test]
+    fn test_panel_shadow() {
+        let panel = Panel::from_text("Hi").expand(false).shadow(true);
+        assert_eq!(
+            panel.render_plain(20),
+            "╭────╮ \n\
+             │ Hi │░\n\
+             ╰────╯░\n \
+             ░░░░░░\n"
+        );
+        let shadow = panel
+            .render(20)
+            .into_iter()
+            .find(|seg| seg.text == "░")
+            .expect("shadow cell");
+        assert!(
+            shadow
+                .style
+                .is_some_and(|s| s.attributes.contains(Attributes::DIM))
+        );
+
+        // At the edge the shadow column is clipped; the bottom line still fits.
+        let clipped = panel.render_plain(6);
+        assert_eq!(clipped, "╭────╮\n│ Hi │\n╰────╯\n ░░░░░\n");
+    }
+
     #[test]
     fn test_panel_render() {
         let panel = Panel::from_text("Hello").width(20);
@@ -841,6 +1022,79 @@ mod tests {
         assert_eq!(truncated.plain(), "He...");
     }
 
+    #[test]
+    fn test_panel_title_renderable_on_top_border() {
+        struct Badge;
+
+        impl Renderable for Badge {
+            fn render<'b>(&'b self, console: &Console, _: &ConsoleOptions) -> Vec<Segment<'b>> {
+                vec![Segment::new("new", Some(console.get_style("badge")))]
+            }
+        }
+
+        let red = Style::parse("red").unwrap();
+        let blue = Style::parse("blue").unwrap();
+        let mut title = Text::new("");
+        title.append_styled("v1.2", red.clone());
+        title.append_styled(" beta", blue.clone());
+
+        let console = Console::builder().width(30).build();
+        let render = |panel: &Panel| -> Vec<Segment<'static>> {
+            Renderable::render(panel, &console, &console.options())
+                .into_iter()
+                .map(Segment::into_owned)
+                .collect()
+        };
+        let panel = Panel::from_text("Content")
+            .title_renderable(Box::new(title))
+            .width(30);
+        let lines = split_lines(render(&panel).into_iter());
+        let top = &lines[0];
+
+        let styled = |text: &str, style: &Style| {
+            top.iter()
+                .any(|seg| seg.text.contains(text) && seg.style.as_ref() == Some(style))
+        };
+        assert!(styled("v1.2", &red));
+        assert!(styled("beta", &blue));
+        assert_eq!(cells::cell_len(&segments_to_plain(top)), 30);
+
+        // Titles wider than the border are truncated.
+        let narrow = Panel::from_text("x")
+            .title_renderable(Box::new(Text::new("a very long title")))
+            .width(12);
+        let top = segments_to_plain(&split_lines(render(&narrow).into_iter())[0]);
+        assert_eq!(cells::cell_len(&top), 12);
+        assert!(top.contains("..."));
+
+        // The title is rendered with the printing console's theme.
+        let badge = Style::parse("bold magenta").unwrap();
+        let theme_styles = std::collections::HashMap::from([("badge".to_string(), badge.clone())]);
+        let themed = Console::builder()
+            .width(30)
+            .theme(crate::theme::Theme::new(Some(theme_styles), true))
+            .build();
+        let panel = Panel::from_text("x")
+            .title_renderable(Box::new(Badge))
+            .width(30);
+        let lines = split_lines(Renderable::render(&panel, &themed, &themed.options()).into_iter());
+        assert!(
+            lines[0]
+                .iter()
+                .any(|seg| seg.text.contains("new") && seg.style.as_ref() == Some(&badge))
+        );
+
+        // Without a console the title is rendered with a default one.
+        let plain = Panel::from_text("x")
+            .title_renderable(Box::new(Text::new("badge")))
+            .subtitle_renderable(Box::new(Text::new("footer")))
+            .render_plain(30);
+        assert!(
+            plain.contains("badge") && plain.contains("footer"),
+            "{plain}"
+        );
+    }
+
     #[test]
     fn test_panel_title_preserves_spans() {
         let mut title = Text::new("AB");
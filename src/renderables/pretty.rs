@@ -198,8 +198,7 @@ impl<T: Debug + ?Sized> Renderable for Inspect<'_, T> {
 
         if self.options.show_type {
             let type_name = any::type_name_of_val(self.value);
-            let header =
-                Text::assemble(&[("Type: ", Some(Style::new().bold())), (type_name, None)]);
+            let header = Text::assemble([("Type: ", Some(Style::new().bold())), (type_name, None)]);
             output.extend(header.render("").into_iter().map(Segment::into_owned));
             output.push(Segment::line());
         }
@@ -4,12 +4,18 @@
 //! in the terminal with various styles and features.
 
 use crate::cells;
-use crate::console::{Console, ConsoleOptions};
+use crate::color::ColorSystem;
+use crate::console::{Clock, Console, ConsoleOptions, SystemClock};
 use crate::filesize::{self, SizeUnit, binary, binary_speed, decimal, decimal_speed};
+use crate::live::{Live, LiveOptions};
 use crate::renderables::Renderable;
-use crate::segment::Segment;
+use crate::segment::{Segment, segments_to_plain};
 use crate::style::Style;
+use crate::sync::lock_recover;
 use crate::text::Text;
+use std::collections::VecDeque;
+use std::fmt::Write as _;
+use std::sync::{Arc, Mutex};
 use std::time::{Duration, Instant};
 
 /// Bar style variants for the progress bar.
@@ -66,6 +72,16 @@ impl BarStyle {
     }
 }
 
+/// Glyph shown by a [`Spinner`] once it has been finished.
+const SPINNER_DONE: &str = "\u{2713}"; // ✓
+/// Frames and done marker used when the console can't display Unicode.
+const SPINNER_ASCII_FRAMES: [&str; 4] = ["|", "/", "-", "\\"];
+const SPINNER_ASCII_DONE: &str = "+";
+/// Time per frame for [`Spinner::frame_at`] at normal speed.
+const SPINNER_INTERVAL: Duration = Duration::from_millis(80);
+/// Fastest speed multiplier reachable via [`Spinner::speed_from_rate`].
+const SPINNER_MAX_SPEED: f64 = 4.0;
+
 /// Spinner animation frames.
 #[derive(Debug, Clone)]
 pub struct Spinner {
@@ -75,6 +91,16 @@ pub struct Spinner {
     frame_index: usize,
     /// Style for the spinner.
     style: Style,
+    /// Chained phases as (spinner, frames before switching to the next phase).
+    phases: Vec<(Spinner, usize)>,
+    /// Index of the active phase in `phases`.
+    phase: usize,
+    /// Frames shown so far in the active phase.
+    phase_frames: usize,
+    /// Whether the spinner has completed and shows a static checkmark.
+    finished: bool,
+    /// Multiplier on the frame rate used by [`Spinner::frame_at`].
+    speed: f64,
 }
 
 impl Default for Spinner {
@@ -87,73 +113,45 @@ impl Spinner {
     /// Create a dots spinner (⠋⠙⠹⠸⠼⠴⠦⠧⠇⠏).
     #[must_use]
     pub fn dots() -> Self {
-        Self {
-            frames: vec!["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
-            frame_index: 0,
-            style: Style::new(),
-        }
+        Self::custom(vec!["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"])
     }
 
     /// Create a line spinner (⎺⎻⎼⎽⎼⎻).
     #[must_use]
     pub fn line() -> Self {
-        Self {
-            frames: vec!["⎺", "⎻", "⎼", "⎽", "⎼", "⎻"],
-            frame_index: 0,
-            style: Style::new(),
-        }
+        Self::custom(vec!["⎺", "⎻", "⎼", "⎽", "⎼", "⎻"])
     }
 
     /// Create a simple spinner (|/-\).
     #[must_use]
     pub fn simple() -> Self {
-        Self {
-            frames: vec!["|", "/", "-", "\\"],
-            frame_index: 0,
-            style: Style::new(),
-        }
+        Self::custom(vec!["|", "/", "-", "\\"])
     }
 
     /// Create a bouncing ball spinner (⠁⠂⠄⠂).
     #[must_use]
     pub fn bounce() -> Self {
-        Self {
-            frames: vec!["⠁", "⠂", "⠄", "⠂"],
-            frame_index: 0,
-            style: Style::new(),
-        }
+        Self::custom(vec!["⠁", "⠂", "⠄", "⠂"])
     }
 
     /// Create a growing dots spinner (⣾⣽⣻⢿⡿⣟⣯⣷).
     #[must_use]
     pub fn growing() -> Self {
-        Self {
-            frames: vec!["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"],
-            frame_index: 0,
-            style: Style::new(),
-        }
+        Self::custom(vec!["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"])
     }
 
     /// Create a moon phase spinner (🌑🌒🌓🌔🌕🌖🌗🌘).
     #[must_use]
     pub fn moon() -> Self {
-        Self {
-            frames: vec!["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"],
-            frame_index: 0,
-            style: Style::new(),
-        }
+        Self::custom(vec!["🌑", "🌒", "🌓", "🌔", "🌕", "🌖", "🌗", "🌘"])
     }
 
     /// Create a clock spinner (🕐🕑🕒🕓🕔🕕🕖🕗🕘🕙🕚🕛).
     #[must_use]
     pub fn clock() -> Self {
-        Self {
-            frames: vec![
-                "🕐", "🕑", "🕒", "🕓", "🕔", "🕕", "🕖", "🕗", "🕘", "🕙", "🕚", "🕛",
-            ],
-            frame_index: 0,
-            style: Style::new(),
-        }
+        Self::custom(vec![
+            "🕐", "🕑", "🕒", "🕓", "🕔", "🕕", "🕖", "🕗", "🕘", "🕙", "🕚", "🕛",
+        ])
     }
 
     /// Create a spinner from custom frames.
@@ -163,9 +161,63 @@ impl Spinner {
             frames,
             frame_index: 0,
             style: Style::new(),
+            phases: Vec::new(),
+            phase: 0,
+            phase_frames: 0,
+            finished: false,
+            speed: 1.0,
         }
     }
 
+    /// Chain spinners into one multi-phase animation.
+    ///
+    /// Each phase shows its spinner's frames (and style) for the given number
+    /// of frames before switching to the next phase. The last phase loops
+    /// until [`Spinner::finish`] is called.
+    ///
+    /// ```
+    /// use rich_rust::renderables::Spinner;
+    ///
+    /// let mut spinner = Spinner::chain(vec![(Spinner::simple(), 2), (Spinner::bounce(), 0)]);
+    /// assert_eq!(spinner.next_frame(), "|");
+    /// assert_eq!(spinner.next_frame(), "/");
+    /// assert_eq!(spinner.next_frame(), "⠁");
+    /// ```
+    #[must_use]
+    pub fn chain(phases: Vec<(Spinner, usize)>) -> Self {
+        let Some((first, _)) = phases.first() else {
+            return Self::custom(Vec::new());
+        };
+        let mut spinner = Self::custom(first.frames.clone()).style(first.style.clone());
+        spinner.phases = phases;
+        spinner
+    }
+
+    /// Switch to the chained phase at `index`, restarting its animation.
+    ///
+    /// Out-of-range indices are ignored.
+    pub fn set_active(&mut self, index: usize) {
+        let Some((phase, _)) = self.phases.get(index) else {
+            return;
+        };
+        self.frames.clone_from(&phase.frames);
+        self.style = phase.style.clone();
+        self.frame_index = 0;
+        self.phase = index;
+        self.phase_frames = 0;
+    }
+
+    /// Stop animating and show a static `✓`.
+    pub fn finish(&mut self) {
+        self.finished = true;
+    }
+
+    /// Whether [`Spinner::finish`] has been called.
+    #[must_use]
+    pub fn is_finished(&self) -> bool {
+        self.finished
+    }
+
     /// Set the spinner style.
     #[must_use]
     pub fn style(mut self, style: Style) -> Self {
@@ -175,32 +227,122 @@ impl Spinner {
 
     /// Advance to the next frame and return the current frame.
     pub fn next_frame(&mut self) -> &'static str {
+        if self.finished {
+            return SPINNER_DONE;
+        }
         if self.frames.is_empty() {
             return " ";
         }
         let frame = self.frames[self.frame_index];
         self.frame_index = (self.frame_index + 1) % self.frames.len();
+        self.phase_frames += 1;
+        if let Some((_, limit)) = self.phases.get(self.phase)
+            && self.phase + 1 < self.phases.len()
+            && self.phase_frames >= *limit
+        {
+            self.set_active(self.phase + 1);
+        }
         frame
     }
 
     /// Get the current frame without advancing.
     #[must_use]
     pub fn current_frame(&self) -> &'static str {
+        if self.finished {
+            return SPINNER_DONE;
+        }
         if self.frames.is_empty() {
             return " ";
         }
         self.frames[self.frame_index]
     }
 
+    /// Scale the spin speed of [`Spinner::frame_at`] with throughput.
+    ///
+    /// Idle tasks spin at half speed; every tenfold increase in
+    /// `items_per_sec` adds one times the normal speed, up to four times.
+    /// Feed it a progress bar's recent rate to show how busy a task is:
+    ///
+    /// ```
+    /// use rich_rust::renderables::{ProgressBar, Spinner};
+    ///
+    /// let bar = ProgressBar::new();
+    /// let mut spinner = Spinner::dots();
+    /// spinner.speed_from_rate(bar.speed().unwrap_or(0.0));
+    /// ```
+    pub fn speed_from_rate(&mut self, items_per_sec: f64) {
+        // NaN maps to idle; infinity saturates at the top speed below.
+        let rate = if items_per_sec.is_nan() {
+            0.0
+        } else {
+            items_per_sec.max(0.0)
+        };
+        self.speed = (0.5 + (1.0 + rate).log10()).min(SPINNER_MAX_SPEED);
+    }
+
+    /// Frames advanced after `elapsed`, at the current speed.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    fn frames_elapsed(&self, elapsed: Duration) -> usize {
+        (elapsed.as_secs_f64() * self.speed / SPINNER_INTERVAL.as_secs_f64()) as usize
+    }
+
+    /// Get the frame shown `elapsed` after the spinner started.
+    ///
+    /// Frames advance every 80ms, scaled by [`Spinner::speed_from_rate`].
+    #[must_use]
+    pub fn frame_at(&self, elapsed: Duration) -> &'static str {
+        if self.finished {
+            return SPINNER_DONE;
+        }
+        if self.frames.is_empty() {
+            return " ";
+        }
+        self.frames[self.frames_elapsed(elapsed) % self.frames.len()]
+    }
+
     /// Render the current spinner frame as a segment.
     #[must_use]
     pub fn render(&self) -> Segment<'static> {
         Segment::new(self.current_frame(), Some(self.style.clone()))
     }
+
+    /// The current frame, swapped for an ASCII one when it isn't ASCII.
+    fn ascii_frame(&self) -> &'static str {
+        let frame = self.current_frame();
+        if frame.is_ascii() {
+            frame
+        } else if self.finished {
+            SPINNER_ASCII_DONE
+        } else {
+            SPINNER_ASCII_FRAMES[self.frame_index % SPINNER_ASCII_FRAMES.len()]
+        }
+    }
 }
 
-/// A progress bar with percentage, ETA, and customizable appearance.
+impl Renderable for Spinner {
+    fn render<'a>(&'a self, console: &Console, _options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        if console.supports_unicode() {
+            return vec![self.render()];
+        }
+        vec![Segment::new(self.ascii_frame(), Some(self.style.clone()))]
+    }
+}
+
+/// One weighted slice of a stacked progress bar.
 #[derive(Debug, Clone)]
+struct BarSegment {
+    /// Relative share of the bar width.
+    weight: f64,
+    /// Style for the completed portion of this slice.
+    style: Style,
+    /// Progress within this slice (0.0 - 1.0).
+    progress: f64,
+}
+
+type SuffixFormatter = Arc<dyn Fn(&ProgressBar) -> String + Send + Sync>;
+
+/// A progress bar with percentage, ETA, and customizable appearance.
+#[derive(Clone)]
 pub struct ProgressBar {
     /// Current progress (0.0 - 1.0).
     completed: f64,
@@ -226,14 +368,22 @@ pub struct ProgressBar {
     show_elapsed: bool,
     /// Show speed (items/sec).
     show_speed: bool,
+    /// Show `current/total` item counts.
+    show_count: bool,
+    /// Unit label appended to item counts (e.g. "files").
+    unit: Option<String>,
     /// Task description.
     description: Option<Text>,
     /// Start time for ETA calculation.
     start_time: Option<Instant>,
+    /// When the task finished; freezes elapsed time and average speed.
+    finished_at: Option<Instant>,
     /// Whether to show brackets around the bar.
     show_brackets: bool,
     /// Finished message (replaces bar when complete).
     finished_message: Option<String>,
+    /// Replace the bar with a time/rate summary when complete.
+    summary_on_finish: bool,
     /// Whether the task is complete.
     is_finished: bool,
     /// Total bytes for file transfer (optional).
@@ -246,6 +396,58 @@ pub struct ProgressBar {
     show_transfer_speed: bool,
     /// Use binary (1024-based) units for file sizes, or decimal (1000-based).
     use_binary_units: bool,
+    /// Weighted sub-task slices (empty for a single bar).
+    segments: Vec<BarSegment>,
+    /// Custom trailing text, replacing the built-in suffix columns.
+    suffix_formatter: Option<SuffixFormatter>,
+    /// Span of recent updates used for speed and ETA (`None` = since start).
+    rate_window: Option<Duration>,
+    /// `(time, current)` samples inside the rate window, oldest first, plus
+    /// the last one before it as a baseline.
+    samples: VecDeque<(Instant, u64)>,
+    /// Time source for elapsed time, speed, and ETA.
+    clock: Arc<dyn Clock>,
+}
+
+impl std::fmt::Debug for ProgressBar {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProgressBar")
+            .field("completed", &self.completed)
+            .field("total", &self.total)
+            .field("current", &self.current)
+            .field("width", &self.width)
+            .field("bar_style", &self.bar_style)
+            .field("completed_style", &self.completed_style)
+            .field("remaining_style", &self.remaining_style)
+            .field("pulse_style", &self.pulse_style)
+            .field("show_percentage", &self.show_percentage)
+            .field("show_eta", &self.show_eta)
+            .field("show_elapsed", &self.show_elapsed)
+            .field("show_speed", &self.show_speed)
+            .field("show_count", &self.show_count)
+            .field("unit", &self.unit)
+            .field("description", &self.description)
+            .field("start_time", &self.start_time)
+            .field("finished_at", &self.finished_at)
+            .field("show_brackets", &self.show_brackets)
+            .field("finished_message", &self.finished_message)
+            .field("summary_on_finish", &self.summary_on_finish)
+            .field("is_finished", &self.is_finished)
+            .field("total_bytes", &self.total_bytes)
+            .field("transferred_bytes", &self.transferred_bytes)
+            .field("show_file_size", &self.show_file_size)
+            .field("show_transfer_speed", &self.show_transfer_speed)
+            .field("use_binary_units", &self.use_binary_units)
+            .field("segments", &self.segments)
+            .field(
+                "suffix_formatter",
+                &self.suffix_formatter.as_ref().map(|_| "<formatter>"),
+            )
+            .field("rate_window", &self.rate_window)
+            .field("samples", &self.samples.len())
+            .field("clock", &"<dyn Clock>")
+            .finish()
+    }
 }
 
 impl Default for ProgressBar {
@@ -263,16 +465,25 @@ impl Default for ProgressBar {
             show_eta: false,
             show_elapsed: false,
             show_speed: false,
+            show_count: false,
+            unit: None,
             description: None,
             start_time: None,
+            finished_at: None,
             show_brackets: true,
             finished_message: None,
+            summary_on_finish: false,
             is_finished: false,
             total_bytes: None,
             transferred_bytes: 0,
             show_file_size: false,
             show_transfer_speed: false,
             use_binary_units: false,
+            segments: Vec::new(),
+            suffix_formatter: None,
+            rate_window: None,
+            samples: VecDeque::new(),
+            clock: Arc::new(SystemClock),
         }
     }
 }
@@ -342,7 +553,7 @@ impl ProgressBar {
     pub fn show_eta(mut self, show: bool) -> Self {
         self.show_eta = show;
         if show && self.start_time.is_none() {
-            self.start_time = Some(Instant::now());
+            self.start_time = Some(self.clock.now());
         }
         self
     }
@@ -352,7 +563,7 @@ impl ProgressBar {
     pub fn show_elapsed(mut self, show: bool) -> Self {
         self.show_elapsed = show;
         if show && self.start_time.is_none() {
-            self.start_time = Some(Instant::now());
+            self.start_time = Some(self.clock.now());
         }
         self
     }
@@ -362,11 +573,92 @@ impl ProgressBar {
     pub fn show_speed(mut self, show: bool) -> Self {
         self.show_speed = show;
         if show && self.start_time.is_none() {
-            self.start_time = Some(Instant::now());
+            self.start_time = Some(self.clock.now());
         }
         self
     }
 
+    /// Set whether to show `current/total` item counts (e.g. `1,234/10,000`).
+    #[must_use]
+    pub fn show_count(mut self, show: bool) -> Self {
+        self.show_count = show;
+        self
+    }
+
+    /// Set the unit label shown after item counts (e.g. "files").
+    #[must_use]
+    pub fn unit(mut self, unit: impl Into<String>) -> Self {
+        self.unit = Some(unit.into());
+        self
+    }
+
+    /// Replace the built-in suffix (percentage, ETA, speed, ...) with custom text.
+    ///
+    /// The formatter receives the bar and returns the text placed after it,
+    /// e.g. `"42/100 pkgs • 3.2/s"`. The bar shrinks to make room for it.
+    #[must_use]
+    pub fn suffix_formatter(
+        mut self,
+        formatter: Box<dyn Fn(&ProgressBar) -> String + Send + Sync>,
+    ) -> Self {
+        self.suffix_formatter = Some(Arc::from(formatter));
+        self
+    }
+
+    /// Compute speed and ETA from the updates within `window` only.
+    ///
+    /// By default both are averaged over the whole run, so a transfer that
+    /// stalls keeps showing its earlier rate. With a window they follow
+    /// recent throughput instead.
+    #[must_use]
+    pub fn rate_window(mut self, window: Duration) -> Self {
+        self.rate_window = Some(window);
+        self
+    }
+
+    /// Record a `(time, current)` sample and drop those outside the window,
+    /// keeping the newest older sample so a stall still spans two samples.
+    fn record_sample(&mut self, at: Instant) {
+        let Some(window) = self.rate_window else {
+            return;
+        };
+        self.samples.push_back((at, self.current));
+        if let Some(cutoff) = at.checked_sub(window) {
+            while self.samples.get(1).is_some_and(|&(time, _)| time <= cutoff) {
+                self.samples.pop_front();
+            }
+        }
+    }
+
+    /// Items per second across the samples in the rate window.
+    fn windowed_speed(&self) -> Option<f64> {
+        let &(first_time, first_count) = self.samples.front()?;
+        let &(last_time, last_count) = self.samples.back()?;
+        let span = last_time.duration_since(first_time).as_secs_f64();
+        if span <= 0.0 {
+            return None;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        Some(last_count.saturating_sub(first_count) as f64 / span)
+    }
+
+    /// `current/total unit`, with `current` padded to the width of `total`.
+    fn format_count(&self) -> String {
+        let current = group_thousands(self.current);
+        let mut count = match self.total {
+            Some(total) => {
+                let total = group_thousands(total);
+                format!("{current:>width$}/{total}", width = total.len())
+            }
+            None => current,
+        };
+        if let Some(ref unit) = self.unit {
+            count.push(' ');
+            count.push_str(unit);
+        }
+        count
+    }
+
     /// Set the task description.
     ///
     /// Passing a `&str` uses `Text::new()` and does **NOT** parse markup.
@@ -394,17 +686,86 @@ impl ProgressBar {
         self
     }
 
+    /// Set the time source for elapsed time, speed, and ETA.
+    ///
+    /// Defaults to [`SystemClock`]; inject a fake clock for deterministic
+    /// tests. An already started timer restarts at the new clock's `now()`.
+    #[must_use]
+    pub fn clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        if self.start_time.is_some() {
+            self.start_time = Some(clock.now());
+        }
+        self.samples.clear();
+        self.clock = clock;
+        self
+    }
+
+    /// Set whether a finished bar is replaced by a one-line summary.
+    ///
+    /// The summary reads `✓ Done in 1:23 (avg 4.2/s)`, using the
+    /// [`finished_message`](Self::finished_message) in place of `Done` when
+    /// one is set.
+    #[must_use]
+    pub fn summary_on_finish(mut self, summary: bool) -> Self {
+        self.summary_on_finish = summary;
+        if summary && self.start_time.is_none() {
+            self.start_time = Some(self.clock.now());
+        }
+        self
+    }
+
+    /// Split the bar into weighted sub-task segments.
+    ///
+    /// Each `(weight, style)` pair gets a share of the bar width proportional
+    /// to its weight and is filled independently via
+    /// [`set_segment_progress`](Self::set_segment_progress). Negative weights
+    /// are treated as zero.
+    #[must_use]
+    pub fn segments(mut self, segments: Vec<(f64, Style)>) -> Self {
+        self.segments = segments
+            .into_iter()
+            .map(|(weight, style)| BarSegment {
+                weight: weight.max(0.0),
+                style,
+                progress: 0.0,
+            })
+            .collect();
+        self
+    }
+
+    /// Update the progress (0.0 - 1.0) of the segment at `index`.
+    ///
+    /// Overall progress becomes the weighted average of all segments.
+    /// Out-of-range indices are ignored.
+    pub fn set_segment_progress(&mut self, index: usize, progress: f64) {
+        let Some(segment) = self.segments.get_mut(index) else {
+            return;
+        };
+        segment.progress = progress.clamp(0.0, 1.0);
+
+        let total_weight: f64 = self.segments.iter().map(|s| s.weight).sum();
+        if total_weight > 0.0 {
+            let done: f64 = self.segments.iter().map(|s| s.weight * s.progress).sum();
+            self.set_progress(done / total_weight);
+        }
+    }
+
     /// Update progress directly (0.0 - 1.0).
     pub fn set_progress(&mut self, progress: f64) {
         self.completed = progress.clamp(0.0, 1.0);
         if self.completed >= 1.0 {
-            self.is_finished = true;
+            self.mark_finished(self.clock.now());
         }
     }
 
     /// Update progress with current/total counts.
     pub fn update(&mut self, current: u64) {
+        self.update_at(current, self.clock.now());
+    }
+
+    fn update_at(&mut self, current: u64, at: Instant) {
         self.current = current;
+        self.record_sample(at);
         if let Some(total) = self.total
             && total > 0
         {
@@ -415,7 +776,7 @@ impl ProgressBar {
             self.completed = self.completed.clamp(0.0, 1.0);
         }
         if self.completed >= 1.0 {
-            self.is_finished = true;
+            self.mark_finished(at);
         }
     }
 
@@ -427,7 +788,13 @@ impl ProgressBar {
     /// Mark the progress bar as finished.
     pub fn finish(&mut self) {
         self.completed = 1.0;
+        self.mark_finished(self.clock.now());
+    }
+
+    /// Flag the bar finished, recording the first time it got there.
+    fn mark_finished(&mut self, at: Instant) {
         self.is_finished = true;
+        self.finished_at.get_or_insert(at);
     }
 
     /// Get the current progress (0.0 - 1.0).
@@ -436,25 +803,50 @@ impl ProgressBar {
         self.completed
     }
 
+    /// Get the current item count.
+    #[must_use]
+    pub fn current(&self) -> u64 {
+        self.current
+    }
+
+    /// Get the total item count, if known.
+    #[must_use]
+    pub fn total(&self) -> Option<u64> {
+        self.total
+    }
+
     /// Check if the progress bar is finished.
     #[must_use]
     pub fn is_finished(&self) -> bool {
         self.is_finished
     }
 
-    /// Get the elapsed time since start.
+    /// Get the elapsed time since start, stopping once the bar finishes.
     #[must_use]
     pub fn elapsed(&self) -> Option<Duration> {
-        self.start_time.map(|start| start.elapsed())
+        let now = self.finished_at.unwrap_or_else(|| self.clock.now());
+        self.start_time
+            .map(|start| now.saturating_duration_since(start))
     }
 
     /// Calculate estimated time remaining.
+    ///
+    /// Uses the recent rate when a [`rate_window`](Self::rate_window) is set.
     #[must_use]
     pub fn eta(&self) -> Option<Duration> {
         if self.completed <= 0.0 || self.completed >= 1.0 {
             return None;
         }
 
+        if let (Some(speed), Some(total)) = (self.windowed_speed(), self.total) {
+            if speed <= 0.0 {
+                return None;
+            }
+            #[allow(clippy::cast_precision_loss)]
+            let remaining = total.saturating_sub(self.current) as f64;
+            return Some(Duration::from_secs_f64(remaining / speed));
+        }
+
         let elapsed = self.elapsed()?;
         let elapsed_secs = elapsed.as_secs_f64();
         if elapsed_secs < 0.1 {
@@ -468,8 +860,13 @@ impl ProgressBar {
     }
 
     /// Calculate items per second.
+    ///
+    /// Uses the recent rate when a [`rate_window`](Self::rate_window) is set.
     #[must_use]
     pub fn speed(&self) -> Option<f64> {
+        if let Some(speed) = self.windowed_speed() {
+            return Some(speed);
+        }
         let elapsed = self.elapsed()?;
         let elapsed_secs = elapsed.as_secs_f64();
         if elapsed_secs < 0.1 {
@@ -530,7 +927,7 @@ impl ProgressBar {
     pub fn show_transfer_speed(mut self, show: bool) -> Self {
         self.show_transfer_speed = show;
         if show && self.start_time.is_none() {
-            self.start_time = Some(Instant::now());
+            self.start_time = Some(self.clock.now());
         }
         self
     }
@@ -548,6 +945,8 @@ impl ProgressBar {
     pub fn update_bytes(&mut self, bytes: u64) {
         self.transferred_bytes = bytes;
         self.current = bytes;
+        let now = self.clock.now();
+        self.record_sample(now);
         if let Some(total) = self.total_bytes
             && total > 0
         {
@@ -558,7 +957,7 @@ impl ProgressBar {
             self.completed = self.completed.clamp(0.0, 1.0);
         }
         if self.completed >= 1.0 {
-            self.is_finished = true;
+            self.mark_finished(now);
         }
     }
 
@@ -580,8 +979,13 @@ impl ProgressBar {
     }
 
     /// Calculate transfer speed in bytes per second.
+    ///
+    /// Uses the recent rate when a [`rate_window`](Self::rate_window) is set.
     #[must_use]
     pub fn transfer_speed(&self) -> Option<f64> {
+        if let Some(speed) = self.windowed_speed() {
+            return Some(speed);
+        }
         let elapsed = self.elapsed()?;
         let elapsed_secs = elapsed.as_secs_f64();
         if elapsed_secs < 0.1 {
@@ -648,15 +1052,54 @@ impl ProgressBar {
     #[must_use]
     pub fn render(&self, available_width: usize) -> Vec<Segment<'static>> {
         let mut segments = Vec::new();
+        self.render_to(&mut segments, available_width);
+        segments
+    }
+
+    /// Append the rendered bar to `buf`, producing the same text as
+    /// [`ProgressBar::render_plain`] without building intermediate segments.
+    ///
+    /// With `color_system` set, each styled piece is wrapped in ANSI codes
+    /// for that color system. The buffer is not cleared first, so a tight
+    /// refresh loop can reuse one `String`:
+    ///
+    /// ```
+    /// use rich_rust::renderables::ProgressBar;
+    ///
+    /// let mut bar = ProgressBar::new().width(10);
+    /// let mut frame = String::with_capacity(64);
+    /// for step in 0..=4 {
+    ///     bar.set_progress(f64::from(step) / 4.0);
+    ///     frame.clear();
+    ///     bar.render_into(&mut frame, 40, None);
+    ///     assert_eq!(frame, bar.render_plain(40));
+    /// }
+    /// ```
+    pub fn render_into(
+        &self,
+        buf: &mut String,
+        available_width: usize,
+        color_system: Option<ColorSystem>,
+    ) {
+        self.render_to(&mut StringSink { buf, color_system }, available_width);
+    }
+
+    fn render_to(&self, sink: &mut impl BarSink, available_width: usize) {
+        if self.is_finished && self.summary_on_finish {
+            let style = Style::new().color_str("green").unwrap_or_default();
+            sink.piece(&self.finish_summary(), 1, Some(&style));
+            sink.line();
+            return;
+        }
 
         // If finished and has a finished message, show that
         if self.is_finished
             && let Some(ref msg) = self.finished_message
         {
             let style = Style::new().color_str("green").unwrap_or_default();
-            segments.push(Segment::new(format!("✓ {msg}"), Some(style)));
-            segments.push(Segment::line());
-            return segments;
+            sink.piece(&format!("✓ {msg}"), 1, Some(&style));
+            sink.line();
+            return;
         }
 
         // Description
@@ -664,57 +1107,146 @@ impl ProgressBar {
         if let Some(ref desc) = self.description {
             let mut desc_text = desc.clone();
             desc_text.append(" ");
-            let desc_width = desc_text.cell_len();
-            segments.extend(
-                desc_text
-                    .render("")
-                    .into_iter()
-                    .map(super::super::segment::Segment::into_owned),
-            );
-            used_width += desc_width;
+            used_width += desc_text.cell_len();
+            sink.text(&desc_text);
         }
 
         // Calculate bar width
-        let mut suffix_parts: Vec<String> = Vec::new();
+        let mut suffix = String::new();
+        self.write_suffix(&mut suffix);
+        let suffix_width = cells::cell_len(&suffix);
+
+        let bracket_width = if self.show_brackets { 2 } else { 0 };
+        let bar_width = available_width
+            .saturating_sub(used_width)
+            .saturating_sub(suffix_width)
+            .saturating_sub(bracket_width)
+            .min(self.width);
+
+        if bar_width < 3 {
+            // Not enough space for a bar, just show percentage
+            if self.show_percentage {
+                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                let pct = (self.completed * 100.0) as u32;
+                sink.piece(&format!("{pct}%"), 1, None);
+            }
+            sink.line();
+            return;
+        }
+
+        // Render the bar
+        if self.show_brackets {
+            sink.piece("[", 1, None);
+        }
+
+        let total_weight: f64 = self.segments.iter().map(|s| s.weight).sum();
+        if total_weight > 0.0 {
+            self.render_stacked(sink, bar_width, total_weight);
+        } else {
+            self.render_single(sink, bar_width);
+        }
 
+        if self.show_brackets {
+            sink.piece("]", 1, None);
+        }
+
+        // Suffix (percentage, ETA, etc.)
+        if !suffix.is_empty() {
+            sink.piece(&suffix, 1, None);
+        }
+
+        sink.line();
+    }
+
+    /// Build the `✓ Done in 1:23 (avg 4.2/s)` line shown by
+    /// [`summary_on_finish`](Self::summary_on_finish).
+    fn finish_summary(&self) -> String {
+        let msg = self.finished_message.as_deref().unwrap_or("Done");
+        let mut out = format!("✓ {msg}");
+        if let Some(elapsed) = self.elapsed() {
+            let _ = write!(out, " in {}", Self::format_duration(elapsed));
+            let secs = elapsed.as_secs_f64();
+            if secs > 0.0 {
+                #[allow(clippy::cast_precision_loss)]
+                let avg = self.current as f64 / secs;
+                let unit = self
+                    .unit
+                    .as_deref()
+                    .map_or(String::new(), |u| format!(" {u}"));
+                let _ = write!(out, " (avg {avg:.1}{unit}/s)");
+            }
+        }
+        out
+    }
+
+    /// Write the space-prefixed suffix (percentage, ETA, etc.), if any.
+    ///
+    /// Built-in fields are padded to fixed widths (and ETA/speed reserve
+    /// their space while unknown) so the bar keeps its width across updates.
+    fn write_suffix(&self, out: &mut String) {
+        /// Cells reserved for a duration (`9:59:59`); only runs of ten
+        /// hours or more widen the suffix.
+        const DURATION_WIDTH: usize = 7;
+        /// Cells reserved for the numeric part of a speed.
+        const SPEED_WIDTH: usize = 4;
+
+        if let Some(formatter) = &self.suffix_formatter {
+            let custom = formatter(self);
+            if !custom.is_empty() {
+                out.push(' ');
+                out.push_str(&custom);
+            }
+            return;
+        }
+
+        // write! to String is infallible
         if self.show_percentage {
             #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
             let pct = (self.completed * 100.0) as u32;
-            suffix_parts.push(format!("{pct:3}%"));
+            let _ = write!(out, " {pct:3}%");
+        }
+
+        if self.show_count {
+            out.push(' ');
+            out.push_str(&self.format_count());
         }
 
         if self.show_elapsed
             && let Some(elapsed) = self.elapsed()
         {
-            suffix_parts.push(Self::format_duration(elapsed));
+            let _ = write!(out, " {:>DURATION_WIDTH$}", Self::format_duration(elapsed));
         }
 
-        if self.show_eta
-            && !self.is_finished
-            && let Some(eta) = self.eta()
-        {
-            suffix_parts.push(format!("ETA {}", Self::format_duration(eta)));
+        if self.show_eta {
+            match self.eta().filter(|_| !self.is_finished) {
+                Some(eta) => {
+                    let _ = write!(out, " ETA {:>DURATION_WIDTH$}", Self::format_duration(eta));
+                }
+                None => out.push_str(&" ".repeat(" ETA ".len() + DURATION_WIDTH)),
+            }
         }
 
-        if self.show_speed
-            && let Some(speed) = self.speed()
-        {
-            if speed >= 1.0 {
-                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-                let speed_int = speed as u64;
-                suffix_parts.push(format!("{speed_int}/s"));
-            } else {
-                suffix_parts.push(format!("{speed:.2}/s"));
+        if self.show_speed {
+            match self.speed() {
+                Some(speed) if speed >= 1.0 => {
+                    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+                    let speed_int = speed as u64;
+                    let _ = write!(out, " {speed_int:>SPEED_WIDTH$}/s");
+                }
+                Some(speed) => {
+                    let _ = write!(out, " {speed:>SPEED_WIDTH$.2}/s");
+                }
+                None => out.push_str(&" ".repeat(1 + SPEED_WIDTH + "/s".len())),
             }
         }
 
         // File size display (e.g., "1.5 MB / 10.0 MB")
         if self.show_file_size {
-            let current_size = self.format_file_size();
+            out.push(' ');
+            out.push_str(&self.format_file_size());
             if let Some(total_size) = self.format_total_size() {
-                suffix_parts.push(format!("{current_size}/{total_size}"));
-            } else {
-                suffix_parts.push(current_size);
+                out.push('/');
+                out.push_str(&total_size);
             }
         }
 
@@ -722,39 +1254,53 @@ impl ProgressBar {
         if self.show_transfer_speed
             && let Some(speed_str) = self.format_transfer_speed()
         {
-            suffix_parts.push(speed_str);
+            out.push(' ');
+            out.push_str(&speed_str);
         }
+    }
 
-        let suffix = if suffix_parts.is_empty() {
-            String::new()
-        } else {
-            format!(" {}", suffix_parts.join(" "))
-        };
-        let suffix_width = cells::cell_len(&suffix);
-
-        let bracket_width = if self.show_brackets { 2 } else { 0 };
-        let bar_width = available_width
-            .saturating_sub(used_width)
-            .saturating_sub(suffix_width)
-            .saturating_sub(bracket_width)
-            .min(self.width);
-
-        if bar_width < 3 {
-            // Not enough space for a bar, just show percentage
-            if self.show_percentage {
-                #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-                let pct = (self.completed * 100.0) as u32;
-                segments.push(Segment::new(format!("{pct}%"), None));
+    /// Render a stacked bar, apportioning `bar_width` across weighted segments.
+    fn render_stacked(&self, sink: &mut impl BarSink, bar_width: usize, total_weight: f64) {
+        // Cumulative rounding keeps the slices contiguous and summing to bar_width.
+        let mut cumulative = 0.0;
+        let mut start = 0;
+        for segment in &self.segments {
+            cumulative += segment.weight;
+            #[allow(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                clippy::cast_precision_loss
+            )]
+            let end =
+                ((cumulative / total_weight * bar_width as f64).round() as usize).min(bar_width);
+            let width = end.saturating_sub(start);
+            start = end;
+
+            #[allow(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                clippy::cast_precision_loss
+            )]
+            let filled = ((segment.progress * width as f64).floor() as usize).min(width);
+            if filled > 0 {
+                sink.piece(
+                    self.bar_style.completed_char(),
+                    filled,
+                    Some(&segment.style),
+                );
+            }
+            if width > filled {
+                sink.piece(
+                    self.bar_style.remaining_char(),
+                    width - filled,
+                    Some(&self.remaining_style),
+                );
             }
-            segments.push(Segment::line());
-            return segments;
-        }
-
-        // Render the bar
-        if self.show_brackets {
-            segments.push(Segment::new("[", None));
         }
+    }
 
+    /// Render a single completed/pulse/remaining bar.
+    fn render_single(&self, sink: &mut impl BarSink, bar_width: usize) {
         #[allow(
             clippy::cast_possible_truncation,
             clippy::cast_sign_loss,
@@ -765,11 +1311,11 @@ impl ProgressBar {
 
         // Completed portion
         if completed_width > 0 {
-            let completed_chars = self.bar_style.completed_char().repeat(completed_width);
-            segments.push(Segment::new(
-                completed_chars,
-                Some(self.completed_style.clone()),
-            ));
+            sink.piece(
+                self.bar_style.completed_char(),
+                completed_width,
+                Some(&self.completed_style),
+            );
         }
 
         // Pulse character (at the edge)
@@ -780,58 +1326,108 @@ impl ProgressBar {
         if show_pulse {
             // Replace first remaining char with pulse
             let remaining_after_pulse = remaining_width.saturating_sub(1);
-            segments.push(Segment::new(
-                self.bar_style.pulse_char(),
-                Some(self.pulse_style.clone()),
-            ));
+            sink.piece(self.bar_style.pulse_char(), 1, Some(&self.pulse_style));
 
             if remaining_after_pulse > 0 {
-                let remaining_chars = self
-                    .bar_style
-                    .remaining_char()
-                    .repeat(remaining_after_pulse);
-                segments.push(Segment::new(
-                    remaining_chars,
-                    Some(self.remaining_style.clone()),
-                ));
+                sink.piece(
+                    self.bar_style.remaining_char(),
+                    remaining_after_pulse,
+                    Some(&self.remaining_style),
+                );
             }
         } else if remaining_width > 0 {
-            let remaining_chars = self.bar_style.remaining_char().repeat(remaining_width);
-            segments.push(Segment::new(
-                remaining_chars,
-                Some(self.remaining_style.clone()),
-            ));
-        }
-
-        if self.show_brackets {
-            segments.push(Segment::new("]", None));
-        }
-
-        // Suffix (percentage, ETA, etc.)
-        if !suffix.is_empty() {
-            segments.push(Segment::new(suffix, None));
+            sink.piece(
+                self.bar_style.remaining_char(),
+                remaining_width,
+                Some(&self.remaining_style),
+            );
         }
-
-        segments.push(Segment::line());
-        segments
     }
 
     /// Render the progress bar as a plain string.
     #[must_use]
     pub fn render_plain(&self, width: usize) -> String {
-        self.render(width)
-            .into_iter()
-            .map(|seg| seg.text.into_owned())
-            .collect()
+        segments_to_plain(&self.render(width))
     }
 }
 
 impl Renderable for ProgressBar {
-    fn render<'a>(&'a self, _console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+    fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        if !console.supports_unicode() && self.bar_style != BarStyle::Ascii {
+            let ascii = self.clone().bar_style(BarStyle::Ascii);
+            return ascii.render(options.max_width);
+        }
         self.render(options.max_width).into_iter().collect()
     }
 }
 
+/// Destination for the styled pieces of a rendered [`ProgressBar`].
+trait BarSink {
+    /// Emit `text` repeated `count` times with `style`.
+    fn piece(&mut self, text: &str, count: usize, style: Option<&Style>);
+    /// Emit rich text, keeping its spans.
+    fn text(&mut self, text: &Text);
+    /// End the line.
+    fn line(&mut self);
+}
+
+impl BarSink for Vec<Segment<'static>> {
+    fn piece(&mut self, text: &str, count: usize, style: Option<&Style>) {
+        self.push(Segment::new(text.repeat(count), style.cloned()));
+    }
+
+    fn text(&mut self, text: &Text) {
+        self.extend(text.render("").into_iter().map(Segment::into_owned));
+    }
+
+    fn line(&mut self) {
+        self.push(Segment::line());
+    }
+}
+
+/// Writes pieces straight into a string, optionally wrapped in ANSI codes.
+struct StringSink<'b> {
+    buf: &'b mut String,
+    color_system: Option<ColorSystem>,
+}
+
+impl BarSink for StringSink<'_> {
+    fn piece(&mut self, text: &str, count: usize, style: Option<&Style>) {
+        let codes = self
+            .color_system
+            .zip(style)
+            .map(|(color_system, style)| style.render_ansi(color_system));
+        if let Some(codes) = &codes {
+            self.buf.push_str(&codes.0);
+        }
+        for _ in 0..count {
+            self.buf.push_str(text);
+        }
+        if let Some(codes) = &codes {
+            self.buf.push_str(&codes.1);
+        }
+    }
+
+    fn text(&mut self, text: &Text) {
+        if self.color_system.is_none() {
+            self.buf.push_str(text.plain());
+            return;
+        }
+        for segment in text.render("") {
+            self.piece(&segment.text, 1, segment.style.as_ref());
+        }
+    }
+
+    fn line(&mut self) {
+        self.buf.push('\n');
+    }
+}
+
+/// Format an integer with comma thousands separators (locale-neutral).
+fn group_thousands(n: u64) -> String {
+    crate::filesize::group_digits(&n.to_string(), ',')
+}
+
 /// Create an ASCII-style progress bar.
 #[must_use]
 pub fn ascii_bar() -> ProgressBar {
@@ -856,6 +1452,101 @@ pub fn gradient_bar() -> ProgressBar {
     ProgressBar::new().bar_style(BarStyle::Gradient)
 }
 
+// =============================================================================
+// Iterator Tracking
+// =============================================================================
+
+/// An iterator adapter that advances a [`ProgressBar`] as items are consumed.
+///
+/// Created by [`Console::track`]. On an interactive console the bar is drawn
+/// through a transient [`Live`] display that is removed once the iterator is
+/// exhausted; otherwise items are yielded without any output.
+pub struct Track<I> {
+    iter: I,
+    bar: Arc<Mutex<ProgressBar>>,
+    live: Option<Live>,
+    yielded: bool,
+}
+
+impl<I: Iterator> Track<I> {
+    /// Wrap `iter`, sizing the bar from `total` or the iterator's exact length.
+    pub fn new(console: &Arc<Console>, iter: I, total: Option<u64>) -> Self {
+        let total = total.or_else(|| match iter.size_hint() {
+            (lower, Some(upper)) if lower == upper => u64::try_from(upper).ok(),
+            _ => None,
+        });
+        let bar = match total {
+            Some(total) => ProgressBar::with_total(total),
+            None => ProgressBar::new(),
+        };
+        let bar = Arc::new(Mutex::new(bar.show_count(total.is_some())));
+
+        let live = console.is_interactive().then(|| {
+            let options = LiveOptions {
+                transient: true,
+                ..LiveOptions::default()
+            };
+            let render_bar = Arc::clone(&bar);
+            let live = Live::with_options(Arc::clone(console), options)
+                .get_renderable(move || Box::new(lock_recover(&render_bar).clone()));
+            let _ = live.start(true);
+            live
+        });
+
+        Self {
+            iter,
+            bar,
+            live,
+            yielded: false,
+        }
+    }
+
+    /// A snapshot of the progress bar's current state.
+    #[must_use]
+    pub fn bar(&self) -> ProgressBar {
+        lock_recover(&self.bar).clone()
+    }
+
+    /// Current progress (0.0 - 1.0).
+    #[must_use]
+    pub fn progress(&self) -> f64 {
+        lock_recover(&self.bar).progress()
+    }
+}
+
+impl<I: Iterator> Iterator for Track<I> {
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // An item counts as done once the caller asks for the next one.
+        if self.yielded {
+            lock_recover(&self.bar).advance(1);
+        }
+        let item = self.iter.next();
+        self.yielded = item.is_some();
+        if item.is_none() {
+            lock_recover(&self.bar).finish();
+            if let Some(live) = self.live.take() {
+                let _ = live.stop();
+            }
+        }
+        item
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I> std::fmt::Debug for Track<I> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Track")
+            .field("bar", &self.bar)
+            .field("live", &self.live.as_ref().map(|_| "<Live>"))
+            .finish_non_exhaustive()
+    }
+}
+
 // =============================================================================
 // Standalone File Size and Transfer Speed Columns
 // =============================================================================
@@ -1190,6 +1881,34 @@ mod tests {
     use super::*;
     use crate::style::Attributes;
 
+    /// Clock that only moves when a test advances it.
+    struct FakeClock {
+        start: Instant,
+        offset: Mutex<Duration>,
+    }
+
+    impl FakeClock {
+        fn new() -> Arc<Self> {
+            Arc::new(Self {
+                start: Instant::now(),
+                offset: Mutex::new(Duration::ZERO),
+            })
+        }
+
+        fn set(&self, offset: Duration) {
+            *lock_recover(&self.offset) = offset;
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> Instant {
+            self.start + *lock_recover(&self.offset)
+        }
+        fn system_now(&self) -> std::time::SystemTime {
+            std::time::SystemTime::UNIX_EPOCH + *lock_recover(&self.offset)
+        }
+    }
+
     #[test]
     fn test_progress_bar_new() {
         let bar = ProgressBar::new();
@@ -1248,6 +1967,190 @@ mod tests {
         assert!(!plain.is_empty());
     }
 
+    #[test]
+    fn test_progress_bar_rate_window_tracks_recent_throughput() {
+        let mut bar = ProgressBar::with_total(1000).rate_window(Duration::from_secs(5));
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+        // A fast burst, then a crawl.
+        for (current, secs) in [(0, 0), (800, 2), (805, 5), (820, 8), (840, 10)] {
+            bar.update_at(current, at(secs));
+        }
+
+        // Only the last five seconds count: 35 items in 5s, not 840 in 10s.
+        assert_eq!(bar.speed(), Some(7.0));
+        assert_eq!(bar.transfer_speed(), Some(7.0));
+        let eta = bar.eta().expect("eta").as_secs_f64();
+        assert!((eta - 160.0 / 7.0).abs() < 1e-9, "{eta}");
+
+        // Without a window no samples are kept.
+        let mut plain = ProgressBar::with_total(1000);
+        plain.update_at(10, at(1));
+        assert!(plain.samples.is_empty());
+    }
+
+    #[test]
+    fn test_progress_bar_eta_from_fake_clock() {
+        let clock = FakeClock::new();
+        let mut bar = ProgressBar::with_total(100).clock(clock.clone());
+        clock.set(Duration::from_secs(10));
+        bar.update(25);
+
+        assert_eq!(bar.elapsed(), Some(Duration::from_secs(10)));
+        assert_eq!(bar.eta(), Some(Duration::from_secs(30)));
+        assert!(bar.render_plain(80).contains("30s"));
+    }
+
+    #[test]
+    fn test_progress_bar_width_stable_across_updates() {
+        let mut bar = ProgressBar::with_total(100)
+            .width(60)
+            .show_count(true)
+            .show_eta(true)
+            .show_speed(true);
+        bar.start_time = Instant::now().checked_sub(Duration::from_secs(9));
+
+        let bar_columns = |plain: &str| {
+            let line = plain.trim_end_matches('\n');
+            let open = line.find('[').unwrap();
+            let close = line.find(']').unwrap();
+            (
+                cells::cell_len(&line[..open]),
+                cells::cell_len(&line[..close]),
+                cells::cell_len(line),
+            )
+        };
+
+        bar.update(9);
+        let at_nine = bar.render_plain(60);
+        assert!(at_nine.contains("   9%   9/100 ETA"), "{at_nine:?}");
+        bar.update(100);
+        let at_hundred = bar.render_plain(60);
+        assert!(at_hundred.contains(" 100% 100/100 "), "{at_hundred:?}");
+
+        assert_eq!(bar_columns(&at_nine), bar_columns(&at_hundred));
+
+        // ETAs of an hour or more fit the reserved space too.
+        let mut slow = ProgressBar::with_total(100)
+            .width(60)
+            .show_count(true)
+            .show_eta(true)
+            .show_speed(true);
+        slow.start_time = Instant::now().checked_sub(Duration::from_secs(100));
+        slow.update(2);
+        let hours = slow.render_plain(60);
+        assert!(hours.contains("ETA 1:2"), "{hours:?}");
+        assert_eq!(bar_columns(&hours), bar_columns(&at_nine));
+    }
+
+    #[test]
+    fn test_progress_bar_count_with_unit() {
+        let mut bar = ProgressBar::with_total(10_000)
+            .show_eta(false)
+            .show_count(true)
+            .unit("files");
+        bar.update(1234);
+        let plain = bar.render_plain(80);
+        assert!(plain.contains("1,234/10,000 files"), "got {plain:?}");
+
+        assert_eq!(group_thousands(0), "0");
+        assert_eq!(group_thousands(999), "999");
+        assert_eq!(group_thousands(1_000), "1,000");
+        assert_eq!(group_thousands(12_345_678), "12,345,678");
+    }
+
+    #[test]
+    fn test_track_yields_items_and_completes() {
+        let console = Console::builder()
+            .force_terminal(false)
+            .file(Box::new(std::io::sink()))
+            .build()
+            .shared();
+        let mut tracked = console.track(0..10, Some(10));
+        assert!(tracked.progress().abs() < f64::EPSILON);
+        let items: Vec<i32> = tracked.by_ref().collect();
+        assert_eq!(items, (0..10).collect::<Vec<_>>());
+        assert!((tracked.progress() - 1.0).abs() < f64::EPSILON);
+        assert_eq!(tracked.bar().current(), 10);
+        assert!(tracked.bar().is_finished());
+
+        // Without an explicit total, an exact size hint is used.
+        let tracked = console.track(vec!["a", "b", "c"], None);
+        assert_eq!(tracked.bar().total(), Some(3));
+    }
+
+    #[test]
+    fn test_render_into_matches_render_plain() {
+        let mut bar = ProgressBar::with_total(8)
+            .width(20)
+            .description(Text::new("Copying"))
+            .show_count(true);
+        let mut frame = String::with_capacity(128);
+        for step in 0..=8 {
+            bar.update(step);
+            frame.clear();
+            bar.render_into(&mut frame, 60, None);
+            assert_eq!(frame, bar.render_plain(60));
+        }
+
+        frame.clear();
+        bar.render_into(&mut frame, 60, Some(ColorSystem::TrueColor));
+        assert!(frame.contains("\x1b["), "expected ANSI codes: {frame:?}");
+        // The finished bar keeps the space reserved for its ETA.
+        assert!(frame.trim_end().ends_with("8/8"), "{frame:?}");
+    }
+
+    #[test]
+    fn test_progress_bar_suffix_formatter() {
+        let mut bar = ProgressBar::with_total(100)
+            .width(20)
+            .show_brackets(false)
+            .suffix_formatter(Box::new(|bar| {
+                format!(
+                    "{}/{} pkgs • 3.2/s",
+                    bar.current(),
+                    bar.total().unwrap_or(0)
+                )
+            }));
+        bar.update(42);
+        let plain = bar.render_plain(80);
+        let line = plain.trim_end_matches('\n');
+        assert!(line.ends_with(" 42/100 pkgs • 3.2/s"), "got {plain:?}");
+        assert!(
+            !line.contains("42%"),
+            "built-in suffix should be replaced: {plain:?}"
+        );
+
+        // The bar shrinks so bar + suffix fit the available width.
+        let narrow = bar.render_plain(30);
+        let narrow_line = narrow.trim_end_matches('\n');
+        assert_eq!(cells::cell_len(narrow_line), 30, "got {narrow:?}");
+        assert!(narrow_line.ends_with(" 42/100 pkgs • 3.2/s"));
+    }
+
+    #[test]
+    fn test_progress_bar_stacked_segments() {
+        let compile = Style::new().color_str("blue").unwrap();
+        let test = Style::new().color_str("yellow").unwrap();
+        let mut bar = ProgressBar::new()
+            .width(10)
+            .show_brackets(false)
+            .show_percentage(false)
+            .segments(vec![(1.0, compile.clone()), (1.0, test)]);
+        bar.set_segment_progress(0, 1.0);
+        bar.set_segment_progress(1, 0.0);
+
+        assert_eq!(
+            bar.render_plain(10),
+            "\u{2588}".repeat(5) + &"\u{2591}".repeat(5) + "\n"
+        );
+        assert!((bar.progress() - 0.5).abs() < f64::EPSILON);
+
+        let segments = bar.render(10);
+        assert_eq!(segments[0].style, Some(compile));
+        assert_eq!(segments[1].style, Some(bar.remaining_style.clone()));
+    }
+
     #[test]
     fn test_progress_bar_styles() {
         for style in [
@@ -1296,6 +2199,46 @@ mod tests {
         assert!(plain.contains('✓'));
     }
 
+    #[test]
+    fn test_progress_bar_summary_on_finish() {
+        let mut bar = ProgressBar::with_total(348).summary_on_finish(true);
+        bar.start_time = Instant::now().checked_sub(Duration::from_secs(83));
+        bar.update(348);
+        assert!(bar.is_finished());
+        assert_eq!(
+            bar.render_plain(80).trim_end(),
+            "✓ Done in 1:23 (avg 4.2/s)"
+        );
+
+        let mut bar = ProgressBar::with_total(348)
+            .finished_message("Copied")
+            .unit("files")
+            .summary_on_finish(true);
+        bar.start_time = Instant::now().checked_sub(Duration::from_secs(83));
+        bar.finish();
+        bar.update(348);
+        assert_eq!(
+            bar.render_plain(80).trim_end(),
+            "✓ Copied in 1:23 (avg 4.2 files/s)"
+        );
+    }
+
+    #[test]
+    fn test_progress_bar_summary_frozen_after_finish() {
+        let clock = FakeClock::new();
+        let mut bar = ProgressBar::with_total(348)
+            .summary_on_finish(true)
+            .clock(clock.clone());
+        clock.set(Duration::from_secs(83));
+        bar.update(348);
+        let first = bar.render_plain(80);
+        assert_eq!(first.trim_end(), "✓ Done in 1:23 (avg 4.2/s)");
+
+        clock.set(Duration::from_secs(200));
+        assert_eq!(bar.render_plain(80), first);
+        assert_eq!(bar.elapsed(), Some(Duration::from_secs(83)));
+    }
+
     #[test]
     fn test_spinner_next_frame() {
         let mut spinner = Spinner::simple();
@@ -1313,6 +2256,52 @@ mod tests {
         assert_eq!(spinner.current_frame(), "|"); // Doesn't advance
     }
 
+    #[test]
+    fn test_spinner_chain_switches_phases_and_finishes() {
+        let mut spinner = Spinner::chain(vec![
+            (Spinner::simple(), 3),
+            (Spinner::custom(vec!["a", "b"]), 0),
+        ]);
+        assert_eq!(spinner.next_frame(), "|");
+        assert_eq!(spinner.next_frame(), "/");
+        assert_eq!(spinner.next_frame(), "-");
+        // First phase exhausted: the second spinner's frames take over and loop.
+        assert_eq!(spinner.current_frame(), "a");
+        assert_eq!(spinner.next_frame(), "a");
+        assert_eq!(spinner.next_frame(), "b");
+        assert_eq!(spinner.next_frame(), "a");
+
+        spinner.set_active(0);
+        assert_eq!(spinner.next_frame(), "|");
+
+        spinner.finish();
+        assert!(spinner.is_finished());
+        assert_eq!(spinner.next_frame(), "\u{2713}");
+        assert_eq!(spinner.render().text, "\u{2713}");
+    }
+
+    #[test]
+    fn test_spinner_speed_from_rate() {
+        let elapsed = Duration::from_secs(2);
+        let mut idle = Spinner::simple();
+        idle.speed_from_rate(0.0);
+        let mut busy = Spinner::simple();
+        busy.speed_from_rate(500.0);
+
+        assert!(busy.frames_elapsed(elapsed) > idle.frames_elapsed(elapsed));
+        assert_eq!(idle.frames_elapsed(elapsed), 12);
+        assert_eq!(Spinner::simple().frame_at(Duration::from_millis(170)), "-");
+
+        busy.speed_from_rate(f64::INFINITY);
+        assert_eq!(busy.frames_elapsed(elapsed), 100);
+        busy.speed_from_rate(f64::NAN);
+        assert_eq!(busy.frames_elapsed(elapsed), 12);
+        busy.speed_from_rate(f64::NEG_INFINITY);
+        assert_eq!(busy.frames_elapsed(elapsed), 12);
+        busy.speed_from_rate(1e12);
+        assert_eq!(busy.frames_elapsed(elapsed), 100);
+    }
+
     #[test]
     fn test_spinner_render() {
         let spinner = Spinner::dots();
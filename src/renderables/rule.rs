@@ -1,12 +1,13 @@
 //! Rule - horizontal line with optional title.
 //!
 //! A Rule renders as a horizontal line that spans the console width,
-//! optionally with a centered (or aligned) title.
+//! optionally with a centered (or aligned) title. [`Rule::vertical`] renders
+//! a one-cell-wide divider instead, for separating side-by-side panes.
 
 use crate::cells;
 use crate::console::{Console, ConsoleOptions};
 use crate::renderables::Renderable;
-use crate::segment::Segment;
+use crate::segment::{Segment, segments_to_plain};
 use crate::style::Style;
 use crate::text::{JustifyMethod, OverflowMethod, Text};
 
@@ -21,6 +22,8 @@ pub struct Rule {
     style: Style,
     /// Title alignment.
     align: JustifyMethod,
+    /// Line count when drawn as a vertical divider.
+    vertical: Option<usize>,
 }
 
 impl Default for Rule {
@@ -30,6 +33,7 @@ impl Default for Rule {
             character: String::from("\u{2500}"), // ─
             style: Style::parse("bright_green").unwrap_or_default(),
             align: JustifyMethod::Center,
+            vertical: None,
         }
     }
 }
@@ -50,6 +54,18 @@ impl Rule {
         }
     }
 
+    /// Create a vertical rule: `height` lines holding a single `│` each.
+    ///
+    /// Width and title are ignored; character and style apply as usual.
+    #[must_use]
+    pub fn vertical(height: usize) -> Self {
+        Self {
+            character: String::from("\u{2502}"), // │
+            vertical: Some(height),
+            ..Self::default()
+        }
+    }
+
     /// Set the rule character.
     #[must_use]
     pub fn character(mut self, ch: impl Into<String>) -> Self {
@@ -92,6 +108,17 @@ impl Rule {
     /// Render the rule to segments for a given width.
     #[must_use]
     pub fn render(&self, width: usize) -> Vec<Segment<'static>> {
+        if let Some(height) = self.vertical {
+            return (0..height)
+                .flat_map(|_| {
+                    [
+                        Segment::new(self.character.clone(), Some(self.style.clone())),
+                        Segment::line(),
+                    ]
+                })
+                .collect();
+        }
+
         let char_width = cells::cell_len(&self.character);
         if char_width == 0 || width == 0 {
             return vec![Segment::line()];
@@ -224,10 +251,7 @@ impl Rule {
     /// Render the rule as a string (for simple output).
     #[must_use]
     pub fn render_plain(&self, width: usize) -> String {
-        self.render(width)
-            .into_iter()
-            .map(|seg| seg.text.into_owned())
-            .collect()
+        segments_to_plain(&self.render(width))
     }
 }
 
@@ -277,6 +301,18 @@ mod tests {
         assert!(text.contains('\u{2500}')); // ─
     }
 
+    #[test]
+    fn test_rule_vertical() {
+        let style = Style::parse("red").unwrap();
+        let rule = Rule::vertical(3).style(style.clone());
+        let segments = rule.render(80);
+        assert_eq!(rule.render_plain(80), "│\n│\n│\n");
+
+        let bars: Vec<&Segment<'_>> = segments.iter().filter(|s| s.text == "│").collect();
+        assert_eq!(bars.len(), 3);
+        assert!(bars.iter().all(|s| s.style.as_ref() == Some(&style)));
+    }
+
     #[test]
     fn test_rule_custom_char() {
         let rule = Rule::new().character("=");
@@ -57,6 +57,9 @@
 //! let syntax = Syntax::from_path("src/main.rs")?
 //!     .line_numbers(true)
 //!     .theme("InspiredGitHub");
+//!
+//! // Code already in memory: detect from the name, or a `#!` line
+//! let syntax = Syntax::for_path("deploy", "#!/usr/bin/env python\nprint('hi')\n");
 //! ```
 //!
 //! # Available Themes
@@ -90,14 +93,16 @@ use crate::segment::Segment;
 use crate::style::Style;
 use crate::text::Text;
 
+use std::collections::HashMap;
 use std::fs;
+use std::ops::Range;
 use std::path::Path;
 use std::sync::Arc;
 use std::sync::LazyLock;
 
-use syntect::easy::HighlightLines;
+use syntect::easy::{HighlightLines, ScopeRangeIterator};
 use syntect::highlighting::{Theme, ThemeSet};
-use syntect::parsing::SyntaxSet;
+use syntect::parsing::{ParseState, ScopeStack, ScopeStackOp, SyntaxSet};
 use syntect::util::LinesWithEndings;
 
 static SYNTAX_SET: LazyLock<SyntaxSet> = LazyLock::new(SyntaxSet::load_defaults_newlines);
@@ -164,6 +169,8 @@ pub struct Syntax {
     custom_syntax_set: Option<Arc<SyntaxSet>>,
     /// Optional custom theme set (loaded from user paths).
     custom_theme_set: Option<Arc<ThemeSet>>,
+    /// Per-token-kind style overrides.
+    token_styles: HashMap<TokenKind, Style>,
 }
 
 impl Default for Syntax {
@@ -182,6 +189,7 @@ impl Default for Syntax {
             padding: (0, 0),
             custom_syntax_set: None,
             custom_theme_set: None,
+            token_styles: HashMap::new(),
         }
     }
 }
@@ -204,20 +212,71 @@ impl Syntax {
 
     /// Load syntax from a file path, auto-detecting the language.
     ///
+    /// See [`Self::guess_language`] for how the language is picked.
+    ///
     /// # Errors
     ///
     /// Returns an error if the file cannot be read.
     pub fn from_path(path: impl AsRef<Path>) -> Result<Self, SyntaxError> {
         let path = path.as_ref();
         let code = fs::read_to_string(path).map_err(|e| SyntaxError::IoError(e.to_string()))?;
+        let language = Self::guess_language(path, &code);
+        Ok(Self::new(code, language))
+    }
 
-        // Auto-detect language from extension
-        let language = path
-            .extension()
-            .and_then(|ext| ext.to_str())
-            .map_or_else(|| String::from("text"), Self::extension_to_language);
+    /// Create a code block for in-memory `code` that came from `path`.
+    ///
+    /// The file is not read; `path` only drives [`Self::guess_language`].
+    #[must_use]
+    pub fn for_path(path: impl AsRef<Path>, code: impl Into<String>) -> Self {
+        let code = code.into();
+        let language = Self::guess_language(path, &code);
+        Self::new(code, language)
+    }
 
-        Ok(Self::new(code, language))
+    /// Guess the language of `code` from its file name, then its shebang.
+    ///
+    /// The extension (or a bare `Dockerfile`/`Makefile` name) wins when a
+    /// built-in syntax exists for it. Otherwise a `#!` first line names the
+    /// interpreter, so `#!/usr/bin/env python3` gives `"python"`. Anything
+    /// else is `"text"`, which renders unhighlighted.
+    #[must_use]
+    pub fn guess_language(path: impl AsRef<Path>, code: &str) -> String {
+        let path = path.as_ref();
+        let from_name = path
+            .extension()
+            .or_else(|| path.file_name())
+            .and_then(|name| name.to_str())
+            .map(Self::extension_to_language)
+            .filter(|language| SYNTAX_SET.find_syntax_by_token(language).is_some());
+
+        from_name
+            .or_else(|| Self::shebang_language(code))
+            .unwrap_or_else(|| String::from("text"))
+    }
+
+    /// Map a `#!` first line to a language name.
+    fn shebang_language(code: &str) -> Option<String> {
+        let command = code.lines().next()?.strip_prefix("#!")?;
+        let mut words = command.split_whitespace();
+        let mut program = words.next()?.rsplit('/').next()?;
+        if program == "env" {
+            program = words.find(|word| !word.starts_with('-') && !word.contains('='))?;
+        }
+        let interpreter = program.trim_end_matches(|c: char| c.is_ascii_digit() || c == '.');
+        let language = match interpreter {
+            "python" | "pypy" => "python",
+            "sh" | "bash" | "dash" | "ksh" | "zsh" => "bash",
+            "node" | "nodejs" => "javascript",
+            "ruby" => "ruby",
+            "perl" => "perl",
+            "php" => "php",
+            "lua" => "lua",
+            "Rscript" => "r",
+            "pwsh" => "powershell",
+            _ => return None,
+        };
+        Some(language.to_string())
     }
 
     /// Load a syntect syntax set from a folder of `.sublime-syntax` definitions.
@@ -373,6 +432,17 @@ impl Syntax {
         self
     }
 
+    /// Override the styles of specific token kinds.
+    ///
+    /// Each override replaces the theme's style for that kind (the code
+    /// background is kept unless the override sets its own). Kinds without an
+    /// entry keep the theme's style.
+    #[must_use]
+    pub fn token_styles(mut self, styles: HashMap<TokenKind, Style>) -> Self {
+        self.token_styles = styles;
+        self
+    }
+
     /// Get the list of available themes.
     #[must_use]
     pub fn available_themes() -> Vec<String> {
@@ -408,6 +478,11 @@ impl Syntax {
         let syntax = ps
             .find_syntax_by_token(&self.language)
             .or_else(|| ps.find_syntax_by_extension(&self.language))
+            .or_else(|| {
+                self.language
+                    .eq_ignore_ascii_case("text")
+                    .then(|| ps.find_syntax_plain_text())
+            })
             .ok_or_else(|| SyntaxError::UnknownLanguage(self.language.clone()))?;
 
         // Get the theme. The Python-Rich compatibility theme name is an alias; use a built-in
@@ -424,6 +499,9 @@ impl Syntax {
             .ok_or_else(|| SyntaxError::UnknownTheme(self.theme_name.clone()))?;
 
         let mut highlighter = HighlightLines::new(syntax, theme);
+        // Token kinds are only needed (and parsed a second time) when overrides exist.
+        let mut kind_parser = (!use_python_rich_rust && !self.token_styles.is_empty())
+            .then(|| (ParseState::new(syntax), ScopeStack::new()));
         let mut segments: Vec<Segment<'static>> = Vec::new();
 
         // Background used for padding/fill and for styling indent guides.
@@ -518,8 +596,19 @@ impl Syntax {
                         )]
                     });
 
+                let kinds = kind_parser
+                    .as_mut()
+                    .and_then(|(state, stack)| {
+                        let ops = state.parse_line(&line_for_highlight, ps).ok()?;
+                        Some(token_kind_ranges(&ops, &line_for_highlight, stack))
+                    })
+                    .unwrap_or_default();
+
                 let mut col = 0usize;
+                let mut offset = 0usize;
                 for (style, text) in ranges {
+                    let start = offset;
+                    offset += text.len();
                     if text.is_empty() {
                         continue;
                     }
@@ -528,14 +617,40 @@ impl Syntax {
                     } else {
                         self.syntect_style_to_rich(style, theme)
                     };
-                    append_syntax_text(
-                        &mut line_text,
-                        text,
-                        &rich_style,
-                        leading_spaces,
-                        &mut col,
-                        &guide_style,
-                    );
+
+                    // Split the highlighted run wherever an overridden token kind begins or ends.
+                    let mut pieces: Vec<(&str, Style)> = Vec::new();
+                    let mut cursor = start;
+                    for (range, kind) in &kinds {
+                        let lo = range.start.max(cursor);
+                        let hi = range.end.min(offset);
+                        if lo >= hi {
+                            continue;
+                        }
+                        if lo > cursor {
+                            pieces.push((&line_for_highlight[cursor..lo], rich_style.clone()));
+                        }
+                        let piece_style = self.token_styles.get(kind).map_or_else(
+                            || rich_style.clone(),
+                            |custom| Style::new().bgcolor(bg.clone()).combine(custom),
+                        );
+                        pieces.push((&line_for_highlight[lo..hi], piece_style));
+                        cursor = hi;
+                    }
+                    if cursor < offset {
+                        pieces.push((&line_for_highlight[cursor..offset], rich_style));
+                    }
+
+                    for (piece, piece_style) in pieces {
+                        append_syntax_text(
+                            &mut line_text,
+                            piece,
+                            &piece_style,
+                            leading_spaces,
+                            &mut col,
+                            &guide_style,
+                        );
+                    }
                 }
             }
 
@@ -686,19 +801,27 @@ impl Syntax {
         matches!(language.to_ascii_lowercase().as_str(), "rust" | "rs")
     }
 
-    fn python_rich_style(token_kind: RustTokenKind, background: &Color) -> Style {
+    /// Style for a token kind, honoring [`Self::token_styles`] overrides.
+    fn token_style(&self, token_kind: TokenKind, background: &Color) -> Style {
+        match self.token_styles.get(&token_kind) {
+            Some(custom) => Style::new().bgcolor(background.clone()).combine(custom),
+            None => Self::python_rich_style(token_kind, background),
+        }
+    }
+
+    fn python_rich_style(token_kind: TokenKind, background: &Color) -> Style {
         let fg = match token_kind {
-            RustTokenKind::Keyword => Color::from_rgb(102, 217, 239),
-            RustTokenKind::Function => Color::from_rgb(166, 226, 46),
-            RustTokenKind::Operator => Color::from_rgb(255, 70, 137),
-            RustTokenKind::Number => Color::from_rgb(174, 129, 255),
-            RustTokenKind::String => Color::from_rgb(230, 219, 116),
-            RustTokenKind::Comment => Color::from_rgb(117, 113, 94),
-            RustTokenKind::Plain => Color::from_rgb(248, 248, 242),
+            TokenKind::Keyword => Color::from_rgb(102, 217, 239),
+            TokenKind::Function => Color::from_rgb(166, 226, 46),
+            TokenKind::Operator => Color::from_rgb(255, 70, 137),
+            TokenKind::Number => Color::from_rgb(174, 129, 255),
+            TokenKind::String => Color::from_rgb(230, 219, 116),
+            TokenKind::Comment => Color::from_rgb(117, 113, 94),
+            TokenKind::Plain => Color::from_rgb(248, 248, 242),
         };
 
         let mut style = Style::new().color(fg).bgcolor(background.clone());
-        if matches!(token_kind, RustTokenKind::Comment) {
+        if matches!(token_kind, TokenKind::Comment) {
             style = style.italic();
         }
         style
@@ -720,7 +843,7 @@ impl Syntax {
                 }
                 out.push((
                     line[start..i].to_string(),
-                    Self::python_rich_style(RustTokenKind::Plain, background),
+                    self.token_style(TokenKind::Plain, background),
                 ));
                 continue;
             }
@@ -728,7 +851,7 @@ impl Syntax {
             if b == b'/' && i + 1 < bytes.len() && bytes[i + 1] == b'/' {
                 out.push((
                     line[i..].to_string(),
-                    Self::python_rich_style(RustTokenKind::Comment, background),
+                    self.token_style(TokenKind::Comment, background),
                 ));
                 break;
             }
@@ -736,7 +859,7 @@ impl Syntax {
             if b == b'"' {
                 out.push((
                     "\"".to_string(),
-                    Self::python_rich_style(RustTokenKind::String, background),
+                    self.token_style(TokenKind::String, background),
                 ));
                 i += 1;
 
@@ -763,14 +886,14 @@ impl Syntax {
                 if string_start < i {
                     out.push((
                         line[string_start..i].to_string(),
-                        Self::python_rich_style(RustTokenKind::String, background),
+                        self.token_style(TokenKind::String, background),
                     ));
                 }
 
                 if i < bytes.len() && bytes[i] == b'"' {
                     out.push((
                         "\"".to_string(),
-                        Self::python_rich_style(RustTokenKind::String, background),
+                        self.token_style(TokenKind::String, background),
                     ));
                     i += 1;
                 }
@@ -785,7 +908,7 @@ impl Syntax {
                 }
                 out.push((
                     line[start..i].to_string(),
-                    Self::python_rich_style(RustTokenKind::Number, background),
+                    self.token_style(TokenKind::Number, background),
                 ));
                 continue;
             }
@@ -808,17 +931,14 @@ impl Syntax {
                 let next_non_whitespace = next_non_whitespace_byte(bytes, i);
 
                 let kind = if is_rust_keyword(keyword_lexeme) {
-                    RustTokenKind::Keyword
+                    TokenKind::Keyword
                 } else if lexeme.ends_with('!') || next_non_whitespace == Some(b'(') {
-                    RustTokenKind::Function
+                    TokenKind::Function
                 } else {
-                    RustTokenKind::Plain
+                    TokenKind::Plain
                 };
 
-                out.push((
-                    lexeme.to_string(),
-                    Self::python_rich_style(kind, background),
-                ));
+                out.push((lexeme.to_string(), self.token_style(kind, background)));
                 continue;
             }
 
@@ -830,7 +950,7 @@ impl Syntax {
                 }
                 out.push((
                     line[start..i].to_string(),
-                    Self::python_rich_style(RustTokenKind::Operator, background),
+                    self.token_style(TokenKind::Operator, background),
                 ));
                 continue;
             }
@@ -838,7 +958,7 @@ impl Syntax {
             let ch_len = line[i..].chars().next().map_or(1, char::len_utf8);
             out.push((
                 line[i..i + ch_len].to_string(),
-                Self::python_rich_style(RustTokenKind::Plain, background),
+                self.token_style(TokenKind::Plain, background),
             ));
             i += ch_len;
         }
@@ -853,17 +973,82 @@ impl Syntax {
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-enum RustTokenKind {
+/// Broad token categories used for [`Syntax::token_styles`] overrides.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
+pub enum TokenKind {
+    /// Language keywords and storage modifiers (`fn`, `let`, `pub`).
     Keyword,
+    /// Function and macro names.
     Function,
+    /// Operators and punctuation such as `=` and `->`.
     Operator,
+    /// Numeric literals.
     Number,
+    /// String and character literals.
     String,
+    /// Comments.
     Comment,
+    /// Everything else.
     Plain,
 }
 
+impl TokenKind {
+    /// Classify a syntect scope stack.
+    ///
+    /// Comments and strings win anywhere in the stack (so their delimiters
+    /// match their bodies); otherwise the innermost recognized scope decides.
+    fn from_scopes(stack: &ScopeStack) -> Self {
+        let names: Vec<String> = stack.as_slice().iter().map(|s| s.build_string()).collect();
+        if names.iter().any(|name| name.starts_with("comment")) {
+            return Self::Comment;
+        }
+        if names
+            .iter()
+            .any(|name| name.starts_with("string") || name.starts_with("constant.character"))
+        {
+            return Self::String;
+        }
+        for name in names.iter().rev() {
+            let kind = if name.starts_with("constant.numeric") {
+                Self::Number
+            } else if name.starts_with("keyword.operator") || name.starts_with("punctuation") {
+                Self::Operator
+            } else if name.starts_with("keyword") || name.starts_with("storage") {
+                Self::Keyword
+            } else if name.starts_with("entity.name.function")
+                || name.starts_with("support.function")
+                || name.starts_with("variable.function")
+            {
+                Self::Function
+            } else {
+                continue;
+            };
+            return kind;
+        }
+        Self::Plain
+    }
+}
+
+/// Map a parsed line onto byte ranges tagged with their [`TokenKind`].
+///
+/// `stack` carries the scope stack across lines and is updated in place.
+fn token_kind_ranges(
+    ops: &[(usize, ScopeStackOp)],
+    line: &str,
+    stack: &mut ScopeStack,
+) -> Vec<(Range<usize>, TokenKind)> {
+    let mut kinds = Vec::new();
+    for (range, op) in ScopeRangeIterator::new(ops, line) {
+        if stack.apply(op).is_err() {
+            break;
+        }
+        if !range.is_empty() {
+            kinds.push((range, TokenKind::from_scopes(stack)));
+        }
+    }
+    kinds
+}
+
 fn is_identifier_start(byte: u8) -> bool {
     byte == b'_' || byte.is_ascii_alphabetic()
 }
@@ -1196,6 +1381,32 @@ mod tests {
         assert_eq!(Syntax::extension_to_language("unknown"), "unknown");
     }
 
+    #[test]
+    fn test_guess_language_from_path_and_shebang() {
+        assert_eq!(
+            Syntax::for_path("src/main.rs", "fn main() {}").language,
+            "rust"
+        );
+        assert_eq!(Syntax::guess_language("data.json", "{}"), "json");
+
+        let script = "#!/usr/bin/env python\nprint('hi')\n";
+        assert_eq!(Syntax::for_path("bin/deploy", script).language, "python");
+        assert_eq!(
+            Syntax::guess_language("run", "#!/bin/bash -e\necho hi\n"),
+            "bash"
+        );
+
+        let unknown = Syntax::for_path("notes.unknownext", "just words");
+        assert_eq!(unknown.language, "text");
+        let text: String = unknown
+            .render(None)
+            .expect("plain text renders")
+            .iter()
+            .map(|s| s.text.as_ref())
+            .collect();
+        assert!(text.contains("just words"));
+    }
+
     #[test]
     fn test_available_themes() {
         let themes = Syntax::available_themes();
@@ -1489,6 +1700,64 @@ mod tests {
         assert_eq!(quote_style.bgcolor, Some(Color::from_rgb(39, 40, 34)));
     }
 
+    #[test]
+    fn test_token_styles_override_comments_only() {
+        let comment_style = Style::new().italic().dim();
+        let overrides = HashMap::from([(TokenKind::Comment, comment_style.clone())]);
+        let syntax = Syntax::new("fn main() {} // done", "rust").token_styles(overrides);
+        let segments = syntax.render(None).expect("render should succeed");
+        let background = Color::from_rgb(39, 40, 34);
+
+        let comment = segments
+            .iter()
+            .find(|segment| segment.text == "// done")
+            .and_then(|segment| segment.style.clone())
+            .expect("comment segment should exist");
+        assert_eq!(
+            comment,
+            Style::new()
+                .bgcolor(background.clone())
+                .combine(&comment_style)
+        );
+
+        let keyword = segments
+            .iter()
+            .find(|segment| segment.text == "fn")
+            .and_then(|segment| segment.style.clone())
+            .expect("keyword segment should exist");
+        assert_eq!(keyword.color, Some(Color::from_rgb(102, 217, 239)));
+    }
+
+    #[test]
+    fn test_token_styles_apply_to_syntect_themes() {
+        let code = "def f():\n    return 1  # note\n";
+        let plain = Syntax::new(code, "python").theme("base16-ocean.dark");
+        let overridden = plain.clone().token_styles(HashMap::from([(
+            TokenKind::Comment,
+            Style::new().italic().dim(),
+        )]));
+
+        let style_of = |segments: &[Segment<'_>], needle: &str| {
+            segments
+                .iter()
+                .find(|segment| segment.text.contains(needle))
+                .and_then(|segment| segment.style.clone())
+                .expect("segment should exist")
+        };
+        let before = plain.render(None).expect("render should succeed");
+        let after = overridden.render(None).expect("render should succeed");
+
+        let comment = style_of(&after, "#");
+        assert_eq!(comment, style_of(&after, "note"));
+        assert!(
+            comment
+                .attributes
+                .contains(crate::style::Attributes::ITALIC)
+        );
+        assert!(comment.attributes.contains(crate::style::Attributes::DIM));
+        assert_eq!(style_of(&after, "def"), style_of(&before, "def"));
+    }
+
     #[test]
     fn test_word_wrap_builder() {
         let syntax = Syntax::new("code", "rust").word_wrap(Some(80));
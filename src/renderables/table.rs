@@ -56,18 +56,20 @@
 //! - `min_width(n)`: Minimum width
 //! - `max_width(n)`: Maximum width
 //! - `justify(method)`: Left, right, center, or full justification
-//! - `no_wrap`: Disable text wrapping
+//! - `no_wrap`: Crop content to one line with an ellipsis instead of wrapping
 //! - `style(s)`: Apply a style to cell content
 
 use crate::r#box::{ASCII, BoxChars, HEAVY_HEAD, RowLevel};
 use crate::cells;
 use crate::console::{Console, ConsoleOptions};
+use crate::filesize::group_digits;
 use crate::markup;
 use crate::renderables::Renderable;
-use crate::segment::{Segment, adjust_line_length};
+use crate::segment::{Segment, adjust_line_length, segments_to_plain};
 use crate::style::Style;
 use crate::text::{JustifyMethod, OverflowMethod, Text};
 use num_rational::Ratio;
+use unicode_segmentation::UnicodeSegmentation;
 
 // PaddingDimensions is available but not needed for current implementation
 
@@ -83,6 +85,89 @@ pub enum VerticalAlign {
     Bottom,
 }
 
+/// Number formatting applied to the numeric cells of a column.
+///
+/// See [`Column::number_format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Character inserted between groups of three integer digits.
+    pub thousands_separator: Option<char>,
+    /// Number of digits after the decimal point.
+    pub decimals: usize,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            thousands_separator: Some(','),
+            decimals: 0,
+        }
+    }
+}
+
+impl NumberFormat {
+    /// Format `value` if it is a plain decimal number, otherwise `None`.
+    ///
+    /// Works on the digit string, so values beyond `f64` precision keep
+    /// every digit; only the fraction is rounded (half up).
+    fn apply(&self, value: &str) -> Option<String> {
+        let value = value.trim();
+        let digits = value.strip_prefix(['-', '+']).unwrap_or(value);
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+        if whole.is_empty() && fraction.is_empty()
+            || !whole
+                .chars()
+                .chain(fraction.chars())
+                .all(|c| c.is_ascii_digit())
+        {
+            return None;
+        }
+
+        let decimals = self.decimals;
+        let mut number: Vec<u8> = whole.bytes().collect();
+        number.extend(fraction.bytes().take(decimals));
+        number.resize(whole.len() + decimals, b'0');
+        if fraction
+            .as_bytes()
+            .get(decimals)
+            .is_some_and(|&d| d >= b'5')
+        {
+            let mut index = number.len();
+            loop {
+                if index == 0 {
+                    number.insert(0, b'1');
+                    break;
+                }
+                index -= 1;
+                if number[index] == b'9' {
+                    number[index] = b'0';
+                } else {
+                    number[index] += 1;
+                    break;
+                }
+            }
+        }
+        let split = number.len() - decimals;
+        let (whole, fraction) = number.split_at(split);
+        let whole = std::str::from_utf8(whole).ok()?.trim_start_matches('0');
+        let whole = if whole.is_empty() { "0" } else { whole };
+
+        let mut formatted = String::with_capacity(number.len() + whole.len() / 3 + 2);
+        if value.starts_with('-') && number.iter().any(|&d| d != b'0') {
+            formatted.push('-');
+        }
+        match self.thousands_separator {
+            Some(separator) => formatted.push_str(&group_digits(whole, separator)),
+            None => formatted.push_str(whole),
+        }
+        if decimals > 0 {
+            formatted.push('.');
+            formatted.push_str(std::str::from_utf8(fraction).ok()?);
+        }
+        Some(formatted)
+    }
+}
+
 /// Column definition for a table.
 #[derive(Debug, Clone)]
 pub struct Column {
@@ -110,8 +195,12 @@ pub struct Column {
     pub max_width: Option<usize>,
     /// Ratio for flexible sizing.
     pub ratio: Option<usize>,
-    /// Disable text wrapping.
+    /// Crop content to a single line instead of wrapping.
     pub no_wrap: bool,
+    /// Whether the column is rendered (hidden columns keep their data).
+    pub visible: bool,
+    /// Formatting for numeric cells; other cells are left as they are.
+    pub number_format: Option<NumberFormat>,
 }
 
 impl Default for Column {
@@ -130,11 +219,22 @@ impl Default for Column {
             max_width: None,
             ratio: None,
             no_wrap: false,
+            visible: true,
+            number_format: None,
         }
     }
 }
 
 impl Column {
+    /// Whether this column has a `max_width` cap and truncates content past it.
+    fn truncates_at_cap(&self) -> bool {
+        self.max_width.is_some()
+            && matches!(
+                self.overflow,
+                OverflowMethod::Ellipsis | OverflowMethod::Crop
+            )
+    }
+
     /// Create a new column with a header.
     #[must_use]
     pub fn new(header: impl Into<Text>) -> Self {
@@ -221,13 +321,40 @@ impl Column {
         self
     }
 
-    /// Disable text wrapping.
+    /// Crop content to a single line instead of wrapping.
+    ///
+    /// Content wider than the column ends in `…`, or is cut without a
+    /// marker when the column's overflow is [`OverflowMethod::Crop`] or
+    /// [`OverflowMethod::Ignore`].
     #[must_use]
     pub fn no_wrap(mut self) -> Self {
         self.no_wrap = true;
         self
     }
 
+    /// Show or hide the column at render time.
+    ///
+    /// Hidden columns are skipped during width solving and rendering, but
+    /// their cells stay in the table's rows.
+    #[must_use]
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Format numeric cells and right-align the column.
+    ///
+    /// Cells whose text is a plain decimal number (such as `1234.5` or
+    /// `-3`) are rewritten with a fixed number of decimals and grouped
+    /// digits, so the decimal points line up. Anything else, like `"n/a"`,
+    /// passes through unchanged.
+    #[must_use]
+    pub fn number_format(mut self, format: NumberFormat) -> Self {
+        self.number_format = Some(format);
+        self.justify = JustifyMethod::Right;
+        self
+    }
+
     /// Get the header width.
     fn header_width(&self) -> usize {
         self.header
@@ -254,7 +381,7 @@ impl Column {
 pub struct Cell {
     /// Cell content.
     pub content: Text,
-    /// Cell-specific style (overrides column style).
+    /// Cell-specific style (overrides row and column styles).
     pub style: Option<Style>,
 }
 
@@ -339,7 +466,7 @@ impl<T: Into<Text>> From<T> for Cell {
 pub struct Row {
     /// Cells in this row.
     pub cells: Vec<Cell>,
-    /// Row-level style.
+    /// Row-level style, layered over column styles and under cell styles.
     pub style: Style,
     /// Draw separator after this row.
     pub end_section: bool,
@@ -376,6 +503,40 @@ impl From<Vec<Cell>> for Row {
     }
 }
 
+/// Types that describe themselves as a table row.
+///
+/// Implement this to build a [`Table`] from typed data with [`Table::of`]
+/// or print one directly with [`Console::print_table_of`](crate::Console::print_table_of).
+///
+/// ```
+/// use rich_rust::renderables::table::{Table, ToRow};
+///
+/// struct Task {
+///     name: &'static str,
+///     done: bool,
+/// }
+///
+/// impl ToRow for Task {
+///     fn columns() -> Vec<String> {
+///         vec!["Task".into(), "Done".into()]
+///     }
+///
+///     fn cells(&self) -> Vec<String> {
+///         vec![self.name.into(), self.done.to_string()]
+///     }
+/// }
+///
+/// let table = Table::of(&[Task { name: "write docs", done: true }]);
+/// assert!(table.render_plain(40).contains("write docs"));
+/// ```
+pub trait ToRow {
+    /// Column headers, in display order.
+    fn columns() -> Vec<String>;
+
+    /// Cell values for this item, in the same order as [`ToRow::columns`].
+    fn cells(&self) -> Vec<String>;
+}
+
 /// A data table with columns and rows.
 #[derive(Debug, Clone)]
 pub struct Table {
@@ -391,8 +552,8 @@ pub struct Table {
     width: Option<usize>,
     /// Minimum width.
     min_width: Option<usize>,
-    /// Box style.
-    box_style: &'static BoxChars,
+    /// Box style (`None` renders no borders or dividers).
+    box_style: Option<&'static BoxChars>,
     /// Force ASCII boxes.
     safe_box: Option<bool>,
     /// Cell padding (horizontal, vertical).
@@ -413,6 +574,8 @@ pub struct Table {
     show_lines: bool,
     /// Extra blank lines between rows.
     leading: usize,
+    /// Crop every cell to a single line instead of wrapping.
+    no_wrap: bool,
     /// Table-level style.
     style: Style,
     /// Alternating row styles.
@@ -431,6 +594,14 @@ pub struct Table {
     title_justify: JustifyMethod,
     /// Caption justification.
     caption_justify: JustifyMethod,
+    /// Style for the `…` marker of truncated cells (`None` = cell style).
+    ellipsis_style: Option<Style>,
+    /// Prepend an auto-numbered row index column.
+    show_row_numbers: bool,
+    /// Number shown for the first row when `show_row_numbers` is set.
+    row_number_start: usize,
+    /// Stack header characters vertically, one per line.
+    vertical_headers: bool,
 }
 
 impl Default for Table {
@@ -442,7 +613,7 @@ impl Default for Table {
             caption: None,
             width: None,
             min_width: None,
-            box_style: &HEAVY_HEAD,
+            box_style: Some(&HEAVY_HEAD),
             safe_box: None,
             padding: (1, 0),
             collapse_padding: false,
@@ -453,6 +624,7 @@ impl Default for Table {
             show_edge: true,
             show_lines: false,
             leading: 0,
+            no_wrap: false,
             style: Style::new(),
             row_styles: Vec::new(),
             header_style: Style::new().bold(),
@@ -462,6 +634,10 @@ impl Default for Table {
             caption_style: Style::new(),
             title_justify: JustifyMethod::Center,
             caption_justify: JustifyMethod::Center,
+            ellipsis_style: None,
+            show_row_numbers: false,
+            row_number_start: 1,
+            vertical_headers: false,
         }
     }
 }
@@ -473,6 +649,32 @@ impl Table {
         Self::default()
     }
 
+    /// Create a borderless grid for laying out content in columns.
+    ///
+    /// Mirrors Python Rich's `Table.grid()`: no box, header, or edges, and no
+    /// cell padding. Column widths, expansion, and ratios still apply.
+    ///
+    /// ```
+    /// use rich_rust::renderables::table::{Column, Table};
+    ///
+    /// let mut grid = Table::grid()
+    ///     .with_column(Column::new(""))
+    ///     .with_column(Column::new(""));
+    /// grid.add_row_cells(["left", "right"]);
+    /// assert_eq!(grid.render_plain(40), "leftright\n");
+    /// ```
+    #[must_use]
+    pub fn grid() -> Self {
+        Self {
+            box_style: None,
+            padding: (0, 0),
+            pad_edge: false,
+            show_header: false,
+            show_edge: false,
+            ..Self::default()
+        }
+    }
+
     /// Add a column to the table.
     pub fn add_column(&mut self, column: Column) {
         self.columns.push(column);
@@ -497,6 +699,41 @@ impl Table {
         self
     }
 
+    /// Build a table body by mapping each item to its row cells.
+    ///
+    /// No columns are created; add them with [`Table::with_column`].
+    ///
+    /// ```
+    /// use rich_rust::renderables::table::{Cell, Column, Table};
+    ///
+    /// let people = [("Alice", 30), ("Bob", 25)];
+    /// let table = Table::from_iter_rows(&people, |(name, age)| {
+    ///     vec![Cell::new(*name), Cell::new(age.to_string())]
+    /// })
+    /// .with_column(Column::new("Name"))
+    /// .with_column(Column::new("Age"));
+    /// assert!(table.render_plain(40).contains("Alice"));
+    /// ```
+    #[must_use]
+    pub fn from_iter_rows<'t, T: 't>(
+        items: impl IntoIterator<Item = &'t T>,
+        mut to_cells: impl FnMut(&T) -> Vec<Cell>,
+    ) -> Self {
+        let mut table = Self::new();
+        table.add_rows(items.into_iter().map(|item| Row::new(to_cells(item))));
+        table
+    }
+
+    /// Build a table with one column per [`ToRow::columns`] header and one
+    /// row per item.
+    #[must_use]
+    pub fn of<T: ToRow>(items: &[T]) -> Self {
+        Self::from_iter_rows(items, |item| {
+            item.cells().into_iter().map(Cell::new).collect()
+        })
+        .with_columns(T::columns().into_iter().map(Column::new))
+    }
+
     /// Add a row to the table.
     pub fn add_row(&mut self, row: Row) {
         self.rows.push(row);
@@ -565,6 +802,44 @@ impl Table {
         self
     }
 
+    /// Hide the columns at `indices`, showing all others.
+    ///
+    /// Out-of-range indices are ignored. See [`Column::visible`].
+    #[must_use]
+    pub fn hidden_columns(mut self, indices: &[usize]) -> Self {
+        for (i, column) in self.columns.iter_mut().enumerate() {
+            column.visible = !indices.contains(&i);
+        }
+        self
+    }
+
+    /// Prepend a dim, right-aligned `#` column numbering the rows.
+    ///
+    /// Numbering starts at 1 unless changed with [`Table::row_number_start`].
+    #[must_use]
+    pub fn show_row_numbers(mut self, show: bool) -> Self {
+        self.show_row_numbers = show;
+        self
+    }
+
+    /// Set the number shown for the first row of the index column.
+    #[must_use]
+    pub fn row_number_start(mut self, start: usize) -> Self {
+        self.row_number_start = start;
+        self
+    }
+
+    /// Stack each header's characters vertically, one per line.
+    ///
+    /// Headers no longer dictate column widths, so wide matrices of short
+    /// values stay narrow. Shorter headers are bottom-aligned so every label
+    /// ends directly above its column.
+    #[must_use]
+    pub fn vertical_headers(mut self, vertical: bool) -> Self {
+        self.vertical_headers = vertical;
+        self
+    }
+
     /// Set the title.
     #[must_use]
     pub fn title(mut self, title: impl Into<Text>) -> Self {
@@ -596,14 +871,21 @@ impl Table {
     /// Set the box style.
     #[must_use]
     pub fn box_style(mut self, style: &'static BoxChars) -> Self {
-        self.box_style = style;
+        self.box_style = Some(style);
+        self
+    }
+
+    /// Render without a box (no borders or column dividers).
+    #[must_use]
+    pub fn no_box(mut self) -> Self {
+        self.box_style = None;
         self
     }
 
     /// Use ASCII boxes.
     #[must_use]
     pub fn ascii(mut self) -> Self {
-        self.box_style = &ASCII;
+        self.box_style = Some(&ASCII);
         self.safe_box = Some(true);
         self
     }
@@ -678,6 +960,16 @@ impl Table {
         self
     }
 
+    /// Crop every cell to a single line instead of wrapping.
+    ///
+    /// Keeps each row one line tall; see [`Column::no_wrap`] to enable this
+    /// for individual columns.
+    #[must_use]
+    pub fn no_wrap(mut self, no_wrap: bool) -> Self {
+        self.no_wrap = no_wrap;
+        self
+    }
+
     /// Set border style.
     #[must_use]
     pub fn border_style(mut self, style: Style) -> Self {
@@ -727,14 +1019,37 @@ impl Table {
         self
     }
 
+    /// Set the style for truncation markers in cut-off cells.
+    ///
+    /// Applies to the `…` left by `no_wrap` columns and the `...` left by
+    /// [`OverflowMethod::Ellipsis`] columns; the rest of the cell keeps its
+    /// own style.
+    #[must_use]
+    pub fn ellipsis_style(mut self, style: Style) -> Self {
+        self.ellipsis_style = Some(style);
+        self
+    }
+
     /// Get the effective box characters.
-    fn effective_box(&self) -> &'static BoxChars {
+    fn effective_box(&self) -> Option<&'static BoxChars> {
         let safe = self.safe_box.unwrap_or(false);
-        if safe && !self.box_style.ascii {
-            &ASCII
-        } else {
-            self.box_style
-        }
+        self.box_style.map(|box_style| {
+            if safe && !box_style.ascii {
+                &ASCII
+            } else {
+                box_style
+            }
+        })
+    }
+
+    /// Whether the outer edges are drawn (requires a box).
+    fn edge_visible(&self) -> bool {
+        self.show_edge && self.box_style.is_some()
+    }
+
+    /// Width of a single column divider (zero without a box).
+    fn divider_width(&self) -> usize {
+        usize::from(self.box_style.is_some())
     }
 
     /// Calculate column widths.
@@ -747,12 +1062,12 @@ impl Table {
         let base_max_width = self.width.unwrap_or(max_width).min(max_width);
 
         // Calculate overhead (borders + padding)
-        let border_width = if self.show_edge { 2 } else { 0 };
+        let border_width = if self.edge_visible() { 2 } else { 0 };
         let separator_width = if num_cols > 1 {
             if self.collapse_padding {
-                num_cols - 1
+                (num_cols - 1) * self.divider_width()
             } else {
-                (num_cols - 1) * (1 + self.padding.0 * 2)
+                (num_cols - 1) * (self.divider_width() + self.padding.0 * 2)
             }
         } else {
             0
@@ -847,13 +1162,36 @@ impl Table {
             })
             .collect();
 
+        // Wrapping columns give way first, widest first, as in Python Rich.
+        let wrappable: Vec<bool> = self
+            .columns
+            .iter()
+            .map(|col| !self.no_wrap && !col.no_wrap && !col.truncates_at_cap())
+            .collect();
+        let excess = Self::collapse_widest(&mut result, &minimums, &wrappable, excess);
+        if excess == 0 {
+            return result;
+        }
+        let available = result.iter().sum::<usize>() - excess;
+
         // Calculate shrinkable amount per column
-        let shrinkable: Vec<usize> = result
+        let mut shrinkable: Vec<usize> = result
             .iter()
             .zip(minimums.iter())
             .map(|(w, m)| w.saturating_sub(*m))
             .collect();
 
+        // Columns capped with `max_width` that truncate rather than wrap keep
+        // their cap while the remaining columns can absorb the excess.
+        let flexible: Vec<usize> = shrinkable
+            .iter()
+            .zip(&self.columns)
+            .map(|(&shrink, col)| if col.truncates_at_cap() { 0 } else { shrink })
+            .collect();
+        if flexible.iter().sum::<usize>() >= excess {
+            shrinkable = flexible;
+        }
+
         let total_shrinkable: usize = shrinkable.iter().sum();
         if total_shrinkable == 0 {
             return result;
@@ -877,7 +1215,7 @@ impl Table {
                 if diff == 0 {
                     break;
                 }
-                if result[i] > minimums[i] {
+                if shrinkable[i] > 0 && result[i] > minimums[i] {
                     let can_remove = (result[i] - minimums[i]).min(diff);
                     result[i] -= can_remove;
                     diff -= can_remove;
@@ -888,6 +1226,57 @@ impl Table {
         result
     }
 
+    /// Shave up to `excess` cells off the widest `eligible` columns, never
+    /// going below `minimums`. Returns the excess left over.
+    fn collapse_widest(
+        widths: &mut [usize],
+        minimums: &[usize],
+        eligible: &[bool],
+        mut excess: usize,
+    ) -> usize {
+        while excess > 0 {
+            let candidates: Vec<usize> = (0..widths.len())
+                .filter(|&i| eligible.get(i).copied().unwrap_or(false))
+                .filter(|&i| widths[i] > minimums.get(i).copied().unwrap_or(1))
+                .collect();
+            let Some(widest) = candidates.iter().map(|&i| widths[i]).max() else {
+                break;
+            };
+            let group: Vec<usize> = candidates
+                .iter()
+                .copied()
+                .filter(|&i| widths[i] == widest)
+                .collect();
+            let next = candidates
+                .iter()
+                .map(|&i| widths[i])
+                .filter(|&w| w < widest)
+                .max()
+                .unwrap_or(0);
+            let floor = group
+                .iter()
+                .map(|&i| minimums.get(i).copied().unwrap_or(1))
+                .max()
+                .unwrap_or(0)
+                .max(next);
+            let step = widest.saturating_sub(floor).max(1);
+
+            let per_column = step.min(excess / group.len());
+            if per_column > 0 {
+                for &i in &group {
+                    widths[i] -= per_column;
+                }
+                excess -= per_column * group.len();
+            } else {
+                for &i in group.iter().take(excess) {
+                    widths[i] -= 1;
+                }
+                excess = excess.saturating_sub(group.len());
+            }
+        }
+        excess
+    }
+
     /// Expand column widths to fill available space.
     fn expand_widths(&self, widths: &[usize], available: usize) -> Vec<usize> {
         let total: usize = widths.iter().sum();
@@ -971,9 +1360,131 @@ impl Table {
         sizes
     }
 
+    /// Copy of the table with row numbers, number formats, hidden columns
+    /// and (when `stack_headers`) vertical headers applied, or `None` when
+    /// none of them are in use.
+    ///
+    /// The copy has those options switched off, so rendering it doesn't
+    /// project again.
+    fn projection(&self, stack_headers: bool) -> Option<Self> {
+        let number_rows = self.show_row_numbers;
+        let format_numbers = self.columns.iter().any(|c| c.number_format.is_some());
+        let hide_columns = self.columns.iter().any(|c| !c.visible);
+        let stack_headers = stack_headers && self.vertical_headers && self.show_header;
+        if !(number_rows || format_numbers || hide_columns || stack_headers) {
+            return None;
+        }
+
+        let mut projected = self.clone();
+        if number_rows {
+            projected.insert_row_numbers();
+        }
+        if format_numbers {
+            projected.format_numbers();
+        }
+        if hide_columns {
+            projected.drop_hidden_columns();
+        }
+        if stack_headers {
+            projected.stack_headers();
+        }
+        Some(projected)
+    }
+
+    /// Drop hidden columns and their cells.
+    fn drop_hidden_columns(&mut self) {
+        let keep: Vec<bool> = self.columns.iter().map(|c| c.visible).collect();
+        self.columns.retain(|c| c.visible);
+        for row in &mut self.rows {
+            let mut index = 0;
+            row.cells.retain(|_| {
+                let visible = keep.get(index).copied().unwrap_or(false);
+                index += 1;
+                visible
+            });
+        }
+    }
+
+    /// Prepend the row index column.
+    fn insert_row_numbers(&mut self) {
+        self.show_row_numbers = false;
+        self.columns.insert(
+            0,
+            Column::new("#")
+                .justify(JustifyMethod::Right)
+                .style(Style::new().dim()),
+        );
+        for (offset, row) in self.rows.iter_mut().enumerate() {
+            let number = self.row_number_start + offset;
+            row.cells.insert(0, Cell::from(number.to_string()));
+        }
+    }
+
+    /// Format numeric cells per column.
+    fn format_numbers(&mut self) {
+        let formats: Vec<Option<NumberFormat>> = self
+            .columns
+            .iter_mut()
+            .map(|c| c.number_format.take())
+            .collect();
+        for row in &mut self.rows {
+            for (cell, format) in row.cells.iter_mut().zip(&formats) {
+                let Some(number) = format.and_then(|f| f.apply(cell.content.plain())) else {
+                    continue;
+                };
+                // Digits move around, so only styles covering the whole cell
+                // carry over.
+                let len = cell.content.len();
+                let mut content = cell.content.slice(0, 0);
+                content.set_style(cell.content.style().clone());
+                content.append(&number);
+                for span in cell.content.spans() {
+                    if span.start == 0 && span.end >= len {
+                        content.stylize_all(span.style.clone());
+                    }
+                }
+                cell.content = content;
+            }
+        }
+    }
+
+    /// Stack each header one grapheme per line, bottom-aligned.
+    fn stack_headers(&mut self) {
+        self.vertical_headers = false;
+        let height = self
+            .columns
+            .iter()
+            .map(|c| c.header.plain().graphemes(true).count())
+            .max()
+            .unwrap_or(0);
+        for column in &mut self.columns {
+            let graphemes: Vec<usize> = column
+                .header
+                .plain()
+                .graphemes(true)
+                .map(|g| g.chars().count())
+                .collect();
+            let mut header = column.header.slice(0, 0);
+            header.append(&"\n".repeat(height - graphemes.len()));
+            let mut start = 0;
+            for (index, len) in graphemes.into_iter().enumerate() {
+                if index > 0 {
+                    header.append("\n");
+                }
+                header.append_text(&column.header.slice(start, start + len));
+                start += len;
+            }
+            column.header = header;
+        }
+    }
+
     /// Render the table to segments.
     #[must_use]
     pub fn render(&self, max_width: usize) -> Vec<Segment<'static>> {
+        if let Some(projected) = self.projection(true) {
+            return projected.render(max_width);
+        }
+
         let box_chars = self.effective_box();
         let widths = self.calculate_widths(max_width);
 
@@ -998,7 +1509,9 @@ impl Table {
         }
 
         // Top border
-        if self.show_edge {
+        if let Some(box_chars) = box_chars
+            && self.edge_visible()
+        {
             let top = self.build_separator(box_chars, &widths, RowLevel::Top);
             segments.push(Segment::new(top, Some(self.border_style.clone())));
             segments.push(Segment::line());
@@ -1055,18 +1568,21 @@ impl Table {
             }
 
             // Header separator
-            let sep = self.build_separator(box_chars, &widths, RowLevel::HeadRow);
-            segments.push(Segment::new(sep, Some(self.border_style.clone())));
-            segments.push(Segment::line());
+            if let Some(box_chars) = box_chars {
+                let sep = self.build_separator(box_chars, &widths, RowLevel::HeadRow);
+                segments.push(Segment::new(sep, Some(self.border_style.clone())));
+                segments.push(Segment::line());
+            }
         }
 
         // Data rows
         for (row_idx, row) in self.rows.iter().enumerate() {
             let row_style = if self.row_styles.is_empty() {
-                &row.style
+                row.style.clone()
             } else {
-                &self.row_styles[row_idx % self.row_styles.len()]
+                self.row_styles[row_idx % self.row_styles.len()].combine(&row.style)
             };
+            let row_style = &row_style;
 
             // Pad cells to match column count
             let mut cells: Vec<Text> = Vec::with_capacity(self.columns.len());
@@ -1133,7 +1649,10 @@ impl Table {
             }
 
             // Row separator (if show_lines or end_section)
-            if (self.show_lines || row.end_section) && !is_last {
+            if let Some(box_chars) = box_chars
+                && (self.show_lines || row.end_section)
+                && !is_last
+            {
                 let sep = self.build_separator(box_chars, &widths, RowLevel::Row);
                 segments.push(Segment::new(sep, Some(self.border_style.clone())));
                 segments.push(Segment::line());
@@ -1143,9 +1662,11 @@ impl Table {
         // Footer
         if self.show_footer && !self.columns.is_empty() {
             // Footer separator
-            let sep = self.build_separator(box_chars, &widths, RowLevel::FootRow);
-            segments.push(Segment::new(sep, Some(self.border_style.clone())));
-            segments.push(Segment::line());
+            if let Some(box_chars) = box_chars {
+                let sep = self.build_separator(box_chars, &widths, RowLevel::FootRow);
+                segments.push(Segment::new(sep, Some(self.border_style.clone())));
+                segments.push(Segment::line());
+            }
 
             let footer_cells: Vec<&Text> = self.columns.iter().map(|c| &c.footer).collect();
             let footer_styles: Vec<&Style> = self.columns.iter().map(|c| &c.footer_style).collect();
@@ -1185,7 +1706,9 @@ impl Table {
         }
 
         // Bottom border
-        if self.show_edge {
+        if let Some(box_chars) = box_chars
+            && self.edge_visible()
+        {
             let bottom = self.build_separator(box_chars, &widths, RowLevel::Bottom);
             segments.push(Segment::new(bottom, Some(self.border_style.clone())));
             segments.push(Segment::line());
@@ -1270,15 +1793,15 @@ impl Table {
         let content: usize = widths.iter().sum();
         let separators = if widths.len() > 1 {
             if self.collapse_padding {
-                widths.len() - 1
+                (widths.len() - 1) * self.divider_width()
             } else {
-                (widths.len() - 1) * (1 + self.padding.0 * 2)
+                (widths.len() - 1) * (self.divider_width() + self.padding.0 * 2)
             }
         } else {
             0
         };
         let edge_padding = if self.pad_edge { self.padding.0 * 2 } else { 0 };
-        let edges = if self.show_edge { 2 } else { 0 };
+        let edges = if self.edge_visible() { 2 } else { 0 };
         content + separators + edge_padding + edges
     }
 
@@ -1286,7 +1809,7 @@ impl Table {
     #[allow(clippy::too_many_arguments)]
     fn render_row_content(
         &self,
-        box_chars: &BoxChars,
+        box_chars: Option<&BoxChars>,
         widths: &[usize],
         cells: &[&Text],
         cell_styles: &[&Style],
@@ -1297,24 +1820,19 @@ impl Table {
         let mut segments = Vec::new();
         let pad_str = " ".repeat(self.padding.0);
         let last_idx = widths.len().saturating_sub(1);
-        let cell_chars = match row_level {
+        let cell_chars = box_chars.map(|box_chars| match row_level {
             RowLevel::HeadRow => &box_chars.head,
             _ => &box_chars.foot,
-        };
+        });
 
         // Prepare cell content (split into lines)
         let mut row_cells_lines: Vec<Vec<Vec<Segment<'static>>>> = Vec::with_capacity(widths.len());
         let mut max_height = 0;
 
         for (i, (&width, &cell)) in widths.iter().zip(cells.iter()).enumerate() {
-            let cell_style = cell_styles.get(i).copied().unwrap_or(&self.style);
-            let override_style = cell_overrides.get(i).and_then(|style| style.as_ref());
-
-            let mut combined_style = self.style.combine(row_style).combine(cell_style);
-            if let Some(override_style) = override_style {
-                combined_style = combined_style.combine(override_style);
-            }
-            combined_style = combined_style.combine(cell.style());
+            let combined_style = self
+                .layered_cell_style(cell_styles, row_style, cell_overrides, i)
+                .combine(cell.style());
 
             let mut cell_text = cell.clone();
             cell_text.set_style(combined_style.clone());
@@ -1323,14 +1841,37 @@ impl Table {
                 .columns
                 .get(i)
                 .map_or(OverflowMethod::Fold, |c| c.overflow);
+            let no_wrap = self.no_wrap || self.columns.get(i).is_some_and(|c| c.no_wrap);
 
             // Handle wrapping/truncation
             cell_text.overflow = overflow;
 
-            // If overflow is Crop/Ellipsis/Ignore, wrap() handles them (returning single line or truncated line)
-            // If overflow is Fold, wrap() handles wrapping.
-            // Note: wrap() handles explicit newlines via split_lines() internally first.
-            let lines: Vec<Text> = cell_text.wrap(width);
+            // Each line, with the character offset where its truncation marker starts.
+            let lines: Vec<(Text, Option<usize>)> = if no_wrap {
+                // Keep the row one line tall: first line only, cut to fit.
+                let line = match overflow {
+                    OverflowMethod::Fold | OverflowMethod::Ellipsis => {
+                        let (line, truncated) = cell_text.fit(width);
+                        let marker = (truncated && width > 0).then(|| line.len() - 1);
+                        (line, marker)
+                    }
+                    OverflowMethod::Crop | OverflowMethod::Ignore => {
+                        let mut line = cell_text
+                            .split_lines()
+                            .into_iter()
+                            .next()
+                            .unwrap_or_else(|| Text::new(""));
+                        line.truncate(width, OverflowMethod::Crop, false);
+                        (line, None)
+                    }
+                };
+                vec![line]
+            } else {
+                // If overflow is Crop/Ellipsis/Ignore, wrap() handles them (returning single line or truncated line)
+                // If overflow is Fold, wrap() handles wrapping.
+                // Note: wrap() handles explicit newlines via split_lines() internally first.
+                cell_text.wrap_with_markers(width)
+            };
 
             let justify = self
                 .columns
@@ -1338,7 +1879,13 @@ impl Table {
                 .map_or(JustifyMethod::Left, |c| c.justify);
             let mut cell_lines_segments = Vec::with_capacity(lines.len());
 
-            for mut line in lines {
+            for (mut line, marker) in lines {
+                if let Some(ellipsis_style) = &self.ellipsis_style
+                    && let Some(start) = marker
+                {
+                    let end = line.len();
+                    line.stylize(start, end, ellipsis_style.clone());
+                }
                 if line.cell_len() < width {
                     line.pad(width, justify);
                     // Re-apply style to ensure padding gets background color
@@ -1366,7 +1913,9 @@ impl Table {
 
         for h in 0..max_height {
             // Left edge
-            if self.show_edge {
+            if let Some(cell_chars) = cell_chars
+                && self.show_edge
+            {
                 segments.push(Segment::new(
                     cell_chars[0].to_string(),
                     Some(self.border_style.clone()),
@@ -1374,15 +1923,11 @@ impl Table {
             }
 
             for (i, (&width, cell_lines)) in widths.iter().zip(row_cells_lines.iter()).enumerate() {
-                // Reconstruct style for padding (needed if cell line is empty/missing)
-                let cell_style = cell_styles.get(i).copied().unwrap_or(&self.style);
-                let override_style = cell_overrides.get(i).and_then(|style| style.as_ref());
-                let mut combined_style = self.style.combine(row_style).combine(cell_style);
-                if let Some(override_style) = override_style {
-                    combined_style = combined_style.combine(override_style);
-                }
-                // Also combine with base cell style for consistency (background color)
-                combined_style = combined_style.combine(cells[i].style());
+                // Reconstruct style for padding (needed if cell line is empty/missing),
+                // including the content's base style for background color.
+                let combined_style = self
+                    .layered_cell_style(cell_styles, row_style, cell_overrides, i)
+                    .combine(cells[i].style());
 
                 // Left padding
                 let pad_left = if self.collapse_padding {
@@ -1417,7 +1962,9 @@ impl Table {
                 }
 
                 // Cell divider
-                if i < widths.len() - 1 {
+                if let Some(cell_chars) = cell_chars
+                    && i < widths.len() - 1
+                {
                     segments.push(Segment::new(
                         cell_chars[2].to_string(),
                         Some(self.border_style.clone()),
@@ -1426,7 +1973,9 @@ impl Table {
             }
 
             // Right edge
-            if self.show_edge {
+            if let Some(cell_chars) = cell_chars
+                && self.show_edge
+            {
                 segments.push(Segment::new(
                     cell_chars[3].to_string(),
                     Some(self.border_style.clone()),
@@ -1440,11 +1989,31 @@ impl Table {
 
         segments
     }
+
+    /// Compose the style under a cell's content.
+    ///
+    /// Layers apply in the order table < column < row < cell, so a cell
+    /// style wins over its row, and a row style over its column.
+    fn layered_cell_style(
+        &self,
+        column_styles: &[&Style],
+        row_style: &Style,
+        cell_overrides: &[Option<Style>],
+        index: usize,
+    ) -> Style {
+        let column_style = column_styles.get(index).copied().unwrap_or(&self.style);
+        let mut style = self.style.combine(column_style).combine(row_style);
+        if let Some(cell_style) = cell_overrides.get(index).and_then(Option::as_ref) {
+            style = style.combine(cell_style);
+        }
+        style
+    }
+
     /// Render multiple leading blank lines between rows.
     #[allow(clippy::too_many_arguments)]
     fn render_leading_lines(
         &self,
-        box_chars: &BoxChars,
+        box_chars: Option<&BoxChars>,
         widths: &[usize],
         row_style: &Style,
         cell_styles: &[&Style],
@@ -1544,11 +2113,76 @@ impl Table {
     /// Render to plain text.
     #[must_use]
     pub fn render_plain(&self, max_width: usize) -> String {
-        self.render(max_width)
-            .into_iter()
-            .map(|seg| seg.text)
-            .collect()
+        segments_to_plain(&self.render(max_width))
     }
+
+    /// Export the table as a GitHub-flavored Markdown table.
+    ///
+    /// The header row is followed by an alignment row taken from each
+    /// column's justification (`:---`, `---:`, `:---:`). Borders, styles,
+    /// title and caption are dropped; pipes in cells are escaped and line
+    /// breaks become `<br>`.
+    #[must_use]
+    pub fn to_markdown(&self) -> String {
+        if let Some(projected) = self.projection(false) {
+            return projected.to_markdown();
+        }
+        if self.columns.is_empty() {
+            return String::new();
+        }
+
+        let mut out = String::new();
+        let header: Vec<String> = self
+            .columns
+            .iter()
+            .map(|c| markdown_cell(c.header.plain()))
+            .collect();
+        push_markdown_row(&mut out, &header);
+
+        let alignment: Vec<String> = self
+            .columns
+            .iter()
+            .map(|c| {
+                match c.justify {
+                    JustifyMethod::Left => ":---",
+                    JustifyMethod::Right => "---:",
+                    JustifyMethod::Center => ":---:",
+                    JustifyMethod::Default | JustifyMethod::Full => "---",
+                }
+                .to_string()
+            })
+            .collect();
+        push_markdown_row(&mut out, &alignment);
+
+        for row in &self.rows {
+            let cells: Vec<String> = (0..self.columns.len())
+                .map(|i| {
+                    row.cells
+                        .get(i)
+                        .map_or_else(String::new, |cell| markdown_cell(cell.content.plain()))
+                })
+                .collect();
+            push_markdown_row(&mut out, &cells);
+        }
+        out
+    }
+}
+
+/// Escape cell text for a Markdown table: pipes are escaped and line breaks
+/// become `<br>` so each row stays on one line.
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// Append `| a | b |` and a newline to `out`.
+fn push_markdown_row(out: &mut String, cells: &[String]) {
+    out.push('|');
+    for cell in cells {
+        out.push(' ');
+        out.push_str(cell);
+        out.push_str(" |");
+    }
+    out.push('\n');
 }
 
 impl Renderable for Table {
@@ -1558,7 +2192,8 @@ impl Renderable for Table {
         }
 
         // Inherit the Console's safe_box setting unless explicitly overridden.
-        let effective = self.clone().safe_box(console.safe_box());
+        let safe = console.safe_box() || !console.supports_unicode();
+        let effective = self.clone().safe_box(safe);
         effective.render(options.max_width).into_iter().collect()
     }
 }
@@ -1621,6 +2256,328 @@ mod tests {
         assert!(text.contains("30"));
     }
 
+    #[test]
+    fn test_table_grid_has_no_border_glyphs() {
+        let mut grid = Table::grid()
+            .padding(1, 0)
+            .with_column(Column::new(""))
+            .with_column(Column::new("").justify(JustifyMethod::Right));
+        grid.add_row(Row::new(vec![
+            Cell::new(Text::new("status")),
+            Cell::new(Text::new("ok")),
+        ]));
+        grid.add_row_cells(["uptime", "3d"]);
+
+        let output = grid.render_plain(40);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines, vec!["status  ok", "uptime  3d"]);
+        assert!(!output.chars().any(|c| "┃━┏┓┗┛│─+|".contains(c)));
+    }
+
+    #[test]
+    fn test_table_grid_expand_uses_ratios() {
+        let mut grid = Table::grid()
+            .expand(true)
+            .with_column(Column::new("").ratio(1))
+            .with_column(Column::new("").ratio(1));
+        grid.add_row_cells(["a", "b"]);
+
+        let output = grid.render_plain(10);
+        assert_eq!(output, "a    b    \n");
+    }
+
+    #[test]
+    fn test_column_number_format() {
+        let mut table = Table::new().with_column(Column::new("Item")).with_column(
+            Column::new("Amount").number_format(NumberFormat {
+                thousands_separator: Some(','),
+                decimals: 2,
+            }),
+        );
+        table.add_row_cells(["a", "1234.5"]);
+        table.add_row_cells(["b", "n/a"]);
+        table.add_row_cells(["c", "-7"]);
+
+        let output = table.render_plain(80);
+        let cells: Vec<&str> = output
+            .lines()
+            .filter(|l| l.contains('│'))
+            .map(|l| l.split('│').nth(2).unwrap())
+            .collect();
+        assert_eq!(cells, vec![" 1,234.50 ", "      n/a ", "    -7.00 "]);
+
+        let plain = NumberFormat {
+            thousands_separator: None,
+            decimals: 0,
+        };
+        assert_eq!(plain.apply("1234567.6").as_deref(), Some("1234568"));
+        assert_eq!(
+            plain.apply("12345678901234567890").as_deref(),
+            Some("12345678901234567890")
+        );
+        assert_eq!(plain.apply("999.5").as_deref(), Some("1000"));
+        assert_eq!(plain.apply("-0.4").as_deref(), Some("0"));
+        let grouped = NumberFormat {
+            thousands_separator: Some('_'),
+            decimals: 3,
+        };
+        assert_eq!(
+            grouped.apply("+12345678901234567890.12345").as_deref(),
+            Some("12_345_678_901_234_567_890.123")
+        );
+        assert_eq!(grouped.apply(".5").as_deref(), Some("0.500"));
+
+        let mut styled = Table::new().with_column(Column::new("N").number_format(plain));
+        styled.add_row(Row::new(vec![Cell::from_markup("[bold]12[/]34")]));
+        styled.add_row(Row::new(vec![Cell::from_markup("[red]5678[/]")]));
+        let projected = styled.projection(false).expect("number format applies");
+        assert!(projected.rows[0].cells[0].content.spans().is_empty());
+        assert_eq!(projected.rows[1].cells[0].content.spans().len(), 1);
+        assert_eq!(plain.apply("12e3"), None);
+        assert_eq!(plain.apply("."), None);
+    }
+
+    #[test]
+    fn test_ellipsis_style_applies_to_truncation_marker() {
+        let cell_style = Style::new().bold();
+        let mut table = Table::new()
+            .with_column(Column::new("Description").width(8).no_wrap())
+            .with_column(
+                Column::new("Notes")
+                    .width(8)
+                    .overflow(OverflowMethod::Ellipsis),
+            )
+            .ellipsis_style(Style::new().dim());
+        table.add_row(Row::new(vec![
+            Cell::new("truncated text").style(cell_style.clone()),
+            Cell::new("also far too long"),
+        ]));
+        table.add_row_cells(["short", "fits"]);
+
+        let segments = table.render(80);
+        let marker = segments
+            .iter()
+            .find(|s| s.text == "…")
+            .expect("no_wrap cell should end in a marker");
+        let marker_style = marker.style.clone().unwrap_or_default();
+        assert!(marker_style.attributes.contains(Attributes::DIM));
+        let before = segments
+            .iter()
+            .find(|s| s.text.starts_with("truncat"))
+            .expect("cell text");
+        let before_style = before.style.clone().unwrap_or_default();
+        assert!(before_style.attributes.contains(Attributes::BOLD));
+        assert!(!before_style.attributes.contains(Attributes::DIM));
+
+        let dots = segments
+            .iter()
+            .find(|s| s.text == "...")
+            .expect("ellipsis column should end in a marker");
+        assert!(
+            dots.style
+                .as_ref()
+                .is_some_and(|s| s.attributes.contains(Attributes::DIM))
+        );
+        // Cells that fit have no dimmed text.
+        assert!(
+            segments
+                .iter()
+                .filter(|s| s.text.contains("fits"))
+                .all(|s| {
+                    !s.style
+                        .as_ref()
+                        .is_some_and(|st| st.attributes.contains(Attributes::DIM))
+                })
+        );
+    }
+
+    #[test]
+    fn test_ellipsis_style_marks_only_the_truncation_marker() {
+        let mut table = Table::new()
+            .with_column(
+                Column::new("Notes")
+                    .width(8)
+                    .overflow(OverflowMethod::Ellipsis),
+            )
+            .ellipsis_style(Style::new().dim());
+        table.add_row_cells(["wait...\nab\tcd\tef"]);
+
+        let segments = table.render(80);
+        let is_dim = |s: &Segment<'_>| {
+            s.style
+                .as_ref()
+                .is_some_and(|st| st.attributes.contains(Attributes::DIM))
+        };
+        let dimmed: Vec<&str> = segments
+            .iter()
+            .filter(|s| is_dim(s))
+            .map(|s| s.text.as_ref())
+            .collect();
+        assert_eq!(dimmed, ["..."]);
+        let literal = segments
+            .iter()
+            .find(|s| s.text.starts_with("wait"))
+            .expect("cell text");
+        assert!(literal.text.contains("..."));
+    }
+
+    #[test]
+    fn test_no_wrap_crops_cells_with_ellipsis() {
+        let long = "a very long description that would normally wrap";
+        let mut table = Table::new()
+            .with_column(Column::new("Id"))
+            .with_column(Column::new("Description").width(12).no_wrap());
+        table.add_row_cells(["1", long]);
+
+        let output = table.render_plain(80);
+        let rows: Vec<&str> = output.lines().filter(|l| l.contains(" 1 ")).collect();
+        assert_eq!(rows.len(), 1, "{output}");
+        let cell = rows[0].split('│').nth(2).unwrap();
+        assert_eq!(cell.trim_end(), " a very long…");
+        assert_eq!(output.lines().count(), 5, "{output}");
+
+        // The table-level switch applies to every column.
+        let mut table = Table::new()
+            .with_column(Column::new("Description").width(12))
+            .no_wrap(true);
+        table.add_row_cells([long]);
+        assert_eq!(table.render_plain(80).lines().count(), 5);
+    }
+
+    #[test]
+    fn test_table_shrinks_widest_wrapping_columns_to_console_width() {
+        let mut table = Table::new()
+            .with_column(Column::new("ID").no_wrap())
+            .with_column(Column::new("Description"))
+            .with_column(Column::new("Notes"));
+        table.add_row_cells([
+            "abc-123-xyz",
+            "a fairly long description of the item here",
+            "notes that run on for a while",
+        ]);
+
+        let console = Console::builder().width(40).force_terminal(false).build();
+        console.begin_capture();
+        console.print_renderable(&table);
+        let output = segments_to_plain(&console.end_capture());
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert!(lines.iter().all(|line| cells::cell_len(line) <= 40));
+        // The no_wrap column keeps its natural width; the others wrap.
+        assert!(lines.iter().any(|line| line.contains("│ abc-123-xyz │")));
+        let widths: Vec<usize> = lines[0]
+            .trim_matches(|c| c == '┏' || c == '┓')
+            .split('┳')
+            .map(cells::cell_len)
+            .collect();
+        assert_eq!(widths[0], 13);
+        assert!(widths[1] < 44 && widths[2] < 31);
+        assert!(lines.len() > 6);
+    }
+
+    #[test]
+    fn test_table_vertical_headers() {
+        let mut table = Table::new()
+            .with_column(Column::new("ID"))
+            .with_column(Column::new("Age"))
+            .vertical_headers(true);
+        table.add_row_cells(["1", "30"]);
+
+        let output = table.render_plain(80);
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines[0], "┏━━━┳━━━━┓");
+        assert_eq!(lines[1], "┃   ┃ A  ┃");
+        assert_eq!(lines[2], "┃ I ┃ g  ┃");
+        assert_eq!(lines[3], "┃ D ┃ e  ┃");
+        assert_eq!(lines[4], "┡━━━╇━━━━┩");
+        assert_eq!(lines[5], "│ 1 │ 30 │");
+
+        // Combining marks stay with their base character.
+        let table = Table::new()
+            .with_column(Column::new("e\u{301}a"))
+            .with_column(Column::new("Qty").number_format(NumberFormat::default()))
+            .show_row_numbers(true)
+            .vertical_headers(true);
+        let projected = table.projection(true).expect("projection");
+        assert_eq!(projected.columns[1].header.plain(), "\ne\u{301}\na");
+        assert_eq!(projected.columns[2].header.plain(), "Q\nt\ny");
+        assert!(!projected.vertical_headers && !projected.show_row_numbers);
+        assert!(projected.columns.iter().all(|c| c.number_format.is_none()));
+    }
+
+    #[test]
+    fn test_table_show_row_numbers() {
+        let mut table = Table::new()
+            .with_column(Column::new("Name"))
+            .with_column(Column::new("Age"))
+            .show_row_numbers(true);
+        table.add_row_cells(["Alice", "30"]);
+        table.add_row_cells(["Bob", "25"]);
+        table.add_row_cells(["Carol", "41"]);
+
+        let output = table.render_plain(80);
+        let header = output.lines().find(|l| l.contains("Name")).unwrap();
+        let headers: Vec<&str> = header
+            .split('┃')
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+            .collect();
+        assert_eq!(headers, vec!["#", "Name", "Age"]);
+
+        let rows: Vec<Vec<&str>> = output
+            .lines()
+            .filter(|l| l.contains('│'))
+            .map(|l| {
+                l.split('│')
+                    .map(str::trim)
+                    .filter(|c| !c.is_empty())
+                    .collect()
+            })
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                vec!["1", "Alice", "30"],
+                vec!["2", "Bob", "25"],
+                vec!["3", "Carol", "41"],
+            ]
+        );
+
+        let zero_based = table.row_number_start(0).render_plain(80);
+        assert!(zero_based.lines().any(|l| l.starts_with("│ 0 │ Alice")));
+    }
+
+    #[test]
+    fn test_table_hidden_columns_skip_render() {
+        let mut table = Table::new()
+            .with_column(Column::new("Name"))
+            .with_column(Column::new("Detail"))
+            .with_column(Column::new("Age"));
+        table.add_row_cells(["Alice", "secret", "30"]);
+        table.add_row_cells(["Bob", "hidden", "25"]);
+        let table = table.hidden_columns(&[1]);
+
+        let output = table.render_plain(80);
+        assert!(!output.contains("Detail"));
+        assert!(!output.contains("secret"));
+        assert!(!output.contains("hidden"));
+
+        let alice = output.lines().find(|l| l.contains("Alice")).unwrap();
+        let cells: Vec<&str> = alice
+            .split('│')
+            .map(str::trim)
+            .filter(|c| !c.is_empty())
+            .collect();
+        assert_eq!(cells, vec!["Alice", "30"]);
+
+        let header = output.lines().find(|l| l.contains("Name")).unwrap();
+        assert_eq!(header.matches('┃').count(), 3);
+
+        // Showing the column again restores it without touching the data.
+        let shown = table.hidden_columns(&[]).render_plain(80);
+        assert!(shown.contains("secret"));
+    }
+
     #[test]
     fn test_table_leading_without_separators() {
         let mut table = Table::new()
@@ -1863,6 +2820,28 @@ mod tests {
         assert_eq!(cell_len(line), 10);
     }
 
+    #[test]
+    fn test_column_max_width_ellipsis_beside_wrapping_column() {
+        let mut table = Table::new()
+            .with_column(
+                Column::new("URL")
+                    .max_width(10)
+                    .overflow(OverflowMethod::Ellipsis),
+            )
+            .with_column(Column::new("Note"));
+        table.add_row_cells([
+            "https://example.com/a/very/long/path",
+            "wraps onto several lines here",
+        ]);
+
+        let output = table.render_plain(30);
+        let lines: Vec<&str> = output.lines().collect();
+        let url_cell = lines[3].split('│').nth(1).expect("url cell").trim();
+        assert_eq!(url_cell, "https:/...");
+        // The sibling column keeps folding its content across lines.
+        assert!(lines.len() > 6);
+    }
+
     #[test]
     fn test_vertical_align() {
         let col = Column::new("Test").vertical(VerticalAlign::Middle);
@@ -1875,6 +2854,50 @@ mod tests {
         assert_eq!(cell.content.plain(), "Hello");
     }
 
+    #[test]
+    fn test_add_rows_appends_each_row() {
+        let mut table = Table::new().with_column(Column::new("N"));
+        table.add_rows((1..=3).map(|n| Row::new(vec![Cell::new(n.to_string())])));
+        assert_eq!(table.rows.len(), 3);
+
+        let output = table.render_plain(20);
+        for n in ["1", "2", "3"] {
+            assert!(output.lines().any(|line| line.contains(n)));
+        }
+    }
+
+    #[test]
+    fn test_from_iter_rows_maps_items_to_cells() {
+        struct Package {
+            name: &'static str,
+            version: (u32, u32),
+        }
+
+        let packages = vec![
+            Package {
+                name: "serde",
+                version: (1, 0),
+            },
+            Package {
+                name: "regex",
+                version: (1, 11),
+            },
+        ];
+        let table = Table::from_iter_rows(&packages, |pkg| {
+            vec![
+                Cell::new(pkg.name),
+                Cell::new(format!("{}.{}", pkg.version.0, pkg.version.1)),
+            ]
+        })
+        .with_column(Column::new("Crate"))
+        .with_column(Column::new("Version"));
+
+        assert_eq!(table.rows.len(), 2);
+        assert_eq!(table.rows[1].cells[0].content.plain(), "regex");
+        assert_eq!(table.rows[1].cells[1].content.plain(), "1.11");
+        assert!(table.render_plain(40).contains("serde"));
+    }
+
     #[test]
     fn test_row_end_section() {
         let row = Row::new(vec![Cell::new("X")]).end_section();
@@ -1900,6 +2923,55 @@ mod tests {
         assert_eq!(style.color, red.color);
     }
 
+    #[test]
+    fn test_table_to_markdown() {
+        let table = Table::new()
+            .with_column(Column::new("Name"))
+            .with_column(Column::new("Price").justify(JustifyMethod::Right))
+            .with_row_cells(["a|b", "1.50"])
+            .with_row_cells(["two\nlines", "20"])
+            .title("Ignored");
+
+        assert_eq!(
+            table.to_markdown(),
+            "| Name | Price |\n\
+             | :--- | ---: |\n\
+             | a\\|b | 1.50 |\n\
+             | two<br>lines | 20 |\n"
+        );
+    }
+
+    #[test]
+    fn test_table_style_layers_column_row_cell() {
+        let blue = Style::new().color(Color::parse("blue").unwrap());
+        let red = Style::new().color(Color::parse("red").unwrap());
+
+        let mut table = Table::new()
+            .with_column(Column::new("A").style(blue.clone()))
+            .with_column(Column::new("B").style(blue.clone()));
+        table.add_row(
+            Row::new(vec![Cell::new("X").style(red.clone()), Cell::new("Y")])
+                .style(Style::new().bold()),
+        );
+
+        let segments = table.render(20);
+        let style_of = |needle: char| {
+            segments
+                .iter()
+                .find(|seg| seg.text.contains(needle))
+                .and_then(|seg| seg.style.clone())
+                .expect("expected styled cell segment")
+        };
+
+        let cell = style_of('X');
+        assert!(cell.attributes.contains(Attributes::BOLD));
+        assert_eq!(cell.color, red.color);
+
+        let plain_cell = style_of('Y');
+        assert!(plain_cell.attributes.contains(Attributes::BOLD));
+        assert_eq!(plain_cell.color, blue.color);
+    }
+
     #[test]
     fn test_table_preserves_text_spans() {
         let mut text = Text::new("ab");
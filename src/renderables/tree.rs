@@ -3,12 +3,19 @@
 //! This module provides tree components for displaying hierarchical data
 //! in the terminal with configurable guide characters and styles.
 
+use std::fmt;
+use std::sync::Arc;
+
+use crate::cells;
 use crate::console::{Console, ConsoleOptions};
 use crate::renderables::Renderable;
-use crate::segment::Segment;
+use crate::segment::{Segment, pop_trailing_empty_line, segments_to_plain, split_lines};
 use crate::style::Style;
 use crate::text::Text;
 
+/// Width used for renderable nodes when the tree is rendered without a console.
+const DEFAULT_CONTENT_WIDTH: usize = 80;
+
 /// Guide character styles for tree rendering.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum TreeGuides {
@@ -69,10 +76,12 @@ impl TreeGuides {
 }
 
 /// A node in the tree.
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct TreeNode {
     /// The label for this node.
     label: Text,
+    /// Renderable shown in place of the label.
+    content: Option<Arc<dyn Renderable + Send + Sync>>,
     /// Child nodes.
     children: Vec<TreeNode>,
     /// Whether this node is expanded (children visible).
@@ -83,6 +92,19 @@ pub struct TreeNode {
     icon_style: Style,
 }
 
+impl fmt::Debug for TreeNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TreeNode")
+            .field("label", &self.label)
+            .field("content", &self.content.as_ref().map(|_| "<renderable>"))
+            .field("children", &self.children)
+            .field("expanded", &self.expanded)
+            .field("icon", &self.icon)
+            .field("icon_style", &self.icon_style)
+            .finish()
+    }
+}
+
 impl TreeNode {
     /// Create a new tree node with a label.
     ///
@@ -93,6 +115,7 @@ impl TreeNode {
     pub fn new(label: impl Into<Text>) -> Self {
         Self {
             label: label.into(),
+            content: None,
             children: Vec::new(),
             expanded: true,
             icon: None,
@@ -109,6 +132,7 @@ impl TreeNode {
     pub fn with_icon(icon: impl Into<String>, label: impl Into<Text>) -> Self {
         Self {
             label: label.into(),
+            content: None,
             children: Vec::new(),
             expanded: true,
             icon: Some(icon.into()),
@@ -116,6 +140,23 @@ impl TreeNode {
         }
     }
 
+    /// Create a node that displays a renderable (a table, panel, ...) instead
+    /// of a text label.
+    ///
+    /// The renderable is rendered at the width left after the guides; each of
+    /// its lines past the first is indented beneath the node, with the guide
+    /// column continued alongside it.
+    #[must_use]
+    pub fn from_renderable<R>(renderable: R) -> Self
+    where
+        R: Renderable + Send + Sync + 'static,
+    {
+        Self {
+            content: Some(Arc::new(renderable)),
+            ..Self::new("")
+        }
+    }
+
     /// Add a child node.
     #[must_use]
     pub fn child(mut self, node: TreeNode) -> Self {
@@ -294,25 +335,71 @@ impl Tree {
     }
 
     /// Render the tree to segments.
+    ///
+    /// Nodes created with [`TreeNode::from_renderable`] are rendered at an
+    /// 80-column width; render through a [`Console`] to use its width.
     #[must_use]
     pub fn render(&self) -> Vec<Segment<'_>> {
+        self.render_with(None)
+    }
+
+    fn render_with(&self, context: Option<(&Console, usize)>) -> Vec<Segment<'_>> {
         let mut segments = Vec::new();
         let prefix_stack: Vec<bool> = Vec::new();
 
         if self.show_root {
-            self.render_node(&self.root, &mut segments, &prefix_stack, true, 0);
+            self.render_node(&self.root, &mut segments, &prefix_stack, true, 0, context);
         } else {
             // Render children directly
             let children = &self.root.children;
             for (i, child) in children.iter().enumerate() {
                 let is_last = i == children.len() - 1;
-                self.render_node(child, &mut segments, &prefix_stack, is_last, 0);
+                self.render_node(child, &mut segments, &prefix_stack, is_last, 0, context);
             }
         }
 
         segments
     }
 
+    /// Render a node's renderable content, continuing the guides on every
+    /// line after the first.
+    fn render_content<'a>(
+        &self,
+        renderable: &dyn Renderable,
+        segments: &mut Vec<Segment<'a>>,
+        continuation: &[Segment<'a>],
+        prefix_width: usize,
+        context: Option<(&Console, usize)>,
+    ) {
+        let fallback;
+        let (console, width) = if let Some(context) = context {
+            context
+        } else {
+            fallback = Console::builder()
+                .force_terminal(false)
+                .width(DEFAULT_CONTENT_WIDTH)
+                .build();
+            (&fallback, DEFAULT_CONTENT_WIDTH)
+        };
+        let options = console
+            .options()
+            .update_width(width.saturating_sub(prefix_width).max(1));
+        let rendered = renderable
+            .render(console, &options)
+            .into_iter()
+            .map(Segment::into_owned);
+        let mut lines = split_lines(rendered);
+        pop_trailing_empty_line(&mut lines);
+
+        for (index, line) in lines.into_iter().enumerate() {
+            if index > 0 {
+                segments.push(Segment::line());
+                segments.extend(continuation.iter().cloned());
+            }
+            segments.extend(line);
+        }
+    }
+
     fn sanitize_label(label: &Text) -> Text {
         if !label.plain().contains('\n') {
             return label.clone();
@@ -343,12 +430,16 @@ impl Tree {
         prefix_stack: &[bool],
         is_last: bool,
         depth: usize,
+        context: Option<(&Console, usize)>,
     ) {
         // Check depth limit
         if self.max_depth >= 0 && depth as isize > self.max_depth {
             return;
         }
 
+        // Guides repeated on continuation lines of renderable content.
+        let mut continuation: Vec<Segment<'a>> = Vec::new();
+
         // Build the prefix (guides from ancestors)
         for &has_more_siblings in prefix_stack {
             let guide = if has_more_siblings {
@@ -357,6 +448,7 @@ impl Tree {
                 self.guides.space()
             };
             segments.push(Segment::new(guide, Some(self.guide_style.clone())));
+            continuation.push(Segment::new(guide, Some(self.guide_style.clone())));
         }
 
         // Add the branch guide for this node (if not root at depth 0)
@@ -367,14 +459,30 @@ impl Tree {
                 self.guides.branch()
             };
             segments.push(Segment::new(guide, Some(self.guide_style.clone())));
+            let below = if is_last {
+                self.guides.space()
+            } else {
+                self.guides.vertical()
+            };
+            continuation.push(Segment::new(below, Some(self.guide_style.clone())));
         }
 
         // Add icon if present
         if let Some(icon) = node.get_icon() {
-            segments.push(Segment::new(
-                format!("{icon} "),
-                Some(node.icon_style.clone()),
-            ));
+            let icon = format!("{icon} ");
+            continuation.push(Segment::new(" ".repeat(cells::cell_len(&icon)), None));
+            segments.push(Segment::new(icon, Some(node.icon_style.clone())));
+        }
+
+        if let Some(content) = &node.content {
+            let prefix_width = continuation.iter().map(Segment::cell_length).sum();
+            self.render_content(
+                content.as_ref(),
+                segments,
+                &continuation,
+                prefix_width,
+                context,
+            );
         }
 
         // Sanitize label newlines to avoid broken tree line structure.
@@ -416,7 +524,14 @@ impl Tree {
 
             for (i, child) in children.iter().enumerate() {
                 let child_is_last = i == children.len() - 1;
-                self.render_node(child, segments, &new_prefix_stack, child_is_last, depth + 1);
+                self.render_node(
+                    child,
+                    segments,
+                    &new_prefix_stack,
+                    child_is_last,
+                    depth + 1,
+                    context,
+                );
             }
         }
     }
@@ -424,16 +539,21 @@ impl Tree {
     /// Render the tree as a plain string.
     #[must_use]
     pub fn render_plain(&self) -> String {
-        self.render()
-            .into_iter()
-            .map(|seg| seg.text.into_owned())
-            .collect()
+        segments_to_plain(&self.render())
     }
 }
 
 impl Renderable for Tree {
-    fn render<'a>(&'a self, _console: &Console, _options: &ConsoleOptions) -> Vec<Segment<'a>> {
-        self.render()
+    fn render<'a>(&'a self, console: &Console, options: &ConsoleOptions) -> Vec<Segment<'a>> {
+        if !console.supports_unicode() && self.guides != TreeGuides::Ascii {
+            let ascii = self.clone().guides(TreeGuides::Ascii);
+            return ascii
+                .render_with(Some((console, options.max_width)))
+                .into_iter()
+                .map(Segment::into_owned)
+                .collect();
+        }
+        self.render_with(Some((console, options.max_width)))
     }
 }
 
@@ -738,6 +858,34 @@ mod tests {
         assert!(plain.contains("🔧"));
     }
 
+    #[test]
+    fn test_tree_node_from_renderable() {
+        use crate::renderables::{Column, Table};
+
+        let mut table = Table::new()
+            .with_column(Column::new("Name"))
+            .with_column(Column::new("Qty"));
+        table.add_row_cells(["apple", "3"]);
+        table.add_row_cells(["pear", "5"]);
+        let tree = Tree::new(
+            TreeNode::new("inventory")
+                .child(TreeNode::from_renderable(table))
+                .child(TreeNode::new("done")),
+        );
+
+        let console = Console::builder().width(40).build();
+        let lines: Vec<String> = console
+            .render_lines(&tree, 40)
+            .iter()
+            .map(|line| line.iter().map(|s| s.text.as_ref()).collect())
+            .collect();
+        assert_eq!(lines[1], "├── ┏━━━━━━━┳━━━━━┓");
+        assert_eq!(lines[4], "│   │ apple │ 3   │");
+        assert_eq!(lines[5], "│   │ pear  │ 5   │");
+        assert_eq!(lines.last().map(String::as_str), Some("└── done"));
+        assert!(lines[2..7].iter().all(|line| line.starts_with("│   ")));
+    }
+
     #[test]
     fn test_tree_guides_bold() {
         let guides = TreeGuides::Bold;
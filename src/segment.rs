@@ -29,6 +29,23 @@ pub enum ControlType {
     CursorMoveTo = 14,
     EraseInLine = 15,
     SetWindowTitle = 16,
+    /// Scroll the screen contents up (`CSI n S`); not part of Python Rich's set.
+    ScrollUp = 17,
+    /// Scroll the screen contents down (`CSI n T`); not part of Python Rich's set.
+    ScrollDown = 18,
+    /// iTerm2 inline image (`OSC 1337 ; File=`), with the base64 image data as the
+    /// segment text; not part of Python Rich's set.
+    InlineImage = 19,
+    /// Save the cursor position (`CSI s`); not part of Python Rich's set.
+    SaveCursor = 20,
+    /// Restore the saved cursor position (`CSI u`); not part of Python Rich's set.
+    RestoreCursor = 21,
+    /// Desktop notification (`OSC 9`), with the message as the segment text;
+    /// not part of Python Rich's set.
+    Notify = 22,
+    /// Tab/taskbar progress (`OSC 9;4`) with `[state, percent]` params; not
+    /// part of Python Rich's set.
+    TerminalProgress = 23,
 }
 
 /// Remove ASCII control codepoints used by Rich control helpers.
@@ -207,6 +224,13 @@ impl<'a> Segment<'a> {
         self
     }
 
+    /// Drop the style (and with it any hyperlink), keeping text and control codes.
+    #[must_use]
+    pub fn strip_styles(mut self) -> Self {
+        self.style = None;
+        self
+    }
+
     /// Split this segment at a cell position.
     ///
     /// Returns (left, right) segments.
@@ -216,17 +240,8 @@ impl<'a> Segment<'a> {
             return (self.clone(), Self::default());
         }
 
-        let mut width = 0;
-        let mut byte_pos = 0;
-
-        for (i, c) in self.text.char_indices() {
-            let char_width = crate::cells::get_character_cell_size(c);
-            if width + char_width > cell_pos {
-                break;
-            }
-            width += char_width;
-            byte_pos = i + c.len_utf8();
-        }
+        let (left, _) = crate::cells::chop_cells(&self.text, cell_pos);
+        let byte_pos = left.len();
 
         // Optimized split using Cow
         let (left, right) = match &self.text {
@@ -349,6 +364,16 @@ pub fn split_lines<'a>(segments: impl Iterator<Item = Segment<'a>>) -> Vec<Vec<S
     lines
 }
 
+/// Drop the empty last line that [`split_lines`] leaves after a trailing
+/// newline, keeping at least one line. Returns whether a line was dropped.
+pub(crate) fn pop_trailing_empty_line(lines: &mut Vec<Vec<Segment<'_>>>) -> bool {
+    let trailing = lines.len() > 1 && lines.last().is_some_and(Vec::is_empty);
+    if trailing {
+        lines.pop();
+    }
+    trailing
+}
+
 /// Adjust line length by padding or truncating.
 #[must_use]
 pub fn adjust_line_length(
@@ -606,12 +631,48 @@ pub fn align_middle(
     result
 }
 
+/// Concatenate the text of `segments`, skipping control segments.
+///
+/// Line segments already carry `\n`, so multi-line output comes back as
+/// newline-separated text.
+#[must_use]
+pub fn segments_to_plain(segments: &[Segment<'_>]) -> String {
+    let capacity: usize = segments
+        .iter()
+        .filter(|segment| !segment.is_control())
+        .map(|segment| segment.text.len())
+        .sum();
+    let mut output = String::with_capacity(capacity);
+    for segment in segments {
+        if !segment.is_control() {
+            output.push_str(segment.text.as_ref());
+        }
+    }
+    output
+}
+
 /// Get the total cell length of a line of segments.
 #[must_use]
 pub fn line_length(line: &[Segment]) -> usize {
     line.iter().map(Segment::cell_length).sum()
 }
 
+/// Get the cell width of the first line in `segments`.
+///
+/// Sums segment widths up to the first line break, so it can be applied to
+/// unsplit render output. Control segments count as zero width.
+#[must_use]
+pub fn line_cell_width(segments: &[Segment]) -> usize {
+    let mut width = 0;
+    for segment in segments.iter().filter(|segment| !segment.is_control()) {
+        if let Some(end) = segment.text.find('\n') {
+            return width + cell_len(&segment.text[..end]);
+        }
+        width += segment.cell_length();
+    }
+    width
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -663,6 +724,32 @@ mod tests {
         assert_eq!(escape_control_codes(input), "a\\ab\\bc\\vd\\fe\\rf");
     }
 
+    #[test]
+    fn test_segment_strip_styles() {
+        let style = Style::new().bold().link("https://example.com");
+        let stripped = Segment::styled("hello", style).strip_styles();
+        assert_eq!(stripped.text, "hello");
+        assert!(stripped.style.is_none());
+        assert!(!stripped.is_control());
+    }
+
+    #[test]
+    fn test_segments_to_plain_multi_line() {
+        let table = crate::renderables::table::Table::new()
+            .with_column(crate::renderables::table::Column::new("Name"))
+            .with_row_cells(["Alice"]);
+        let segments = table.render(20);
+        assert_eq!(
+            segments_to_plain(&segments),
+            "┏━━━━━━━┓\n┃ Name  ┃\n┡━━━━━━━┩\n│ Alice │\n└───────┘\n"
+        );
+
+        let mut segments = vec![Segment::styled("a", Style::new().bold()), Segment::line()];
+        segments.push(Segment::control(vec![ControlCode::new(ControlType::Bell)]));
+        segments.push(Segment::plain("b"));
+        assert_eq!(segments_to_plain(&segments), "a\nb");
+    }
+
     #[test]
     fn test_segment_split_at_cell() {
         let seg = Segment::new("hello world", None);
@@ -1085,4 +1172,24 @@ mod tests {
         assert!(left.is_control());
         assert!(!right.is_control()); // Right is default (empty)
     }
+
+    // Additional: cell widths of segments and lines
+    #[test]
+    fn test_cell_widths_and_line_cell_width() {
+        assert_eq!(Segment::plain("日本語").cell_length(), 6);
+        assert_eq!(Segment::plain("abc").cell_length(), 3);
+        assert!(Segment::control(vec![ControlCode::new(ControlType::Bell)]).is_control());
+
+        let segments = vec![
+            Segment::plain("日本"),
+            Segment::control(vec![ControlCode::new(ControlType::Home)]),
+            Segment::styled("ab", Style::new().bold()),
+            Segment::line(),
+            Segment::plain("ignored"),
+        ];
+        assert_eq!(line_cell_width(&segments), 6);
+        assert_eq!(line_cell_width(&[Segment::plain("xy\nzzz")]), 2);
+        assert_eq!(line_cell_width(&segments[4..]), 7);
+        assert_eq!(line_cell_width(&[]), 0);
+    }
 }
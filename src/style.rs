@@ -709,7 +709,7 @@ impl Style {
         }
 
         let mut result = Style::new();
-        let words: Vec<&str> = style.split_whitespace().collect();
+        let words = split_style_words(style);
         let mut i = 0;
 
         while i < words.len() {
@@ -1035,6 +1035,32 @@ impl From<ColorParseError> for StyleParseError {
     }
 }
 
+/// Split a style definition on whitespace, keeping parenthesized groups such
+/// as `rgb(255, 0, 0)` together as a single word.
+fn split_style_words(style: &str) -> Vec<&str> {
+    let mut words = Vec::new();
+    let mut depth = 0usize;
+    let mut start: Option<usize> = None;
+    for (i, c) in style.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            c if c.is_whitespace() && depth == 0 => {
+                if let Some(begin) = start.take() {
+                    words.push(&style[begin..i]);
+                }
+                continue;
+            }
+            _ => {}
+        }
+        start.get_or_insert(i);
+    }
+    if let Some(begin) = start {
+        words.push(&style[begin..]);
+    }
+    words
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1110,6 +1136,14 @@ mod tests {
         assert!(style.bgcolor.is_some());
     }
 
+    #[test]
+    fn test_style_parse_spaced_rgb() {
+        let style = Style::parse("bold rgb(10, 20, 30) on rgb(1, 2, 3)").unwrap();
+        assert!(style.attributes.contains(Attributes::BOLD));
+        assert_eq!(style.color, Some(Color::from_rgb(10, 20, 30)));
+        assert_eq!(style.bgcolor, Some(Color::from_rgb(1, 2, 3)));
+    }
+
     #[test]
     fn test_style_parse_not() {
         let style = Style::parse("not bold").unwrap();
@@ -87,6 +87,74 @@ pub fn is_dumb_terminal() -> bool {
     })
 }
 
+/// Check if the terminal understands `OSC 9` notifications and `OSC 9;4` progress.
+///
+/// Recognizes Windows Terminal (`WT_SESSION`), `ConEmu` (`ConEmuANSI=ON`), and
+/// terminals identifying as `WezTerm` or `ghostty` via `TERM_PROGRAM`.
+#[must_use]
+pub fn supports_osc9() -> bool {
+    osc9_supported(
+        std::env::var("WT_SESSION").ok().as_deref(),
+        std::env::var("ConEmuANSI").ok().as_deref(),
+        std::env::var("TERM_PROGRAM").ok().as_deref(),
+    )
+}
+
+fn osc9_supported(
+    wt_session: Option<&str>,
+    conemu_ansi: Option<&str>,
+    term_program: Option<&str>,
+) -> bool {
+    wt_session.is_some_and(|session| !session.is_empty())
+        || conemu_ansi.is_some_and(|value| value.eq_ignore_ascii_case("on"))
+        || term_program.is_some_and(|program| matches!(program, "WezTerm" | "ghostty"))
+}
+
+/// Check whether the terminal can display iTerm2 inline images (`OSC 1337`).
+///
+/// Recognizes terminals identifying as `iTerm.app` or `WezTerm` via `TERM_PROGRAM`.
+#[must_use]
+pub fn supports_inline_images() -> bool {
+    inline_images_supported(std::env::var("TERM_PROGRAM").ok().as_deref())
+}
+
+fn inline_images_supported(term_program: Option<&str>) -> bool {
+    term_program.is_some_and(|program| matches!(program, "iTerm.app" | "WezTerm"))
+}
+
+/// Detect the output character encoding from the locale environment.
+///
+/// Reads the charset of the first non-empty of `LC_ALL`, `LC_CTYPE`, and
+/// `LANG` (e.g. `en_US.ISO-8859-1` yields `"iso-8859-1"`). Locales without a
+/// charset, such as `C`, report `"utf-8"`, matching Python's UTF-8 coercion
+/// of the C locale.
+#[must_use]
+pub fn detect_encoding() -> String {
+    encoding_from_locale(
+        std::env::var("LC_ALL").ok().as_deref(),
+        std::env::var("LC_CTYPE").ok().as_deref(),
+        std::env::var("LANG").ok().as_deref(),
+    )
+}
+
+fn encoding_from_locale(
+    lc_all: Option<&str>,
+    lc_ctype: Option<&str>,
+    lang: Option<&str>,
+) -> String {
+    let locale = [lc_all, lc_ctype, lang]
+        .into_iter()
+        .flatten()
+        .find(|value| !value.is_empty());
+    let charset = locale
+        .and_then(|locale| locale.split_once('.'))
+        .map(|(_, rest)| rest.split('@').next().unwrap_or(rest).to_ascii_lowercase());
+    match charset.as_deref() {
+        None | Some("" | "utf8" | "utf-8") => String::from("utf-8"),
+        Some(charset) => charset.to_string(),
+    }
+}
+
 /// Detect the color system supported by the terminal.
 ///
 /// Checks environment variables to determine color capabilities:
@@ -168,6 +236,77 @@ fn detect_color_system_with(
     }
 }
 
+/// Terminal background brightness.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Background {
+    /// Dark background (light text).
+    Dark,
+    /// Light background (dark text).
+    Light,
+}
+
+/// Detect the terminal background from the `COLORFGBG` environment variable.
+///
+/// Returns `None` if the variable is unset or can't be parsed.
+#[must_use]
+pub fn detect_background() -> Option<Background> {
+    std::env::var("COLORFGBG")
+        .ok()
+        .as_deref()
+        .and_then(parse_colorfgbg)
+}
+
+/// Parse a `COLORFGBG` value (`"fg;bg"` or `"fg;default;bg"`).
+///
+/// The last field is the background ANSI color number. Colors 0-6 and 8 are
+/// treated as dark; 7 and 9-15 as light (rxvt convention).
+fn parse_colorfgbg(value: &str) -> Option<Background> {
+    let bg: u8 = value.rsplit(';').next()?.trim().parse().ok()?;
+    match bg {
+        0..=6 | 8 => Some(Background::Dark),
+        7 | 9..=15 => Some(Background::Light),
+        _ => None,
+    }
+}
+
+/// Snapshot of the terminal capabilities a [`Console`](crate::Console) renders for.
+///
+/// [`Console::new`](crate::Console::new) renders for
+/// [`TerminalEnvironment::detect`]. Pass a hand-built value to
+/// [`ConsoleBuilder::environment`](crate::console::ConsoleBuilder::environment)
+/// to replace all of it at once, e.g. in tests.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TerminalEnvironment {
+    /// Width in cells.
+    pub width: usize,
+    /// Height in lines.
+    pub height: usize,
+    /// Color system, or `None` for no color.
+    pub color_system: Option<ColorSystem>,
+    /// Whether output goes to an interactive terminal.
+    pub is_tty: bool,
+    /// Whether the output encoding can represent Unicode glyphs.
+    pub unicode: bool,
+    /// Whether styled links are emitted as OSC 8 hyperlinks.
+    pub hyperlinks: bool,
+}
+
+impl TerminalEnvironment {
+    /// Detect the environment of the current process's stdout.
+    #[must_use]
+    pub fn detect() -> Self {
+        let is_tty = is_terminal();
+        Self {
+            width: get_terminal_width(),
+            height: get_terminal_height(),
+            color_system: if is_tty { detect_color_system() } else { None },
+            is_tty,
+            unicode: detect_encoding().starts_with("utf"),
+            hyperlinks: true,
+        }
+    }
+}
+
 /// Enable raw terminal mode (for advanced input handling).
 pub fn enable_raw_mode() -> std::io::Result<()> {
     crossterm::terminal::enable_raw_mode()
@@ -290,6 +429,49 @@ pub mod control {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_osc9_supported_detection() {
+        assert!(osc9_supported(Some("0b3e4d1c"), None, None));
+        assert!(osc9_supported(None, Some("ON"), None));
+        assert!(osc9_supported(None, None, Some("WezTerm")));
+        assert!(!osc9_supported(
+            Some(""),
+            Some("OFF"),
+            Some("Apple_Terminal")
+        ));
+        assert!(!osc9_supported(None, None, None));
+    }
+
+    #[test]
+    fn test_inline_images_supported_detection() {
+        assert!(inline_images_supported(Some("iTerm.app")));
+        assert!(inline_images_supported(Some("WezTerm")));
+        assert!(!inline_images_supported(Some("Apple_Terminal")));
+        assert!(!inline_images_supported(None));
+    }
+
+    #[test]
+    fn test_encoding_from_locale() {
+        assert_eq!(encoding_from_locale(None, None, None), "utf-8");
+        assert_eq!(encoding_from_locale(None, None, Some("C")), "utf-8");
+        assert_eq!(
+            encoding_from_locale(None, None, Some("en_US.UTF-8")),
+            "utf-8"
+        );
+        assert_eq!(
+            encoding_from_locale(None, Some("de_DE.ISO-8859-1@euro"), Some("en_US.UTF-8")),
+            "iso-8859-1"
+        );
+        assert_eq!(
+            encoding_from_locale(Some("POSIX"), None, Some("en_US.ISO-8859-1")),
+            "utf-8"
+        );
+        assert_eq!(
+            encoding_from_locale(Some(""), None, Some("ru_RU.KOI8-R")),
+            "koi8-r"
+        );
+    }
+
     /// Helper to create `EnvSettings` for testing
     fn make_env(
         no_color: Option<&str>,
@@ -590,4 +772,27 @@ mod tests {
         let _ = detect_color_system_with(&settings, true);
         let _ = detect_color_system_with(&settings, false);
     }
+
+    // =========================================================================
+    // COLORFGBG background detection tests
+    // =========================================================================
+
+    #[test]
+    fn test_colorfgbg_dark_background() {
+        assert_eq!(parse_colorfgbg("15;0"), Some(Background::Dark));
+        assert_eq!(parse_colorfgbg("15;default;0"), Some(Background::Dark));
+    }
+
+    #[test]
+    fn test_colorfgbg_light_background() {
+        assert_eq!(parse_colorfgbg("0;15"), Some(Background::Light));
+        assert_eq!(parse_colorfgbg("0;7"), Some(Background::Light));
+    }
+
+    #[test]
+    fn test_colorfgbg_invalid() {
+        assert_eq!(parse_colorfgbg(""), None);
+        assert_eq!(parse_colorfgbg("15;default"), None);
+        assert_eq!(parse_colorfgbg("0;99"), None);
+    }
 }
@@ -7,14 +7,16 @@
 use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::hash::{Hash, Hasher};
-use std::ops::{Add, AddAssign};
+use std::ops::{Add, AddAssign, Range};
 
 use crate::ansi::AnsiDecoder;
 use crate::cells::cell_len;
 use crate::console::{Console, ConsoleOptions};
+use crate::measure::{Measurement, RichMeasure};
 use crate::renderables::Renderable;
 use crate::segment::Segment;
 use crate::style::Style;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Text justification method.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -51,6 +53,9 @@ pub enum OverflowMethod {
 /// Spans use character indices (not byte indices) to define regions
 /// of styled text within a `Text` object. Spans can overlap, with
 /// later spans taking precedence during rendering.
+///
+/// Build spans with [`Span::new`]; the no-wrap flag is private and only set
+/// through [`Text::stylize_no_wrap`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Span {
     /// Start character index (inclusive).
@@ -59,6 +64,8 @@ pub struct Span {
     pub end: usize,
     /// Style to apply to this span.
     pub style: Style,
+    /// Keep this range on one line when wrapping (see [`Text::stylize_no_wrap`]).
+    no_wrap: bool,
 }
 
 impl Span {
@@ -69,9 +76,24 @@ impl Span {
             start: start.min(end),
             end: end.max(start),
             style,
+            no_wrap: false,
+        }
+    }
+
+    /// Copy of this span (style and flags) over a new range.
+    fn with_range(&self, start: usize, end: usize) -> Self {
+        Self {
+            no_wrap: self.no_wrap,
+            ..Self::new(start, end, self.style.clone())
         }
     }
 
+    /// Whether this range is kept on one line when wrapping.
+    #[must_use]
+    pub fn no_wrap(&self) -> bool {
+        self.no_wrap
+    }
+
     /// Check if this span is empty (zero length).
     #[must_use]
     pub fn is_empty(&self) -> bool {
@@ -91,6 +113,7 @@ impl Span {
             start: (self.start + offset).min(max),
             end: (self.end + offset).min(max),
             style: self.style.clone(),
+            no_wrap: self.no_wrap,
         }
     }
 
@@ -106,11 +129,13 @@ impl Span {
                 start: self.start,
                 end: split_point.min(self.end),
                 style: self.style.clone(),
+                no_wrap: self.no_wrap,
             },
             Self {
                 start: split_point.min(self.end),
                 end: self.end,
                 style: self.style.clone(),
+                no_wrap: self.no_wrap,
             },
         )
     }
@@ -122,10 +147,20 @@ impl Span {
             start: self.start.saturating_sub(offset),
             end: self.end.saturating_sub(offset),
             style: self.style.clone(),
+            no_wrap: self.no_wrap,
         }
     }
 }
 
+/// Matching options for [`Text::highlight_words`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct WordOptions {
+    /// Match regardless of letter case (`"get"` matches `"GET"`).
+    pub case_insensitive: bool,
+    /// Only match where the word is not part of a larger word.
+    pub whole_word: bool,
+}
+
 /// Rich text with styled spans.
 ///
 /// `Text` represents styled text where different regions can have different
@@ -147,6 +182,8 @@ pub struct Text {
     pub overflow: OverflowMethod,
     /// Disable wrapping.
     pub no_wrap: bool,
+    /// Insert `-` where wrapping has to break inside a word.
+    pub hyphenate: bool,
     /// String to append after text (default "\n").
     pub end: String,
     /// Tab expansion size (default 8).
@@ -237,6 +274,7 @@ impl Text {
             justify: JustifyMethod::Default,
             overflow: OverflowMethod::Fold,
             no_wrap: false,
+            hyphenate: false,
             end: String::from("\n"),
             tab_size: 8,
         }
@@ -260,21 +298,31 @@ impl Text {
             justify: JustifyMethod::Default,
             overflow: OverflowMethod::Fold,
             no_wrap: false,
+            hyphenate: false,
             end: String::from("\n"),
             tab_size: 8,
         }
     }
 
-    /// Create Text by assembling multiple styled pieces.
+    /// Create Text by concatenating pieces (Python Rich `Text.assemble`).
+    ///
+    /// Each piece may be a bare string, a `(string, Style)` pair, a
+    /// `(string, Option<Style>)` pair, or another [`Text`]. Styled pieces
+    /// become spans over their range; empty styles add no span.
+    ///
+    /// ```
+    /// use rich_rust::style::Style;
+    /// use rich_rust::text::Text;
+    ///
+    /// let text = Text::assemble([("OK", Style::parse("green").unwrap()), (" done", Style::new())]);
+    /// assert_eq!(text.plain(), "OK done");
+    /// assert_eq!(text.spans().len(), 1);
+    /// ```
     #[must_use]
-    pub fn assemble(pieces: &[(&str, Option<Style>)]) -> Self {
+    pub fn assemble<P: Into<Self>>(pieces: impl IntoIterator<Item = P>) -> Self {
         let mut text = Self::new("");
-        for (content, style) in pieces {
-            if let Some(s) = style {
-                text.append_styled(content, s.clone());
-            } else {
-                text.append(content);
-            }
+        for piece in pieces {
+            text.append_text(&piece.into());
         }
         text
     }
@@ -291,6 +339,41 @@ impl Text {
         &self.spans
     }
 
+    /// Get the hyperlinked regions as byte ranges into [`Text::plain`] and their URLs.
+    ///
+    /// Regions are ordered by position. Overlapping or touching spans with the
+    /// same URL (e.g. a link with a bold word inside) are reported once.
+    #[must_use]
+    pub fn links(&self) -> Vec<(Range<usize>, String)> {
+        let mut char_starts: Vec<usize> = self.plain.char_indices().map(|(i, _)| i).collect();
+        char_starts.push(self.plain.len());
+        let byte_at = |char_index: usize| char_starts[char_index.min(char_starts.len() - 1)];
+
+        let mut linked: Vec<(Range<usize>, &str)> = self
+            .spans
+            .iter()
+            .filter(|span| span.start < span.end)
+            .filter_map(|span| {
+                let url = span.style.link.as_deref()?;
+                Some((byte_at(span.start)..byte_at(span.end), url))
+            })
+            .collect();
+        linked.sort_by_key(|(range, _)| (range.start, range.end));
+
+        let mut links: Vec<(Range<usize>, String)> = Vec::new();
+        for (range, url) in linked {
+            if let Some((last, last_url)) = links.last_mut()
+                && last_url == url
+                && range.start <= last.end
+            {
+                last.end = last.end.max(range.end);
+                continue;
+            }
+            links.push((range, url.to_string()));
+        }
+        links
+    }
+
     /// Get the character length.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -360,6 +443,21 @@ impl Text {
         }
     }
 
+    /// Keep a character range on a single line when wrapping.
+    ///
+    /// [`Text::wrap`] moves the whole range to the next line instead of
+    /// breaking inside it, and only folds it when it is wider than the wrap
+    /// width on its own. Useful for paths, hashtags, and similar tokens.
+    pub fn stylize_no_wrap(&mut self, start: usize, end: usize) {
+        let clamped_start = start.min(self.length);
+        let clamped_end = end.min(self.length);
+        if clamped_start < clamped_end {
+            let mut span = Span::new(clamped_start, clamped_end, Style::null());
+            span.no_wrap = true;
+            self.spans.push(span);
+        }
+    }
+
     /// Apply style to entire text.
     pub fn stylize_all(&mut self, style: Style) {
         if self.length > 0 {
@@ -401,12 +499,72 @@ impl Text {
     }
 
     /// Highlight specific words with a style.
-    pub fn highlight_words(&mut self, words: &[&str], style: &Style, case_sensitive: bool) {
+    ///
+    /// `options` controls case sensitivity and whether matches must stand
+    /// alone as whole words (so `"get"` does not match inside `"target"`).
+    pub fn highlight_words(&mut self, words: &[&str], style: &Style, options: WordOptions) {
         if words.is_empty() {
             return;
         }
 
-        if case_sensitive {
+        // Character ranges of every match, before the whole-word filter.
+        let mut matches: Vec<(usize, usize)> = Vec::new();
+
+        if options.case_insensitive {
+            // Case-insensitive matching requires stable index mapping between
+            // the lowercased string and the original string.
+            let mut lowered = String::new();
+            let mut lower_to_original: Vec<usize> = Vec::new();
+
+            for (orig_idx, c) in self.plain.chars().enumerate() {
+                for lower in c.to_lowercase() {
+                    lowered.push(lower);
+                    lower_to_original.push(orig_idx);
+                }
+            }
+
+            // Optimization: Map byte indices of lowered string to its char indices
+            let lowered_char_starts: Vec<usize> = lowered.char_indices().map(|(i, _)| i).collect();
+            let total_lowered_chars = lowered_char_starts.len();
+            let total_lowered_bytes = lowered.len();
+
+            for word in words {
+                let search_word = word.to_lowercase();
+                if search_word.is_empty() {
+                    continue;
+                }
+
+                let mut search_start = 0;
+                while let Some(pos) = lowered[search_start..].find(&search_word) {
+                    let byte_start = search_start + pos;
+                    let byte_end = byte_start + search_word.len();
+
+                    // Convert byte indices in lowered string to char indices in lowered string
+                    let char_start_lowered = lowered_char_starts
+                        .binary_search(&byte_start)
+                        .unwrap_or_else(|x| x);
+                    let char_end_lowered = if byte_end == total_lowered_bytes {
+                        total_lowered_chars
+                    } else {
+                        lowered_char_starts
+                            .binary_search(&byte_end)
+                            .unwrap_or_else(|x| x)
+                    };
+
+                    if char_start_lowered < char_end_lowered
+                        && char_end_lowered <= lower_to_original.len()
+                    {
+                        let orig_start = lower_to_original[char_start_lowered];
+                        let orig_end = lower_to_original[char_end_lowered - 1] + 1;
+                        if orig_start < orig_end {
+                            matches.push((orig_start, orig_end));
+                        }
+                    }
+
+                    search_start = byte_end;
+                }
+            }
+        } else {
             // Optimization: Map byte indices to char indices once
             let char_starts: Vec<usize> = self.plain.char_indices().map(|(i, _)| i).collect();
             let total_chars = char_starts.len();
@@ -431,68 +589,29 @@ impl Text {
                     };
 
                     if char_start < char_end {
-                        self.spans
-                            .push(Span::new(char_start, char_end, style.clone()));
+                        matches.push((char_start, char_end));
                     }
                     search_start = byte_end;
                 }
             }
-            return;
-        }
-
-        // Case-insensitive matching requires stable index mapping between
-        // the lowercased string and the original string.
-        let mut lowered = String::new();
-        let mut lower_to_original: Vec<usize> = Vec::new();
-
-        for (orig_idx, c) in self.plain.chars().enumerate() {
-            for lower in c.to_lowercase() {
-                lowered.push(lower);
-                lower_to_original.push(orig_idx);
-            }
         }
 
-        // Optimization: Map byte indices of lowered string to its char indices
-        let lowered_char_starts: Vec<usize> = lowered.char_indices().map(|(i, _)| i).collect();
-        let total_lowered_chars = lowered_char_starts.len();
-        let total_lowered_bytes = lowered.len();
-
-        for word in words {
-            let search_word = word.to_lowercase();
-            if search_word.is_empty() {
-                continue;
-            }
-
-            let mut search_start = 0;
-            while let Some(pos) = lowered[search_start..].find(&search_word) {
-                let byte_start = search_start + pos;
-                let byte_end = byte_start + search_word.len();
-
-                // Convert byte indices in lowered string to char indices in lowered string
-                let char_start_lowered = lowered_char_starts
-                    .binary_search(&byte_start)
-                    .unwrap_or_else(|x| x);
-                let char_end_lowered = if byte_end == total_lowered_bytes {
-                    total_lowered_chars
-                } else {
-                    lowered_char_starts
-                        .binary_search(&byte_end)
-                        .unwrap_or_else(|x| x)
-                };
+        let chars: Vec<char> = if options.whole_word {
+            self.plain.chars().collect()
+        } else {
+            Vec::new()
+        };
+        let is_word_char = |c: char| c.is_alphanumeric() || c == '_';
 
-                if char_start_lowered < char_end_lowered
-                    && char_end_lowered <= lower_to_original.len()
-                {
-                    let orig_start = lower_to_original[char_start_lowered];
-                    let orig_end = lower_to_original[char_end_lowered - 1] + 1;
-                    if orig_start < orig_end {
-                        self.spans
-                            .push(Span::new(orig_start, orig_end, style.clone()));
-                    }
+        for (start, end) in matches {
+            if options.whole_word {
+                let before = start.checked_sub(1).map(|i| chars[i]);
+                let after = chars.get(end).copied();
+                if before.is_some_and(is_word_char) || after.is_some_and(is_word_char) {
+                    continue;
                 }
-
-                search_start = byte_end;
             }
+            self.spans.push(Span::new(start, end, style.clone()));
         }
     }
 
@@ -537,7 +656,7 @@ impl Text {
             let new_end = span.end.min(clamped_end) - clamped_start;
 
             if new_start < new_end {
-                spans.push(Span::new(new_start, new_end, span.style.clone()));
+                spans.push(span.with_range(new_start, new_end));
             }
         }
 
@@ -549,6 +668,7 @@ impl Text {
             justify: self.justify,
             overflow: self.overflow,
             no_wrap: self.no_wrap,
+            hyphenate: self.hyphenate,
             end: self.end.clone(),
             tab_size: self.tab_size,
         }
@@ -612,7 +732,7 @@ impl Text {
                     let new_end = span.end.min(char_idx) - start_char;
 
                     if new_start < new_end {
-                        spans.push(Span::new(new_start, new_end, span.style.clone()));
+                        spans.push(span.with_range(new_start, new_end));
                     }
                 }
 
@@ -624,6 +744,7 @@ impl Text {
                     justify: self.justify,
                     overflow: self.overflow,
                     no_wrap: self.no_wrap,
+                    hyphenate: self.hyphenate,
                     end: self.end.clone(),
                     tab_size: self.tab_size,
                 });
@@ -647,7 +768,7 @@ impl Text {
                 let new_start = span.start.max(start_char) - start_char;
                 let new_end = span.end.min(self.length) - start_char;
                 if new_start < new_end {
-                    spans.push(Span::new(new_start, new_end, span.style.clone()));
+                    spans.push(span.with_range(new_start, new_end));
                 }
             }
 
@@ -659,6 +780,7 @@ impl Text {
                 justify: self.justify,
                 overflow: self.overflow,
                 no_wrap: self.no_wrap,
+                hyphenate: self.hyphenate,
                 end: self.end.clone(),
                 tab_size: self.tab_size,
             });
@@ -741,7 +863,7 @@ impl Text {
             let new_end = char_map.partition_point(|&old| old < span.end);
 
             if new_start < new_end {
-                new_spans.push(Span::new(new_start, new_end, span.style.clone()));
+                new_spans.push(span.with_range(new_start, new_end));
             }
         }
 
@@ -753,6 +875,7 @@ impl Text {
             justify: self.justify,
             overflow: self.overflow,
             no_wrap: self.no_wrap,
+            hyphenate: self.hyphenate,
             end: self.end.clone(),
             tab_size: self.tab_size,
         }
@@ -808,24 +931,116 @@ impl Text {
         }
     }
 
+    /// Fit text to a single line of at most `width` cells.
+    ///
+    /// Text past the first newline is dropped. If anything had to be cut, the
+    /// result ends with `…`. Returns the fitted text and whether it was truncated.
+    #[must_use]
+    pub fn fit(&self, width: usize) -> (Self, bool) {
+        let line_end = self
+            .plain
+            .chars()
+            .position(|c| c == '\n')
+            .unwrap_or(self.length);
+        let line = self.slice(0, line_end);
+        if line_end == self.length && line.cell_len() <= width {
+            return (line, false);
+        }
+        if width == 0 {
+            return (self.slice(0, 0), true);
+        }
+
+        let (cut_pos, _) = line.find_truncation_point(width - 1);
+        let mut fitted = line.slice(0, cut_pos);
+        fitted.append("\u{2026}");
+        (fitted, true)
+    }
+
+    /// Measure the narrowest and widest this text can render, in cells.
+    ///
+    /// The minimum is the widest unbreakable token: the longest word, a
+    /// [`no_wrap`](Text::stylize_no_wrap) range together with the words it
+    /// touches, or the longest line when the whole text is `no_wrap`. The
+    /// maximum is the longest line.
+    ///
+    /// ```
+    /// use rich_rust::text::Text;
+    ///
+    /// let measurement = Text::new("hello world").measure();
+    /// assert_eq!((measurement.minimum, measurement.maximum), (5, 11));
+    /// ```
+    #[must_use]
+    pub fn measure(&self) -> Measurement {
+        let expanded = self.expand_tabs(self.tab_size);
+        let maximum = expanded
+            .split_lines()
+            .iter()
+            .map(Self::cell_len)
+            .max()
+            .unwrap_or(0);
+        if self.no_wrap {
+            return Measurement::exact(maximum);
+        }
+
+        let chars: Vec<char> = expanded.plain.chars().collect();
+        let width = |range: std::ops::Range<usize>| -> usize {
+            cell_len(&chars[range].iter().collect::<String>())
+        };
+
+        let mut minimum = 0;
+        let mut start = 0;
+        for (index, c) in chars.iter().enumerate() {
+            if c.is_whitespace() {
+                minimum = minimum.max(width(start..index));
+                start = index + 1;
+            }
+        }
+        minimum = minimum.max(width(start..chars.len()));
+
+        for span in expanded.spans.iter().filter(|span| span.no_wrap) {
+            let mut token_start = span.start.min(chars.len());
+            let mut token_end = span.end.min(chars.len());
+            while token_start > 0 && !chars[token_start - 1].is_whitespace() {
+                token_start -= 1;
+            }
+            while token_end < chars.len() && !chars[token_end].is_whitespace() {
+                token_end += 1;
+            }
+            minimum = minimum.max(width(token_start..token_end));
+        }
+
+        Measurement::new(minimum.min(maximum), maximum)
+    }
+
     /// Find the character position and cell width for truncation at `max_width`.
     /// Returns `(cut_position, accumulated_width)`.
     fn find_truncation_point(&self, max_width: usize) -> (usize, usize) {
         let mut width = 0;
         let mut cut_pos = 0;
 
-        for (i, c) in self.plain.chars().enumerate() {
-            let char_width = crate::cells::get_character_cell_size(c);
-            if width + char_width > max_width {
+        for grapheme in self.plain.graphemes(true) {
+            let grapheme_width = cell_len(grapheme);
+            if width + grapheme_width > max_width {
                 break;
             }
-            width += char_width;
-            cut_pos = i + 1;
+            width += grapheme_width;
+            cut_pos += grapheme.chars().count();
         }
 
         (cut_pos, width)
     }
 
+    /// Reverse the text by grapheme cluster, carrying spans along.
+    ///
+    /// Multi-codepoint clusters such as ZWJ emoji sequences or letters with
+    /// combining marks keep their internal order.
+    pub fn reverse(&mut self) {
+        self.plain = self.plain.graphemes(true).rev().collect();
+        for span in &mut self.spans {
+            (span.start, span.end) = (self.length - span.end, self.length - span.start);
+        }
+    }
+
     /// Pad text to a specific width.
     pub fn pad(&mut self, width: usize, align: JustifyMethod) {
         let current_width = self.cell_len();
@@ -923,7 +1138,7 @@ impl Text {
             let new_end = positions[end];
 
             if new_start < new_end {
-                new_spans.push(Span::new(new_start, new_end, span.style.clone()));
+                new_spans.push(span.with_range(new_start, new_end));
             }
         }
 
@@ -935,6 +1150,7 @@ impl Text {
             justify: self.justify,
             overflow: self.overflow,
             no_wrap: self.no_wrap,
+            hyphenate: self.hyphenate,
             end: self.end.clone(),
             tab_size: self.tab_size,
         }
@@ -954,6 +1170,9 @@ impl Text {
         // Build event map: position -> list of (span_index, is_start)
         let mut events: BTreeMap<usize, Vec<(usize, bool)>> = BTreeMap::new();
         for (idx, span) in self.spans.iter().enumerate() {
+            if span.no_wrap && span.style.is_null() {
+                continue;
+            }
             events.entry(span.start).or_default().push((idx, true));
             events.entry(span.end).or_default().push((idx, false));
         }
@@ -1049,24 +1268,83 @@ impl Text {
         hasher.finish()
     }
 
+    /// Indent every line by `n` spaces.
+    ///
+    /// Spans move with the text, so styles stay on the same characters.
+    #[must_use]
+    pub fn indent(&self, n: usize) -> Self {
+        self.with_line_prefix(&" ".repeat(n))
+    }
+
+    /// Prefix every line with `prefix`, shifting spans to follow the text.
+    ///
+    /// The prefix itself is unstyled (it still picks up the base style).
+    #[must_use]
+    pub fn with_line_prefix(&self, prefix: &str) -> Self {
+        let mut result = self.slice(0, 0);
+        for (index, line) in self.split_lines().iter().enumerate() {
+            if index > 0 {
+                result.append("\n");
+            }
+            result.append(prefix);
+            result.append_text(line);
+        }
+        result
+    }
+
+    /// Word wrap text, prefixing every resulting line with `prefix`.
+    ///
+    /// Unlike wrapping an already indented text, continuation lines created
+    /// by the wrap carry the prefix too. The prefix width is taken out of
+    /// `width` so the prefixed lines still fit.
+    #[must_use]
+    pub fn wrap_with_line_prefix(&self, width: usize, prefix: &str) -> Vec<Self> {
+        let content_width = width.saturating_sub(cell_len(prefix)).max(1);
+        self.wrap(content_width)
+            .iter()
+            .map(|line| line.with_line_prefix(prefix))
+            .collect()
+    }
+
+    /// Hyphenate words that [`Self::wrap`] has to break.
+    ///
+    /// A word too long for the line is split on a grapheme boundary one cell
+    /// early so the line can end with `-`. Lines too narrow to hold a
+    /// character plus the hyphen break without one.
+    #[must_use]
+    pub fn hyphenate(mut self, hyphenate: bool) -> Self {
+        self.hyphenate = hyphenate;
+        self
+    }
+
     /// Word wrap text to fit within a width.
     #[must_use]
     pub fn wrap(&self, width: usize) -> Vec<Self> {
+        self.wrap_with_markers(width)
+            .into_iter()
+            .map(|(line, _)| line)
+            .collect()
+    }
+
+    /// Word wrap text like [`Self::wrap`], also returning, for each line, the
+    /// character offset where an ellipsis truncation marker starts (the marker
+    /// runs to the end of the line).
+    pub(crate) fn wrap_with_markers(&self, width: usize) -> Vec<(Self, Option<usize>)> {
         if width == 0 {
-            return vec![Self::new("")];
+            return vec![(Self::new(""), None)];
         }
 
         let expanded = self.expand_tabs(self.tab_size);
 
         if expanded.no_wrap || expanded.cell_len() <= width {
-            return vec![expanded];
+            return vec![(expanded, None)];
         }
 
         let mut lines = Vec::new();
 
         for line in expanded.split_lines() {
             if line.cell_len() <= width {
-                lines.push(line);
+                lines.push((line, None));
             } else {
                 lines.extend(self.wrap_line(&line, width));
             }
@@ -1075,94 +1353,177 @@ impl Text {
         lines
     }
 
-    /// Wrap a single line of text.
-    fn wrap_line(&self, line: &Text, width: usize) -> Vec<Self> {
-        let mut result = Vec::new();
+    /// Character ranges `(start, end, hyphenated)` of the lines that
+    /// [`OverflowMethod::Fold`] wraps a single `line` into; hyphenated lines
+    /// get a `-` appended.
+    fn fold_ranges(&self, line: &Text, width: usize) -> Vec<(usize, usize, bool)> {
+        let mut ranges = Vec::new();
         let chars: Vec<char> = line.plain.chars().collect();
 
-        if chars.is_empty() {
-            return vec![Self::new("")];
+        // Wrap at word boundaries when possible
+        let mut current_line_start = 0;
+        let mut current_width = 0;
+        let mut last_space = None;
+
+        // (char index, cell width) of each grapheme cluster, so a base
+        // character is never split from its combining marks or joiners.
+        let mut clusters = Vec::new();
+        let mut char_index = 0;
+        for grapheme in line.plain.graphemes(true) {
+            clusters.push((char_index, crate::cells::grapheme_cell_size(grapheme)));
+            char_index += grapheme.chars().count();
         }
 
-        match line.overflow {
-            OverflowMethod::Fold => {
-                // Wrap at word boundaries when possible
-                let mut current_line_start = 0;
-                let mut current_width = 0;
-                let mut last_space = None;
+        for (k, &(i, char_width)) in clusters.iter().enumerate() {
+            let c = chars[i];
+            if c.is_whitespace() && c != '\n' {
+                last_space = Some(i);
+            }
 
-                for (i, c) in chars.iter().enumerate() {
-                    let char_width = crate::cells::get_character_cell_size(*c);
+            if current_width + char_width > width {
+                // Need to wrap
+                let (wrap_at, next_start) = if let Some(space_pos) = last_space {
+                    if space_pos > current_line_start && space_pos < i {
+                        // Preserve the whitespace we wrapped at on the previous line.
+                        // This matches Python Rich's wrapping behavior and matters for
+                        // renderables that include significant trailing spaces (e.g. `": "`).
+                        (space_pos + 1, space_pos + 1)
+                    } else {
+                        (i, i)
+                    }
+                } else {
+                    (i, i)
+                };
 
-                    if c.is_whitespace() && *c != '\n' {
-                        last_space = Some(i);
+                // Never break inside a no-wrap range that fits on a line by itself;
+                // move the whole range to the next line instead.
+                let (wrap_at, next_start) = line
+                    .spans
+                    .iter()
+                    .find(|span| {
+                        span.no_wrap
+                            && span.start > current_line_start
+                            && span.start < wrap_at
+                            && wrap_at < span.end
+                            && line.slice(span.start, span.end).cell_len() <= width
+                    })
+                    .map_or((wrap_at, next_start), |span| (span.start, span.start));
+
+                // Breaking mid-word: give up trailing clusters until a hyphen fits.
+                let mid_word = wrap_at == i
+                    && wrap_at > current_line_start
+                    && !c.is_whitespace()
+                    && chars[i - 1] != '-'
+                    && !chars[i - 1].is_whitespace();
+                let mut hyphen_at = None;
+                if self.hyphenate && mid_word {
+                    let mut break_k = k;
+                    let mut line_width = current_width;
+                    while line_width + 1 > width
+                        && break_k > 0
+                        && clusters[break_k - 1].0 > current_line_start
+                    {
+                        break_k -= 1;
+                        line_width -= clusters[break_k].1;
                     }
+                    if line_width < width {
+                        hyphen_at = Some(clusters[break_k].0);
+                    }
+                }
+                let (wrap_at, next_start) = hyphen_at.map_or((wrap_at, next_start), |at| (at, at));
 
-                    if current_width + char_width > width {
-                        // Need to wrap
-                        let (wrap_at, next_start) = if let Some(space_pos) = last_space {
-                            if space_pos > current_line_start && space_pos < i {
-                                // Preserve the whitespace we wrapped at on the previous line.
-                                // This matches Python Rich's wrapping behavior and matters for
-                                // renderables that include significant trailing spaces (e.g. `": "`).
-                                (space_pos + 1, space_pos + 1)
-                            } else {
-                                (i, i)
-                            }
-                        } else {
-                            (i, i)
-                        };
-
-                        if wrap_at > current_line_start {
-                            result.push(line.slice(current_line_start, wrap_at));
-                        }
+                if wrap_at > current_line_start {
+                    ranges.push((current_line_start, wrap_at, hyphen_at.is_some()));
+                }
 
-                        // Skip whitespace at wrap point (but keep the first break-space above if we chose it)
-                        current_line_start = next_start;
-                        while current_line_start < chars.len()
-                            && chars[current_line_start].is_whitespace()
-                        {
-                            current_line_start += 1;
-                        }
+                // Skip whitespace at wrap point (but keep the first break-space above if we chose it)
+                current_line_start = next_start;
+                while current_line_start < chars.len() && chars[current_line_start].is_whitespace()
+                {
+                    current_line_start += 1;
+                }
 
-                        current_width = 0;
-                        last_space = None;
+                last_space = None;
 
-                        // Recalculate width from new start
-                        for j in current_line_start..=i {
-                            if j < chars.len() {
-                                current_width += crate::cells::get_character_cell_size(chars[j]);
-                            }
-                        }
-                    } else {
-                        current_width += char_width;
-                    }
-                }
+                // Recalculate width from new start
+                current_width = clusters[..=k]
+                    .iter()
+                    .rev()
+                    .take_while(|&&(start, _)| start >= current_line_start)
+                    .map(|&(_, cluster_width)| cluster_width)
+                    .sum();
+            } else {
+                current_width += char_width;
+            }
+        }
 
-                // Add remaining text
-                if current_line_start < chars.len() {
-                    result.push(line.slice(current_line_start, chars.len()));
-                }
+        // Add remaining text
+        if current_line_start < chars.len() {
+            ranges.push((current_line_start, chars.len(), false));
+        }
+
+        ranges
+    }
+
+    /// Fold a single line like [`Self::wrap`] does, returning only the first
+    /// wrapped line and the character offset where the next one starts.
+    pub(crate) fn fold_first_line(&self, width: usize) -> (Self, usize) {
+        if self.cell_len() <= width {
+            return (self.clone(), self.length);
+        }
+        let ranges = self.fold_ranges(self, width);
+        let Some(&(start, end, hyphenated)) = ranges.first() else {
+            return (Self::new(""), self.length);
+        };
+        let mut first = self.slice(start, end);
+        if hyphenated {
+            first.append("-");
+        }
+        let rest = ranges.get(1).map_or(self.length, |&(next, _, _)| next);
+        (first, rest)
+    }
+
+    /// Wrap a single line of text.
+    fn wrap_line(&self, line: &Text, width: usize) -> Vec<(Self, Option<usize>)> {
+        let mut result = Vec::new();
+        let chars: Vec<char> = line.plain.chars().collect();
+
+        if chars.is_empty() {
+            return vec![(Self::new(""), None)];
+        }
+
+        match line.overflow {
+            OverflowMethod::Fold => {
+                result.extend(self.fold_ranges(line, width).into_iter().map(
+                    |(start, end, hyphenated)| {
+                        let mut wrapped = line.slice(start, end);
+                        if hyphenated {
+                            wrapped.append("-");
+                        }
+                        (wrapped, None)
+                    },
+                ));
             }
             OverflowMethod::Crop => {
-                result.push(line.slice(0, self.char_pos_for_width(line, width)));
+                result.push((line.slice(0, self.char_pos_for_width(line, width)), None));
             }
             OverflowMethod::Ellipsis => {
                 if width >= 3 {
                     let mut truncated = line.slice(0, self.char_pos_for_width(line, width - 3));
+                    let marker = truncated.len();
                     truncated.append("...");
-                    result.push(truncated);
+                    result.push((truncated, Some(marker)));
                 } else {
-                    result.push(line.slice(0, self.char_pos_for_width(line, width)));
+                    result.push((line.slice(0, self.char_pos_for_width(line, width)), None));
                 }
             }
             OverflowMethod::Ignore => {
-                result.push(line.clone());
+                result.push((line.clone(), None));
             }
         }
 
         if result.is_empty() {
-            result.push(Self::new(""));
+            result.push((Self::new(""), None));
         }
 
         result
@@ -1170,15 +1531,7 @@ impl Text {
 
     /// Find character position for a target cell width.
     fn char_pos_for_width(&self, text: &Text, target_width: usize) -> usize {
-        let mut width = 0;
-        for (i, c) in text.plain.chars().enumerate() {
-            let char_width = crate::cells::get_character_cell_size(c);
-            if width + char_width > target_width {
-                return i;
-            }
-            width += char_width;
-        }
-        text.length
+        text.find_truncation_point(target_width).0
     }
 }
 
@@ -1188,6 +1541,12 @@ impl Renderable for Text {
     }
 }
 
+impl RichMeasure for Text {
+    fn rich_measure(&self, _console: &Console, options: &ConsoleOptions) -> Measurement {
+        self.measure().with_maximum(options.max_width)
+    }
+}
+
 impl fmt::Display for Text {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.plain)
@@ -1229,6 +1588,24 @@ impl From<String> for Text {
     }
 }
 
+impl<S: Into<String>> From<(S, Style)> for Text {
+    fn from((content, style): (S, Style)) -> Self {
+        let mut text = Self::new("");
+        if style.is_null() || style == Style::default() {
+            text.append(&content.into());
+        } else {
+            text.append_styled(&content.into(), style);
+        }
+        text
+    }
+}
+
+impl<S: Into<String>> From<(S, Option<Style>)> for Text {
+    fn from((content, style): (S, Option<Style>)) -> Self {
+        (content, style.unwrap_or_default()).into()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1250,6 +1627,28 @@ mod tests {
         assert_eq!(text.spans()[0].end, 5);
     }
 
+    #[test]
+    fn test_text_links() {
+        let mut text = Text::new("See ");
+        text.append_styled("the docs", Style::new().link("https://docs.rs"));
+        text.append(" or ");
+        text.append_styled("café", Style::new().link("https://example.com/café"));
+        text.append(" page.");
+        // A bold word inside the first link is not reported separately.
+        text.stylize(4, 7, Style::new().bold().link("https://docs.rs"));
+
+        let links = text.links();
+        assert_eq!(
+            links,
+            vec![
+                (4..12, "https://docs.rs".to_string()),
+                (16..21, "https://example.com/café".to_string()),
+            ]
+        );
+        assert_eq!(&text.plain()[links[1].0.clone()], "café");
+        assert!(Text::new("no links").links().is_empty());
+    }
+
     #[test]
     fn test_text_append() {
         let mut text = Text::new("hello");
@@ -1382,11 +1781,39 @@ mod tests {
 
     #[test]
     fn test_assemble() {
-        let text = Text::assemble(&[("hello ", None), ("world", Some(Style::new().bold()))]);
+        let text = Text::assemble([("hello ", None), ("world", Some(Style::new().bold()))]);
         assert_eq!(text.plain(), "hello world");
         assert_eq!(text.spans().len(), 1);
     }
 
+    #[test]
+    fn test_assemble_style_pairs() {
+        let green = Style::parse("green").unwrap();
+        let red = Style::parse("red").unwrap();
+        let text = Text::assemble([
+            ("OK", green.clone()),
+            (" ", Style::default()),
+            ("fail", red.clone()),
+        ]);
+        assert_eq!(text.plain(), "OK fail");
+        assert_eq!(
+            text.spans(),
+            &[Span::new(0, 2, green), Span::new(3, 7, red)]
+        );
+    }
+
+    #[test]
+    fn test_assemble_bare_strings_and_text() {
+        let text = Text::assemble(["a", "b", "c"]);
+        assert_eq!(text.plain(), "abc");
+        assert!(text.spans().is_empty());
+
+        let bold = Text::styled("!", Style::new().bold());
+        let text = Text::assemble([Text::new("hi"), bold]);
+        assert_eq!(text.plain(), "hi!");
+        assert_eq!(text.spans(), &[Span::new(2, 3, Style::new().bold())]);
+    }
+
     // ============================================================
     // Additional tests for comprehensive coverage (rich_rust-zca)
     // ============================================================
@@ -1639,6 +2066,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_wrap_hyphenates_long_word() {
+        let text = Text::new("Supercalifragilistic").hyphenate(true);
+        let lines: Vec<String> = text
+            .wrap(8)
+            .iter()
+            .map(|line| line.plain().to_string())
+            .collect();
+        assert_eq!(lines, vec!["Superca-", "lifragi-", "listic"]);
+
+        let (last, interior) = lines.split_last().unwrap();
+        assert!(interior.iter().all(|line| line.ends_with('-')));
+        let rejoined: String = interior
+            .iter()
+            .map(|line| line.trim_end_matches('-'))
+            .chain([last.as_str()])
+            .collect();
+        assert_eq!(rejoined, "Supercalifragilistic");
+
+        // No room for a character plus the hyphen: break without one.
+        let narrow = Text::new("abc").hyphenate(true).wrap(1);
+        assert!(narrow.iter().all(|line| line.plain().len() == 1));
+    }
+
     #[test]
     fn test_wrap_zero_width() {
         let text = Text::new("hello");
@@ -1657,6 +2108,33 @@ mod tests {
         assert_eq!(lines[0].plain(), "hello world this is long");
     }
 
+    #[test]
+    fn test_wrap_keeps_no_wrap_range_together() {
+        let mut text = Text::new("see:src/a.rs ok");
+        text.stylize_no_wrap(4, 12);
+        let lines: Vec<String> = text
+            .wrap(10)
+            .iter()
+            .map(|l| l.plain().to_string())
+            .collect();
+        assert_eq!(lines, vec!["see:", "src/a.rs ", "ok"]);
+
+        // Without the no-wrap range the token is folded mid-way.
+        let plain = Text::new("see:src/a.rs ok");
+        assert_eq!(plain.wrap(10)[0].plain(), "see:src/a.");
+
+        // The marker adds no styling of its own.
+        assert_eq!(text.render("").len(), 1);
+    }
+
+    #[test]
+    fn test_wrap_folds_no_wrap_range_wider_than_width() {
+        let mut text = Text::new("x abcdefghijkl");
+        text.stylize_no_wrap(2, 14);
+        let lines: Vec<String> = text.wrap(5).iter().map(|l| l.plain().to_string()).collect();
+        assert_eq!(lines, vec!["x ", "abcde", "fghij", "kl"]);
+    }
+
     #[test]
     fn test_wrap_fits_width() {
         let text = Text::new("hello");
@@ -1957,7 +2435,14 @@ mod tests {
     #[test]
     fn test_highlight_words() {
         let mut text = Text::new("Hello World HELLO");
-        text.highlight_words(&["hello"], &Style::new().bold(), false);
+        text.highlight_words(
+            &["hello"],
+            &Style::new().bold(),
+            WordOptions {
+                case_insensitive: true,
+                ..WordOptions::default()
+            },
+        );
         // Case insensitive - should find 2 matches
         assert_eq!(text.spans().len(), 2);
     }
@@ -1965,17 +2450,58 @@ mod tests {
     #[test]
     fn test_highlight_words_empty_word_ignored() {
         let mut text = Text::new("Hello");
-        text.highlight_words(&[""], &Style::new().bold(), false);
+        text.highlight_words(&[""], &Style::new().bold(), WordOptions::default());
         assert!(text.spans().is_empty());
     }
 
     #[test]
     fn test_highlight_words_case_insensitive_unicode() {
         let mut text = Text::new("Ångström ångström");
-        text.highlight_words(&["ÅNGSTRÖM"], &Style::new().bold(), false);
+        text.highlight_words(
+            &["ÅNGSTRÖM"],
+            &Style::new().bold(),
+            WordOptions {
+                case_insensitive: true,
+                ..WordOptions::default()
+            },
+        );
         assert_eq!(text.spans().len(), 2);
     }
 
+    #[test]
+    fn test_highlight_words_case_options() {
+        let mut text = Text::new("GET /index");
+        text.highlight_words(&["get"], &Style::new().bold(), WordOptions::default());
+        assert!(text.spans().is_empty(), "case-sensitive by default");
+
+        text.highlight_words(
+            &["get"],
+            &Style::new().bold(),
+            WordOptions {
+                case_insensitive: true,
+                whole_word: true,
+            },
+        );
+        assert_eq!(text.spans().len(), 1);
+        assert_eq!((text.spans()[0].start, text.spans()[0].end), (0, 3));
+    }
+
+    #[test]
+    fn test_highlight_words_whole_word() {
+        let whole = WordOptions {
+            case_insensitive: false,
+            whole_word: true,
+        };
+        let mut text = Text::new("target get forget get_x (get)");
+        text.highlight_words(&["get"], &Style::new().bold(), whole);
+        let ranges: Vec<(usize, usize)> = text.spans().iter().map(|s| (s.start, s.end)).collect();
+        assert_eq!(ranges, vec![(7, 10), (25, 28)]);
+
+        let mut substrings = Text::new("target get");
+        substrings.highlight_words(&["get"], &Style::new().bold(), WordOptions::default());
+        assert_eq!(substrings.spans().len(), 2);
+    }
+
     #[test]
     fn test_split_lines_empty() {
         let text = Text::new("");
@@ -1993,6 +2519,51 @@ mod tests {
         assert_eq!(lines[1].plain(), "");
     }
 
+    #[test]
+    fn test_truncate_never_splits_zwj_sequence() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let source = format!("a{family}b");
+
+        for width in 1..cell_len(&source) {
+            let mut text = Text::new(source.as_str());
+            text.truncate(width, OverflowMethod::Crop, false);
+            let plain = text.plain();
+            assert!(
+                plain == "a" || plain == format!("a{family}"),
+                "width {width} produced {plain:?}"
+            );
+        }
+
+        let (fitted, truncated) = Text::new(source.as_str()).fit(3);
+        assert!(truncated);
+        assert_eq!(fitted.plain(), "a\u{2026}");
+    }
+
+    #[test]
+    fn test_wrap_keeps_combining_marks_and_flags_whole() {
+        let accented = Text::new("e\u{301}");
+        assert_eq!(accented.cell_len(), 1);
+        let lines = Text::new("e\u{301}e\u{301}e\u{301}").wrap(1);
+        let plains: Vec<&str> = lines.iter().map(Text::plain).collect();
+        assert_eq!(plains, vec!["e\u{301}"; 3]);
+
+        let flag = "\u{1F1EF}\u{1F1F5}";
+        assert_eq!(Text::new(flag).cell_len(), 2);
+        let lines = Text::new(format!("ab{flag}")).wrap(3);
+        let plains: Vec<&str> = lines.iter().map(Text::plain).collect();
+        assert_eq!(plains, vec!["ab", flag]);
+    }
+
+    #[test]
+    fn test_reverse_keeps_graphemes_and_spans() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}";
+        let mut text = Text::new(format!("ab{family}"));
+        text.stylize(0, 1, Style::new().bold());
+        text.reverse();
+        assert_eq!(text.plain(), format!("{family}ba"));
+        assert_eq!((text.spans()[0].start, text.spans()[0].end), (6, 7));
+    }
+
     #[test]
     fn test_truncate_crop() {
         let mut text = Text::new("hello world");
@@ -2000,6 +2571,59 @@ mod tests {
         assert_eq!(text.plain(), "hello");
     }
 
+    #[test]
+    fn test_fit_truncates_with_ellipsis() {
+        let (fitted, truncated) = Text::new("hello world").fit(5);
+        assert_eq!(fitted.plain(), "hell\u{2026}");
+        assert!(truncated);
+
+        let (fitted, truncated) = Text::new("hi").fit(5);
+        assert_eq!(fitted.plain(), "hi");
+        assert!(!truncated);
+    }
+
+    #[test]
+    fn test_fit_wide_chars_and_newlines() {
+        // A wide char that would straddle the boundary is dropped entirely.
+        let (fitted, truncated) = Text::new("日本語").fit(4);
+        assert_eq!(fitted.plain(), "日\u{2026}");
+        assert_eq!(fitted.cell_len(), 3);
+        assert!(truncated);
+
+        let (fitted, truncated) = Text::new("ab\ncd").fit(5);
+        assert_eq!(fitted.plain(), "ab\u{2026}");
+        assert!(truncated);
+    }
+
+    #[test]
+    fn test_measure_words_lines_and_no_wrap() {
+        let measurement = Text::new("hello world").measure();
+        assert_eq!(measurement, Measurement::new(5, 11));
+
+        // Wide characters count two cells; the longest line sets the maximum.
+        let measurement = Text::new(
+            "日本 go
+short line here",
+        )
+        .measure();
+        assert_eq!(measurement, Measurement::new(5, 15));
+
+        // A no_wrap range joins the words it touches into one token.
+        let mut text = Text::new("see src/main.rs now");
+        text.stylize_no_wrap(4, 8);
+        assert_eq!(text.measure().minimum, 11);
+        let mut text = Text::new("hello world");
+        text.stylize_no_wrap(0, 11);
+        assert_eq!(text.measure(), Measurement::exact(11));
+
+        let mut text = Text::new(
+            "hello world
+hi",
+        );
+        text.no_wrap = true;
+        assert_eq!(text.measure(), Measurement::exact(11));
+    }
+
     #[test]
     fn test_truncate_with_pad() {
         let mut text = Text::new("hi");
@@ -2048,4 +2672,44 @@ mod tests {
         // Should have spans for "a" (italic), " | " (bold), and potentially "b"
         assert!(joined.spans().len() >= 2);
     }
+
+    #[test]
+    fn test_join_shifts_part_spans_around_separator() {
+        let red = Style::parse("red").unwrap();
+        let blue = Style::parse("blue").unwrap();
+        let separator = Text::new(", ");
+        let parts = [
+            Text::styled("a", red.clone()),
+            Text::styled("b", blue.clone()),
+        ];
+
+        let joined = Text::join(&separator, &parts);
+
+        assert_eq!(joined.plain(), "a, b");
+        assert_eq!(
+            joined.spans(),
+            &[Span::new(0, 1, red), Span::new(3, 4, blue)]
+        );
+    }
+
+    #[test]
+    fn test_indent_shifts_spans() {
+        let bold = Style::new().bold();
+        let mut text = Text::new("a\n");
+        text.append_styled("b", bold.clone());
+
+        let indented = text.indent(2);
+        assert_eq!(indented.plain(), "  a\n  b");
+        assert_eq!(indented.spans(), &[Span::new(6, 7, bold)]);
+
+        let prefixed = Text::new("x\ny").with_line_prefix("> ");
+        assert_eq!(prefixed.plain(), "> x\n> y");
+    }
+
+    #[test]
+    fn test_wrap_with_line_prefix_prefixes_continuations() {
+        let lines = Text::new("one two three").wrap_with_line_prefix(7, "  ");
+        let plains: Vec<&str> = lines.iter().map(Text::plain).collect();
+        assert_eq!(plains, vec!["  one ", "  two ", "  three"]);
+    }
 }
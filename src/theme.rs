@@ -14,6 +14,7 @@ use std::path::Path;
 use std::sync::LazyLock;
 
 use crate::style::{Style, StyleParseError};
+use crate::terminal::Background;
 
 static DEFAULT_STYLES: LazyLock<HashMap<String, Style>> = LazyLock::new(|| {
     let mut styles = HashMap::new();
@@ -42,6 +43,16 @@ static DEFAULT_STYLES: LazyLock<HashMap<String, Style>> = LazyLock::new(|| {
     styles
 });
 
+/// Default styles that are hard to read on a light terminal background, with
+/// replacements used by [`Theme::for_background`].
+const LIGHT_BACKGROUND_OVERRIDES: &[(&str, &str)] = &[
+    ("bar.back", "grey82"),
+    ("json.bool_true", "italic green"),
+    ("repr.bool_true", "italic green"),
+    ("repr.uuid", "not bold yellow"),
+    ("rule.line", "green"),
+];
+
 /// A container for style information used by [`crate::console::Console`].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Theme {
@@ -68,6 +79,21 @@ impl Theme {
         Self { styles: merged }
     }
 
+    /// Default theme adjusted for the given terminal background.
+    ///
+    /// Dark backgrounds use the stock defaults; light backgrounds swap a few
+    /// styles (bright foregrounds, dark bar backgrounds) for legible variants.
+    #[must_use]
+    pub fn for_background(background: Background) -> Self {
+        match background {
+            Background::Dark => Self::default(),
+            Background::Light => {
+                Self::from_style_definitions(LIGHT_BACKGROUND_OVERRIDES.iter().copied(), true)
+                    .expect("light background overrides are valid style definitions")
+            }
+        }
+    }
+
     /// Build a theme from string style definitions (`"bold red"`, `"rule.line"`, etc).
     pub fn from_style_definitions<I, K, V>(styles: I, inherit: bool) -> Result<Self, ThemeError>
     where
@@ -323,6 +349,18 @@ mod tests {
     // Theme Creation Tests
     // =========================================================================
 
+    #[test]
+    fn test_theme_for_background() {
+        assert_eq!(Theme::for_background(Background::Dark), Theme::default());
+
+        let light = Theme::for_background(Background::Light);
+        assert_eq!(
+            light.get("bar.back"),
+            Some(&Style::parse("grey82").unwrap())
+        );
+        assert_eq!(light.get("bold"), DEFAULT_STYLES.get("bold"));
+    }
+
     #[test]
     fn test_theme_new_empty_no_inherit() {
         let theme = Theme::new(None, false);